@@ -0,0 +1,11 @@
+use rvpacker_txt_rs_lib::{types::EngineType, verify::Verifier};
+
+fn main() {
+    let report = Verifier::new("data", "verify", EngineType::New).logging(true).verify();
+
+    for (file, differences) in report.changed_files() {
+        for difference in differences {
+            println!("{file}: {difference}");
+        }
+    }
+}
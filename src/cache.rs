@@ -0,0 +1,98 @@
+//! Cache of written files.
+//!
+//! Writers store hashes of their inputs — the original file, the translation lines, that apply to it, and
+//! the writer options — in the output directory. On the next run, files with unchanged inputs, which are
+//! still present in the output directory, are skipped.
+use crate::{
+    statics::LINES_SEPARATOR,
+    types::{OptionExt, ResultExt},
+};
+use gxhash::{GxBuildHasher, GxHasher};
+use std::{
+    collections::HashMap,
+    fs::{read_to_string, write},
+    hash::Hasher,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Name of the file in the output directory, where hashes of written files are stored.
+pub const CACHE_FILE_NAME: &str = ".rvpacker-cache";
+
+/// Returns the hash of the inputs of a written file.
+///
+/// Every part is prefixed with its length, so moving bytes between parts changes the hash.
+pub(crate) fn inputs_hash(parts: &[&[u8]]) -> u64 {
+    let mut hasher: GxHasher = GxHasher::with_seed(0);
+    hasher.write(env!("CARGO_PKG_VERSION").as_bytes());
+
+    for part in parts {
+        hasher.write_usize(part.len());
+        hasher.write(part);
+    }
+
+    hasher.finish()
+}
+
+/// Hashes of written files, loaded from and saved to the output directory.
+///
+/// # Fields
+///
+/// - `path` - Path to the cache file
+/// - `entries` - Hashes of inputs, mapped to file names
+/// - `force` - Whether cached hashes are ignored, so every file is written
+pub(crate) struct WriteCache {
+    path: PathBuf,
+    entries: Mutex<HashMap<String, u64, GxBuildHasher>>,
+    force: bool,
+}
+
+impl WriteCache {
+    /// Loads the cache from the output directory.
+    ///
+    /// Missing or malformed cache file results in an empty cache.
+    pub(crate) fn load(output_path: &Path, force: bool) -> WriteCache {
+        let path: PathBuf = output_path.join(CACHE_FILE_NAME);
+
+        let entries: HashMap<String, u64, GxBuildHasher> = read_to_string(&path)
+            .unwrap_or_default()
+            .lines()
+            .filter_map(|line| {
+                let (file_name, hash) = line.split_once(LINES_SEPARATOR)?;
+                Some((file_name.to_owned(), u64::from_str_radix(hash, 16).ok()?))
+            })
+            .collect();
+
+        WriteCache {
+            path,
+            entries: Mutex::new(entries),
+            force,
+        }
+    }
+
+    /// Returns whether the file was written with the same inputs, and is still present in the output directory.
+    pub(crate) fn is_fresh(&self, file_name: &str, hash: u64) -> bool {
+        !self.force
+            && self.entries.lock().unwrap_log().get(file_name) == Some(&hash)
+            && self.path.parent().unwrap_log().join(file_name).exists()
+    }
+
+    /// Records the hash of inputs of the written file.
+    pub(crate) fn update(&self, file_name: &str, hash: u64) {
+        self.entries.lock().unwrap_log().insert(file_name.to_owned(), hash);
+    }
+
+    /// Saves the cache to the output directory.
+    pub(crate) fn save(self) {
+        let mut entries: Vec<(String, u64)> = self.entries.into_inner().unwrap_log().into_iter().collect();
+        entries.sort_unstable();
+
+        let content: String = entries
+            .into_iter()
+            .map(|(file_name, hash)| format!("{file_name}{LINES_SEPARATOR}{hash:016x}"))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write(&self.path, content).unwrap_log();
+    }
+}
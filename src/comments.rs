@@ -0,0 +1,96 @@
+//! Comments, attached to lines of `.txt` files.
+//!
+//! Every comment is written as `<!-- Name --><#>value` right before the line it's attached to.
+use crate::statics::LINES_SEPARATOR;
+
+/// Prefix of the comment, which holds a location-specific override of the following line.
+///
+/// The full comment is `<!-- Override: location --><#>translation`.
+pub const OVERRIDE_COMMENT_PREFIX: &str = "<!-- Override: ";
+
+/// Comment, which holds a stable ID of the following line.
+///
+/// The full comment is `<!-- ID --><#>location`, where `location` is the location of the
+/// first occurrence of the line.
+pub const ID_COMMENT: &str = "<!-- ID -->";
+
+/// Prefix of the comment, which precedes every line filled from a translation pack.
+///
+/// The full comment is `<!-- Pack: name --><#>`, where `name` is the name of the pack.
+pub const PACK_COMMENT_PREFIX: &str = "<!-- Pack: ";
+
+/// Comment, which holds the review state of the following line.
+///
+/// The full comment is `<!-- State --><#>state`, where `state` is one of `machine`, `fuzzy`,
+/// `needs-review` and `approved`.
+pub const STATE_COMMENT: &str = "<!-- State -->";
+
+/// Comment, which holds a translator note about the following line.
+///
+/// The full comment is `<!-- Note --><#>text`, where the text is escaped like the source text.
+/// A line can have multiple notes, each in its own comment.
+pub const NOTE_COMMENT: &str = "<!-- Note -->";
+
+/// Comment, which precedes a line, which translation was carried over from a changed original.
+///
+/// The full comment is `<!-- Needs Review --><#>old original`.
+pub const NEEDS_REVIEW_COMMENT: &str = "<!-- Needs Review -->";
+
+/// Returns whether the original part of the line is a comment, attached to the following line.
+///
+/// Such comments are an override, ID, pack, state, note or needs review comment. They're skipped
+/// when appending.
+#[inline]
+pub(crate) fn is_meta_comment(original: &str) -> bool {
+    original == ID_COMMENT
+        || original.starts_with(OVERRIDE_COMMENT_PREFIX)
+        || original.starts_with(PACK_COMMENT_PREFIX)
+        || is_annotation_comment(original)
+}
+
+/// Returns whether the original part of the line is a state, note or needs review comment.
+///
+/// Unlike IDs and overrides, such comments can't be regenerated from the original data, so readers
+/// carry them over to the following line when appending.
+#[inline]
+pub(crate) fn is_annotation_comment(original: &str) -> bool {
+    original == STATE_COMMENT || original == NOTE_COMMENT || original == NEEDS_REVIEW_COMMENT
+}
+
+/// Passes every line of the `.txt` content along with its attached comments to `f`, which
+/// writes them to the output.
+pub(crate) fn map_lines(content: &str, mut f: impl FnMut(&[&str], &str, &mut String)) -> String {
+    let mut output: String = String::with_capacity(content.len());
+    let mut comments: Vec<&str> = Vec::new();
+
+    for line in content.split('\n') {
+        let original: &str = line.split_once(LINES_SEPARATOR).map_or(line, |(original, _)| original);
+
+        if is_meta_comment(original) {
+            comments.push(line);
+            continue;
+        }
+
+        if original.starts_with("<!--") {
+            for comment in comments.drain(..) {
+                output.push_str(comment);
+                output.push('\n');
+            }
+
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        }
+
+        f(&comments, line, &mut output);
+        comments.clear();
+    }
+
+    for comment in comments {
+        output.push_str(comment);
+        output.push('\n');
+    }
+
+    output.pop();
+    output
+}
@@ -0,0 +1,438 @@
+use crate::{
+    comments::{ID_COMMENT, NEEDS_REVIEW_COMMENT, OVERRIDE_COMMENT_PREFIX},
+    functions::{format_version, process_parameter, unescape},
+    state::state_comment,
+    statics::LINES_SEPARATOR,
+    types::{Code, EngineType, GameType, HashMapGx, LineState, TrimReplace},
+};
+use gxhash::GxBuildHasher;
+use indexmap::IndexMap;
+use sonic_rs::{JsonValueTrait, Value};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::Write,
+};
+
+/// Locations of every occurrence of a line, grouped by the line.
+pub(crate) type Occurrences = IndexMap<String, Vec<String>, GxBuildHasher>;
+
+/// Returns the ID of a map event, common event or troop.
+#[inline]
+pub(crate) fn object_id(object: &Value, engine_type: EngineType) -> u64 {
+    object[if engine_type.is_new() { "id" } else { "__symbol__id" }]
+        .as_u64()
+        .unwrap_or(0)
+}
+
+/// Returns the location of an event command list.
+///
+/// Location of a single command is `file/id/page/command`, where `command` is the index of the
+/// command in the list. Choices additionally have `:choice` suffix with the index of the choice.
+///
+/// # Parameters
+///
+/// - `file` - `map{N}` for maps, or lowercase name of the file without extension otherwise
+/// - `id` - ID of the event, common event or troop
+/// - `page` - Index of the page, always `0` for common events
+#[inline]
+pub fn list_location(file: &str, id: u64, page: usize) -> String {
+    format!("{file}/{id}/{page}")
+}
+
+/// Parses location-specific overrides from the `.txt` content.
+///
+/// # Parameters
+///
+/// - `translation` - Content of the `.txt` file
+/// - `write` - When `true`, empty overrides are skipped and new lines are replaced
+///
+/// # Returns
+///
+/// A map of locations to override translations.
+pub fn parse_overrides(translation: &str, write: bool) -> HashMapGx {
+    let mut overrides: HashMapGx = HashMapGx::default();
+    let version: u8 = format_version(translation);
+
+    for line in translation.split('\n') {
+        let Some(rest) = line.strip_prefix(OVERRIDE_COMMENT_PREFIX) else {
+            continue;
+        };
+
+        let Some((location, translation)) = rest.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        let location: &str = location.trim_end_matches(" -->");
+
+        if write {
+            if translation.is_empty() {
+                continue;
+            }
+
+            overrides.insert(location.to_owned(), unescape(translation, version).trim_replace());
+        } else {
+            overrides.insert(location.to_owned(), translation.to_owned());
+        }
+    }
+
+    overrides
+}
+
+/// Inserts override comments before every line, which occurs in more than one location.
+///
+/// Existing overrides are kept. If `separate` is `true`, only locations of the current map
+/// section are listed.
+pub(crate) fn apply_overrides(
+    content: String,
+    occurrences: &Occurrences,
+    overrides: &HashMapGx,
+    separate: bool,
+) -> String {
+    let mut output: String = String::with_capacity(content.len());
+    let mut map_prefix: Option<String> = None;
+
+    for line in content.split('\n') {
+        if separate {
+            if let Some(map_number) = line
+                .strip_prefix("<!-- Map -->")
+                .and_then(|x| x.strip_prefix(LINES_SEPARATOR))
+            {
+                map_prefix = Some(format!("map{map_number}/"));
+            }
+        }
+
+        if !line.starts_with("<!--") {
+            if let Some(locations) = line
+                .split_once(LINES_SEPARATOR)
+                .and_then(|(original, _)| occurrences.get(original))
+            {
+                let locations: Vec<&String> = locations
+                    .iter()
+                    .filter(|location| map_prefix.as_ref().map_or(true, |prefix| location.starts_with(prefix)))
+                    .collect();
+
+                if locations.len() > 1 {
+                    for location in locations {
+                        let translation: &str = overrides.get(location).map_or("", String::as_str);
+                        let _ = writeln!(
+                            output,
+                            "{OVERRIDE_COMMENT_PREFIX}{location} -->{LINES_SEPARATOR}{translation}"
+                        );
+                    }
+                }
+            }
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.pop();
+    output
+}
+
+/// Returns a single-entry translation map with the override of the parameter, if the location
+/// has one.
+#[inline]
+pub(crate) fn override_map(
+    code: Code,
+    parameter: &str,
+    game_type: Option<GameType>,
+    engine_type: EngineType,
+    romanize: bool,
+    overrides: Option<(&HashMapGx, &str)>,
+    location: impl FnOnce(&str) -> String,
+) -> Option<HashMapGx> {
+    let (overrides, prefix) = overrides?;
+    let translation: &String = overrides.get(&location(prefix))?;
+    let key: String = process_parameter(code, parameter, game_type, engine_type, romanize, None, None, false)?;
+
+    let mut map: HashMapGx = HashMapGx::default();
+    map.insert(key, translation.to_owned());
+    Some(map)
+}
+
+/// Comments, attached to lines, grouped by the map section and the original of the line.
+///
+/// Every occurrence of the line has its own group of comments, in order of appearance.
+pub(crate) type Attached = HashMap<(String, String), VecDeque<Vec<String>>, GxBuildHasher>;
+
+/// Removes comments, matching the predicate, from the `.txt` content, and returns them grouped
+/// by the line they're attached to.
+pub(crate) fn detach_comments(content: &str, predicate: impl Fn(&str) -> bool) -> (String, Attached) {
+    let mut output: String = String::with_capacity(content.len());
+    let mut attached: Attached = Attached::default();
+    let mut section: String = String::new();
+    let mut pending: Vec<String> = Vec::new();
+
+    for line in content.split('\n') {
+        let original: &str = line.split_once(LINES_SEPARATOR).map_or(line, |(original, _)| original);
+
+        if original == "<!-- Map -->" {
+            section = line[original.len()..].trim_start_matches(LINES_SEPARATOR).to_owned();
+        }
+
+        if predicate(original) {
+            pending.push(line.to_owned());
+            continue;
+        }
+
+        if !original.starts_with("<!--") {
+            attached
+                .entry((section.clone(), original.to_owned()))
+                .or_default()
+                .push_back(std::mem::take(&mut pending));
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.pop();
+    (output, attached)
+}
+
+/// Inserts attached comments back before the lines they're attached to.
+///
+/// Comments of lines, that are no longer present in the content, are dropped.
+pub(crate) fn attach_comments(content: String, attached: &mut Attached) -> String {
+    if attached.is_empty() {
+        return content;
+    }
+
+    let mut output: String = String::with_capacity(content.len());
+    let mut section: String = String::new();
+
+    for line in content.split('\n') {
+        let original: &str = line.split_once(LINES_SEPARATOR).map_or(line, |(original, _)| original);
+
+        if original == "<!-- Map -->" {
+            section = line[original.len()..].trim_start_matches(LINES_SEPARATOR).to_owned();
+        } else if !original.starts_with("<!--") {
+            if let Some(comments) = attached
+                .get_mut(&(section.clone(), original.to_owned()))
+                .and_then(VecDeque::pop_front)
+            {
+                for comment in comments {
+                    output.push_str(&comment);
+                    output.push('\n');
+                }
+            }
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.pop();
+    output
+}
+
+/// Inserts ID comments before every line, which has a known location and doesn't have an ID yet.
+///
+/// If `preserve` is `true`, every occurrence of the line is a separate line, so `n`-th
+/// occurrence gets `n`-th location. Otherwise, the first location is used.
+pub(crate) fn apply_ids(content: String, occurrences: &Occurrences, preserve: bool) -> String {
+    let mut output: String = String::with_capacity(content.len());
+    let mut counters: HashMap<&str, usize, GxBuildHasher> = HashMap::default();
+    let mut has_id: bool = false;
+
+    for line in content.split('\n') {
+        if let Some((original, _)) = line.split_once(LINES_SEPARATOR) {
+            if original == ID_COMMENT {
+                has_id = true;
+            } else if !original.starts_with("<!--") {
+                if let Some(locations) = occurrences.get(original) {
+                    let counter: &mut usize = counters.entry(original).or_default();
+                    let location: Option<&String> = locations.get(if preserve { *counter } else { 0 });
+                    *counter += 1;
+
+                    if let Some(location) = location.filter(|_| !has_id) {
+                        let _ = writeln!(output, "{ID_COMMENT}{LINES_SEPARATOR}{location}");
+                    }
+                }
+
+                has_id = false;
+            }
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.pop();
+    output
+}
+
+/// Parses stable IDs from the `.txt` content.
+///
+/// # Parameters
+///
+/// - `translation` - Content of the `.txt` file
+///
+/// # Returns
+///
+/// A map of IDs to the original and translation of the line, that follows the ID comment.
+pub fn parse_ids(translation: &str) -> IndexMap<String, (String, String), GxBuildHasher> {
+    let mut ids: IndexMap<String, (String, String), GxBuildHasher> = IndexMap::default();
+    let mut pending_id: Option<&str> = None;
+
+    for line in translation.split('\n') {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if original == ID_COMMENT {
+            pending_id = Some(translation);
+        } else if !original.starts_with("<!--") {
+            if let Some(id) = pending_id.take() {
+                ids.insert(id.to_owned(), (original.to_owned(), translation.to_owned()));
+            }
+        }
+    }
+
+    ids
+}
+
+/// Merges translation from the existing `.txt` content into freshly read content.
+///
+/// Every untranslated line of the fresh content takes the translation of the existing line with
+/// the same ID first, then of the line with the same original in the same map, and finally of any
+/// line with the same original. If the line, matched by ID, has a different original, its
+/// translation is carried over and marked with [`NEEDS_REVIEW_COMMENT`], holding the previous
+/// original, and `needs-review` state. Existing lines, which are matched neither by ID nor by
+/// original, are kept at the end of their map section.
+pub(crate) fn merge_by_ids(content: String, existing: &str) -> String {
+    let map_section = |line: &str| -> Option<String> {
+        line.strip_prefix("<!-- Map -->")
+            .and_then(|x| x.strip_prefix(LINES_SEPARATOR))
+            .map(str::to_owned)
+    };
+
+    let fresh_originals: HashSet<&str, GxBuildHasher> = content
+        .split('\n')
+        .filter(|line| !line.starts_with("<!--"))
+        .filter_map(|line| line.split_once(LINES_SEPARATOR).map(|x| x.0))
+        .collect();
+    let fresh_ids: HashSet<&str, GxBuildHasher> = content
+        .split('\n')
+        .filter_map(|line| line.strip_prefix(ID_COMMENT)?.strip_prefix(LINES_SEPARATOR))
+        .collect();
+
+    let mut by_id: HashMap<&str, (&str, &str), GxBuildHasher> = HashMap::default();
+    let mut by_section: HashMap<(String, &str), &str, GxBuildHasher> = HashMap::default();
+    let mut by_original: HashMap<&str, &str, GxBuildHasher> = HashMap::default();
+    let mut orphans: IndexMap<String, Vec<&str>, GxBuildHasher> = IndexMap::default();
+
+    let mut section: String = String::new();
+    let mut pending_id: Option<&str> = None;
+
+    for line in existing.split('\n') {
+        if let Some(map_number) = map_section(line) {
+            section = map_number;
+        }
+
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if original == ID_COMMENT {
+            pending_id = Some(translation);
+            continue;
+        }
+
+        if translation.is_empty() {
+            continue;
+        }
+
+        by_section.entry((section.clone(), original)).or_insert(translation);
+        by_original.entry(original).or_insert(translation);
+
+        if !original.starts_with("<!--") {
+            let id: Option<&str> = pending_id.take();
+
+            if let Some(id) = id {
+                by_id.insert(id, (original, translation));
+            }
+
+            if !fresh_originals.contains(original) && !id.is_some_and(|id| fresh_ids.contains(id)) {
+                orphans.entry(section.clone()).or_default().push(line);
+            }
+        }
+    }
+
+    let mut output: String = String::with_capacity(content.len().max(existing.len()));
+    let mut flush_orphans = |output: &mut String, section: &str| {
+        if let Some(lines) = orphans.shift_remove(section) {
+            for line in lines {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    };
+
+    section.clear();
+    pending_id = None;
+
+    for line in content.split('\n') {
+        if let Some(map_number) = map_section(line) {
+            flush_orphans(&mut output, &section);
+            section = map_number;
+        }
+
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        };
+
+        if original == ID_COMMENT {
+            pending_id = Some(translation);
+        } else if translation.is_empty() {
+            let is_comment: bool = original.starts_with("<!--");
+
+            let by_id_match: Option<(&str, &str)> =
+                pending_id.filter(|_| !is_comment).and_then(|id| by_id.get(id)).copied();
+
+            let found: Option<&str> = by_id_match
+                .map(|(_, translation)| translation)
+                .or_else(|| by_section.get(&(section.clone(), original)).copied())
+                .or_else(|| by_original.get(original).copied());
+
+            if let Some(found) = found {
+                // Previous state and review comments aren't carried over, since the original changed
+                if let Some((existing_original, _)) = by_id_match.filter(|(x, _)| *x != original) {
+                    let _ = writeln!(output, "{NEEDS_REVIEW_COMMENT}{LINES_SEPARATOR}{existing_original}");
+                    let _ = writeln!(output, "{}", state_comment(LineState::NeedsReview));
+                }
+
+                let _ = writeln!(output, "{original}{LINES_SEPARATOR}{found}");
+
+                if !is_comment {
+                    pending_id = None;
+                }
+
+                continue;
+            }
+        }
+
+        if !original.starts_with("<!--") {
+            pending_id = None;
+        }
+
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    flush_orphans(&mut output, &section);
+
+    for (_, lines) in orphans {
+        for line in lines {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+
+    output.pop();
+    output
+}
@@ -1,17 +1,19 @@
 use crate::{
+    header::is_header_line,
+    observer::{Observer, Warning},
     statics::{
-        localization::{AT_POSITION_MSG, COULD_NOT_SPLIT_LINE_MSG, IN_FILE_MSG},
         regexes::{INVALID_MULTILINE_VARIABLE_RE, INVALID_VARIABLE_RE, IS_ONLY_SYMBOLS_RE, PLUGINS_REGEXPS},
-        LINES_SEPARATOR, NEW_LINE, SYMBOLS,
+        ESCAPED_NEW_LINE, ESCAPED_SEPARATOR, FORMAT_COMMENT, FORMAT_VERSION, LINES_SEPARATOR, NEW_LINE, SYMBOLS,
     },
     types::{
-        Code, EachLine, EngineType, GameType, HashMapGx, IndexMapGx, OptionExt, ProcessingMode, ResultExt, TrimReplace,
-        Variable,
+        Code, EachLine, EngineType, GameType, HashMapGx, IndexMapGx, OptionExt, ProcessingMode, Replacement, ResultExt,
+        TrimReplace, UntranslatedLine, Variable,
     },
 };
 use gxhash::GxBuildHasher;
 use indexmap::{IndexMap, IndexSet};
 use marshal_rs::{load, StringMode};
+use rayon::{prelude::*, ThreadPool, ThreadPoolBuilder};
 use smallvec::SmallVec;
 use sonic_rs::{from_str, prelude::*, Object, Value};
 use std::{
@@ -107,6 +109,33 @@ pub fn get_object_data(object: &Object) -> Vec<u8> {
     vec
 }
 
+/// Returns the objects of a data file.
+///
+/// Data files are arrays of objects, except for VX `Areas`, which is a hash of objects by their IDs.
+#[inline]
+pub fn get_objects(value: &Value) -> Option<Vec<&Value>> {
+    match value.as_object() {
+        Some(object) => Some(object.iter().map(|(_, value)| value).collect()),
+        None => value
+            .as_array()
+            .map(|array| array.iter().filter(|value| value.is_object()).collect()),
+    }
+}
+
+/// Returns the mutable objects of a data file. See [`get_objects`].
+#[inline]
+pub fn get_objects_mut(value: &mut Value) -> Option<Vec<&mut Value>> {
+    if value.is_object() {
+        return value
+            .as_object_mut()
+            .map(|object| object.iter_mut().map(|(_, value)| value).collect());
+    }
+
+    value
+        .as_array_mut()
+        .map(|array| array.iter_mut().filter(|value| value.is_object()).collect())
+}
+
 #[inline]
 pub fn extract_strings(ruby_code: &str, write: bool) -> (IndexSet<String, GxBuildHasher>, Vec<std::ops::Range<usize>>) {
     fn is_escaped(index: usize, string: &str) -> bool {
@@ -166,9 +195,13 @@ pub fn extract_strings(ruby_code: &str, write: bool) -> (IndexSet<String, GxBuil
             } else if inside_string && char == current_quote_type && !is_escaped(i, &line) {
                 let range: std::ops::Range<usize> = string_start_index + 1..global_index + i;
 
-                let extracted_string: String = ruby_code[range.clone()]
-                    .replace("\r\n", NEW_LINE)
-                    .replace('\n', NEW_LINE);
+                let extracted_string: String = ruby_code[range.clone()].replace("\r\n", "\n");
+                // Strings are matched against unescaped translation when writing
+                let extracted_string: String = if write {
+                    extracted_string
+                } else {
+                    escape(&extracted_string)
+                };
 
                 if !extracted_string.is_empty() && !strings.contains(&extracted_string) {
                     strings.insert(extracted_string);
@@ -222,11 +255,42 @@ pub fn filter_maps(entry: Result<DirEntry, std::io::Error>, engine_type: EngineT
     }
 }
 
+/// Returns the names of data files, that aren't processed by default, without extension.
+///
+/// These are `Areas`, `Tilesets` and `Animations`, as their names are rarely displayed in-game,
+/// and `States` for Termina.
+pub fn default_excluded_files(game_type: Option<GameType>) -> Vec<String> {
+    let mut excluded_files: Vec<String> = ["Areas", "Tilesets", "Animations"].map(str::to_owned).to_vec();
+
+    if game_type == Some(GameType::Termina) {
+        excluded_files.push(String::from("States"));
+    }
+
+    excluded_files
+}
+
+/// Returns the names of excluded data files, with the `included_files` opted in.
+///
+/// If `excluded_files` is `None`, [`default_excluded_files`] are used.
+pub(crate) fn resolve_excluded_files(
+    excluded_files: Option<&[String]>,
+    included_files: &[String],
+    game_type: Option<GameType>,
+) -> Vec<String> {
+    let mut excluded_files: Vec<String> = match excluded_files {
+        Some(excluded_files) => excluded_files.to_vec(),
+        None => default_excluded_files(game_type),
+    };
+
+    excluded_files.retain(|file| !included_files.contains(file));
+    excluded_files
+}
+
 #[inline]
 pub fn filter_other(
     entry: Result<DirEntry, std::io::Error>,
     engine_type: EngineType,
-    game_type: Option<GameType>,
+    excluded_files: &[String],
 ) -> Option<(String, PathBuf)> {
     if let Ok(entry) = entry {
         if !entry.file_type().unwrap_log().is_file() {
@@ -239,13 +303,10 @@ pub fn filter_other(
         let (name, _) = unsafe { filename_str.split_once('.').unwrap_unchecked() };
 
         if !name.starts_with("Map")
-            && !matches!(name, "Areas" | "Tilesets" | "Animations" | "System" | "Scripts")
+            && !matches!(name, "System" | "Scripts")
+            && !excluded_files.iter().any(|file| file == name)
             && filename_str.ends_with(determine_extension(engine_type))
         {
-            if game_type.is_some_and(|game_type: GameType| game_type == GameType::Termina) && name == "States" {
-                return None;
-            }
-
             Some((filename_str.to_owned(), entry.path()))
         } else {
             None
@@ -255,6 +316,21 @@ pub fn filter_other(
     }
 }
 
+/// Returns the comment, that precedes strings of the `System` file section, like `<!-- Elements -->`.
+#[inline]
+pub(crate) fn system_section_comment(section: &str) -> String {
+    format!("<!-- {section} -->")
+}
+
+/// Returns labels of switches and variables of the `System` file.
+#[inline(always)]
+pub const fn get_system_dev_labels(engine_type: EngineType) -> (&'static str, &'static str) {
+    match engine_type {
+        EngineType::New => ("switches", "variables"),
+        _ => ("__symbol__switches", "__symbol__variables"),
+    }
+}
+
 #[inline(always)]
 pub const fn get_system_labels(
     engine_type: EngineType,
@@ -431,7 +507,8 @@ pub fn traverse_json(
                 || str.starts_with("rgba"))
                 || key.is_some_and(|x| x.starts_with("LATIN"))
             {
-                let mut string: String = str.replace('\n', NEW_LINE);
+                // Strings are matched against unescaped translation when writing
+                let mut string: String = if write { str.to_owned() } else { escape(str) };
 
                 if romanize {
                     string = romanize_string(string);
@@ -614,15 +691,112 @@ pub fn parse_map_number(string: &str) -> u16 {
     }
 }
 
+/// Escapes the source text for a `.txt` file.
+///
+/// New lines are replaced with `\#`, and `<#` and `\#` sequences are replaced with their escaped
+/// forms, so the result never contains the separator.
+pub fn escape(text: &str) -> String {
+    if !text.contains(['#', '\n']) {
+        return text.to_owned();
+    }
+
+    let mut output: String = String::with_capacity(text.len());
+    let mut rest: &str = text;
+
+    while let Some(char) = rest.chars().next() {
+        if rest.starts_with("<#") {
+            output.push_str(ESCAPED_SEPARATOR);
+            rest = &rest[2..];
+        } else if rest.starts_with(NEW_LINE) {
+            output.push_str(ESCAPED_NEW_LINE);
+            rest = &rest[2..];
+        } else {
+            if char == '\n' {
+                output.push_str(NEW_LINE);
+            } else {
+                output.push(char);
+            }
+
+            rest = &rest[char.len_utf8()..];
+        }
+    }
+
+    output
+}
+
+/// Unescapes the text from a `.txt` file of the given format version.
+pub fn unescape(text: &str, version: u8) -> String {
+    if version < 2 {
+        return text.replace(NEW_LINE, "\n");
+    }
+
+    if !text.contains('#') {
+        return text.to_owned();
+    }
+
+    let mut output: String = String::with_capacity(text.len());
+    let mut rest: &str = text;
+
+    while let Some(char) = rest.chars().next() {
+        if let Some(stripped) = rest.strip_prefix(ESCAPED_SEPARATOR) {
+            output.push_str("<#");
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix(ESCAPED_NEW_LINE) {
+            output.push_str(NEW_LINE);
+            rest = stripped;
+        } else if let Some(stripped) = rest.strip_prefix(NEW_LINE) {
+            output.push('\n');
+            rest = stripped;
+        } else {
+            output.push(char);
+            rest = &rest[char.len_utf8()..];
+        }
+    }
+
+    output
+}
+
+/// Returns the format version of the `.txt` content.
+#[inline]
+pub fn format_version(translation: &str) -> u8 {
+    translation
+        .split('\n')
+        .next()
+        .and_then(|line| line.strip_prefix(FORMAT_COMMENT))
+        .and_then(|line| line.strip_prefix(LINES_SEPARATOR))
+        .and_then(|version| version.trim().parse().ok())
+        .unwrap_or(1)
+}
+
+/// Prepends the current format comment to the `.txt` content, replacing the existing one.
+#[inline]
+pub(crate) fn with_format(content: String) -> String {
+    let content: &str = if content.starts_with(FORMAT_COMMENT) {
+        content.split_once('\n').map_or("", |(_, rest)| rest)
+    } else {
+        &content
+    };
+
+    format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n{content}")
+}
+
+/// Parses `original<#>translation` pairs from the `.txt` content.
+///
+/// The format and header comments are skipped. When `write` is `true`, comments are skipped too, and
+/// both parts are unescaped according to the format version of the content. Lines without the separator are
+/// reported to the `observer`.
 #[inline]
 pub fn parse_translation<'a>(
     translation: &'a str,
     file: &'a str,
     write: bool,
     trim: bool,
+    observer: &'a dyn Observer,
 ) -> Box<dyn Iterator<Item = (String, String)> + 'a> {
+    let version: u8 = format_version(translation);
+
     Box::new(translation.split('\n').enumerate().filter_map(move |(i, line)| {
-        if write && line.starts_with("<!--") {
+        if (write && line.starts_with("<!--")) || is_header_line(line) {
             return None;
         }
 
@@ -640,25 +814,121 @@ pub fn parse_translation<'a>(
 
                 if trim {
                     Some((
-                        original.replace(NEW_LINE, "\n").trim_replace(),
-                        translation.replace(NEW_LINE, "\n").trim_replace(),
+                        unescape(original, version).trim_replace(),
+                        unescape(translation, version).trim_replace(),
                     ))
                 } else {
-                    Some((original.replace(NEW_LINE, "\n"), translation.replace(NEW_LINE, "\n")))
+                    Some((unescape(original, version), unescape(translation, version)))
                 }
             } else {
                 Some((original.to_owned(), translation.to_owned()))
             }
         } else {
-            eprintln!(
-                "{COULD_NOT_SPLIT_LINE_MSG} ({line})\n{AT_POSITION_MSG} {i}\n{IN_FILE_MSG} {file}",
-                i = i + 1,
-            );
+            observer.warning(Warning::UnsplittableLine {
+                file: file.to_owned(),
+                line: i + 1,
+                content: line.to_owned(),
+            });
             None
         }
     }))
 }
 
+/// Returns every non-comment line of the translation, that has no translation.
+#[inline]
+pub(crate) fn get_untranslated(translation: &str, file: &str) -> Vec<UntranslatedLine> {
+    let version: u8 = format_version(translation);
+
+    translation
+        .split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with("<!--"))
+        .filter_map(|(i, line)| {
+            let mut split = line.split(LINES_SEPARATOR);
+            let original: &str = split.next().unwrap_log();
+
+            if split.clone().next().is_none() || split.any(|x| !x.is_empty()) {
+                return None;
+            }
+
+            Some(UntranslatedLine {
+                file: file.to_owned(),
+                line: i + 1,
+                original: unescape(original, version),
+            })
+        })
+        .collect()
+}
+
+/// Joins the path of an object with the key of its value, like `events[1].pages`.
+#[inline]
+pub(crate) fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Returns the text of the value, if it's a string or a Marshal bytes object.
+#[inline]
+pub(crate) fn as_text(value: &Value) -> Option<(String, bool)> {
+    if let Some(str) = value.as_str() {
+        return Some((str.to_owned(), false));
+    }
+
+    let obj = value.as_object()?;
+
+    if obj.get(&"__type").and_then(|x| x.as_str()) != Some("bytes") {
+        return None;
+    }
+
+    let bytes: Vec<u8> = get_object_data(obj);
+    Some((String::from_utf8_lossy(&bytes).into_owned(), true))
+}
+
+/// Collects every string that differs between `original` and `written` values.
+///
+/// Used by writers in dry run to report the replacements they would make.
+pub(crate) fn collect_replacements(
+    file: &str,
+    path: &str,
+    original: &Value,
+    written: &Value,
+    replacements: &mut Vec<Replacement>,
+) {
+    if let (Some((original_text, _)), Some((written_text, _))) = (as_text(original), as_text(written)) {
+        if original_text != written_text {
+            replacements.push(Replacement {
+                file: file.to_owned(),
+                location: path.to_owned(),
+                original: original_text,
+                translation: written_text,
+            });
+        }
+
+        return;
+    }
+
+    if let (Some(original_obj), Some(written_obj)) = (original.as_object(), written.as_object()) {
+        for (key, value) in original_obj.iter() {
+            if let Some(written_value) = written_obj.get(&key) {
+                collect_replacements(file, &join_key(path, key), value, written_value, replacements);
+            }
+        }
+    } else if let (Some(original_arr), Some(written_arr)) = (original.as_array(), written.as_array()) {
+        for (i, (original_value, written_value)) in original_arr.iter().zip(written_arr.iter()).enumerate() {
+            collect_replacements(
+                file,
+                &format!("{path}[{i}]"),
+                original_value,
+                written_value,
+                replacements,
+            );
+        }
+    }
+}
+
 pub fn parse_ignore(ignore_file_path: PathBuf) -> IndexMap<String, HashSet<String, GxBuildHasher>, GxBuildHasher> {
     let mut map: IndexMap<String, HashSet<String, GxBuildHasher>, GxBuildHasher> = IndexMap::default();
 
@@ -976,6 +1246,7 @@ pub fn process_variable(
 
 #[allow(
     clippy::single_match,
+    clippy::collapsible_match,
     clippy::match_single_binding,
     clippy::too_many_arguments,
     unused_mut,
@@ -1108,3 +1379,56 @@ pub fn parse_rpgm_file(path: &Path, engine_type: EngineType) -> Value {
         _ => load(&read(path).unwrap_log(), Some(StringMode::UTF8), Some("")).unwrap_log(),
     }
 }
+
+/// Applies `f` to files in parallel, yielding results in the order of `files`.
+///
+/// Files are processed in batches, so only results of a single batch are held in memory at once, and the
+/// output doesn't depend on the scheduling of threads.
+///
+/// # Parameters
+///
+/// - `files` - File names and paths
+/// - `threads` - Maximum number of used threads, `0` means the number of logical CPUs
+/// - `f` - Function, applied to the name and the path of every file
+pub fn par_map_ordered<T, F>(
+    files: Vec<(String, PathBuf)>,
+    threads: usize,
+    f: F,
+) -> impl Iterator<Item = (String, PathBuf, T)>
+where
+    T: Send,
+    F: Fn(&str, &Path) -> T + Sync + Send,
+{
+    let pool: Option<ThreadPool> =
+        (threads != 0).then(|| ThreadPoolBuilder::new().num_threads(threads).build().unwrap_log());
+    let batch_size: usize = pool
+        .as_ref()
+        .map_or_else(rayon::current_num_threads, ThreadPool::current_num_threads)
+        * 4;
+
+    let mut files = files.into_iter();
+
+    std::iter::from_fn(move || {
+        let batch: Vec<(String, PathBuf)> = files.by_ref().take(batch_size).collect();
+
+        if batch.is_empty() {
+            return None;
+        }
+
+        let process = || -> Vec<(String, PathBuf, T)> {
+            batch
+                .into_par_iter()
+                .map(|(filename, path)| {
+                    let result: T = f(&filename, &path);
+                    (filename, path, result)
+                })
+                .collect()
+        };
+
+        Some(match &pool {
+            Some(pool) => pool.install(process),
+            None => process(),
+        })
+    })
+    .flatten()
+}
@@ -0,0 +1,207 @@
+use crate::{
+    functions::with_format,
+    observer::{Observer, Warning},
+    statics::{FORMAT_COMMENT, LINES_SEPARATOR},
+    types::{EngineType, HeaderMode, MapsProcessingMode},
+};
+use std::{fmt::Write, fs::read_to_string, path::Path};
+
+/// Prefix of the comments, which hold the header of a `.txt` file.
+///
+/// The full comment is `<!-- Header: key --><#>value`. Header comments directly follow the format comment.
+pub const HEADER_COMMENT_PREFIX: &str = "<!-- Header: ";
+
+/// Settings, which were used to produce a `.txt` file.
+///
+/// Settings, that don't apply to the reader, which produced the file, are `None`.
+///
+/// # Fields
+///
+/// - `version` - Version of the crate
+/// - `engine_type` - Engine type of the game
+/// - `maps_processing_mode` - Maps processing mode, only written to `maps.txt`
+/// - `romanize` - Whether the text was romanized
+/// - `trim` - Whether the text was trimmed
+#[derive(PartialEq, Clone)]
+pub struct Header {
+    pub version: String,
+    pub engine_type: Option<EngineType>,
+    pub maps_processing_mode: Option<MapsProcessingMode>,
+    pub romanize: Option<bool>,
+    pub trim: Option<bool>,
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            engine_type: None,
+            maps_processing_mode: None,
+            romanize: None,
+            trim: None,
+        }
+    }
+}
+
+impl Header {
+    /// Parses the header from the `.txt` content.
+    ///
+    /// # Returns
+    ///
+    /// The header, if the content has any header comments, otherwise `None`.
+    pub fn parse(translation: &str) -> Option<Header> {
+        let mut header: Header = Header {
+            version: String::new(),
+            ..Header::default()
+        };
+        let mut found: bool = false;
+
+        for line in translation.split('\n') {
+            if line.starts_with(FORMAT_COMMENT) {
+                continue;
+            }
+
+            let Some((key, value)) = line
+                .strip_prefix(HEADER_COMMENT_PREFIX)
+                .and_then(|line| line.split_once(LINES_SEPARATOR))
+            else {
+                break;
+            };
+
+            found = true;
+
+            match key.trim_end_matches(" -->") {
+                "version" => header.version = value.to_owned(),
+                "engine" => header.engine_type = EngineType::parse(value),
+                "maps" => header.maps_processing_mode = MapsProcessingMode::parse(value),
+                "romanize" => header.romanize = value.trim().parse().ok(),
+                "trim" => header.trim = value.trim().parse().ok(),
+                _ => {}
+            }
+        }
+
+        found.then_some(header)
+    }
+
+    /// Returns the header comment lines, each terminated with a new line.
+    pub fn to_comments(&self) -> String {
+        let mut output: String = String::new();
+
+        let _ = writeln!(output, "{HEADER_COMMENT_PREFIX}version -->{LINES_SEPARATOR}{}", self.version);
+
+        if let Some(engine_type) = self.engine_type {
+            let _ = writeln!(output, "{HEADER_COMMENT_PREFIX}engine -->{LINES_SEPARATOR}{}", engine_type.as_str());
+        }
+
+        if let Some(maps_processing_mode) = self.maps_processing_mode {
+            let _ = writeln!(
+                output,
+                "{HEADER_COMMENT_PREFIX}maps -->{LINES_SEPARATOR}{}",
+                maps_processing_mode.as_str()
+            );
+        }
+
+        if let Some(romanize) = self.romanize {
+            let _ = writeln!(output, "{HEADER_COMMENT_PREFIX}romanize -->{LINES_SEPARATOR}{romanize}");
+        }
+
+        if let Some(trim) = self.trim {
+            let _ = writeln!(output, "{HEADER_COMMENT_PREFIX}trim -->{LINES_SEPARATOR}{trim}");
+        }
+
+        output
+    }
+
+    /// Returns the names of settings, that are present in both headers, but have different values.
+    ///
+    /// Crate version isn't compared.
+    pub fn mismatches(&self, other: &Header) -> Vec<&'static str> {
+        let mut mismatches: Vec<&'static str> = Vec::new();
+
+        if matches!((self.engine_type, other.engine_type), (Some(a), Some(b)) if a != b) {
+            mismatches.push("engine");
+        }
+
+        if matches!((self.maps_processing_mode, other.maps_processing_mode), (Some(a), Some(b)) if a != b) {
+            mismatches.push("maps");
+        }
+
+        if matches!((self.romanize, other.romanize), (Some(a), Some(b)) if a != b) {
+            mismatches.push("romanize");
+        }
+
+        if matches!((self.trim, other.trim), (Some(a), Some(b)) if a != b) {
+            mismatches.push("trim");
+        }
+
+        mismatches
+    }
+
+    /// Overrides settings with ones, present in the `other` header.
+    pub fn configure(&mut self, other: &Header) {
+        self.engine_type = other.engine_type.or(self.engine_type);
+        self.maps_processing_mode = other.maps_processing_mode.or(self.maps_processing_mode);
+        self.romanize = other.romanize.or(self.romanize);
+        self.trim = other.trim.or(self.trim);
+    }
+}
+
+/// Returns whether the line is a format or header comment.
+#[inline]
+pub(crate) fn is_header_line(line: &str) -> bool {
+    line.starts_with(FORMAT_COMMENT) || line.starts_with(HEADER_COMMENT_PREFIX)
+}
+
+/// Replaces the format and header comments of the `.txt` content with the current format comment and the `header`.
+///
+/// If `header` is `None`, only the format comment is written.
+pub(crate) fn with_header(content: String, header: Option<&Header>) -> String {
+    let mut output: String = header.map(Header::to_comments).unwrap_or_default();
+
+    if content.split('\n').any(is_header_line) {
+        for line in content.split('\n').filter(|line| !is_header_line(line)) {
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        output.pop();
+    } else {
+        output.push_str(&content);
+    }
+
+    with_format(output)
+}
+
+/// Applies the header of the `.txt` file at `path` to `settings` according to `mode`.
+///
+/// # Returns
+///
+/// `false` if settings don't match the header in validate mode, meaning that the file should be skipped.
+/// Mismatches are reported to the `observer`.
+pub(crate) fn apply_header(path: &Path, mode: HeaderMode, settings: &mut Header, observer: &dyn Observer) -> bool {
+    if mode.is_ignore() {
+        return true;
+    }
+
+    let Some(file_header) = read_to_string(path).ok().and_then(|content| Header::parse(&content)) else {
+        return true;
+    };
+
+    match mode {
+        HeaderMode::Ignore => {}
+        HeaderMode::Validate => {
+            let mismatches: Vec<&str> = settings.mismatches(&file_header);
+
+            if !mismatches.is_empty() {
+                observer.warning(Warning::HeaderMismatch {
+                    file: path.display().to_string(),
+                    mismatches: mismatches.into_iter().map(str::to_owned).collect(),
+                });
+                return false;
+            }
+        }
+        HeaderMode::Configure => settings.configure(&file_header),
+    }
+
+    true
+}
@@ -0,0 +1,733 @@
+use crate::{
+    comments::is_meta_comment,
+    determine_extension,
+    layout::{maps_exist, read_maps, MAPS_DIRECTORY},
+    observer::{resolve_observer, Observer, Warning},
+    read::{MapReader, OtherReader, PluginReader, ScriptReader, SystemReader},
+    statics::LINES_SEPARATOR,
+    types::{
+        EngineType, FileFilter, GameType, HeaderMode, LineState, MapsLayout, MapsProcessingMode, ProcessingMode,
+        ResultExt, WriteReport,
+    },
+    write::{MapWriter, OtherWriter, PluginWriter, ScriptWriter, SystemWriter},
+};
+use std::{
+    fs::{create_dir_all, read_dir, read_to_string},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+/// Returns the languages of a multi-language translation project.
+///
+/// In a multi-language project, the translation directory holds a subdirectory per language,
+/// named after the language, like `translation/en` and `translation/fr`. Every subdirectory has its
+/// own set of `.txt` files, produced from the same original files.
+///
+/// # Parameters
+///
+/// - `translation_path` - Path to the root translation directory
+///
+/// # Returns
+///
+/// Sorted names of subdirectories, that contain at least one `.txt` file.
+pub fn find_languages<P: AsRef<Path>>(translation_path: P) -> Vec<String> {
+    let Ok(entries) = read_dir(translation_path) else {
+        return Vec::new();
+    };
+
+    let mut languages: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|x| x.is_dir()))
+        .filter(|entry| !txt_files(&entry.path()).is_empty())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    languages.sort_unstable();
+    languages
+}
+
+/// Returns sorted names of `.txt` files in the directory.
+fn txt_files(path: &Path) -> Vec<String> {
+    let Ok(entries) = read_dir(path) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|x| x == "txt"))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+/// Returns the original parts of `.txt` content lines, except for the comments attached to lines.
+fn originals(content: &str) -> Vec<&str> {
+    content
+        .split('\n')
+        .filter_map(|line| line.split_once(LINES_SEPARATOR))
+        .map(|(original, _)| original)
+        .filter(|original| !is_meta_comment(original))
+        .collect()
+}
+
+/// Settings of the writers, that [`LanguageWriter`] runs for every language.
+///
+/// Every field has the same meaning and default value, as the setter of the same name of the writers.
+/// Settings, that don't apply to a writer, are ignored by it.
+///
+/// # Fields
+///
+/// - `maps_processing_mode` - Controls how maps are processed
+/// - `romanize` - Whether to romanize non-Latin text
+/// - `logging` - Whether to log processing information
+/// - `game_type` - Optional specific game type for specialized processing
+/// - `trim` - Whether to trim whitespace from strings
+/// - `dry_run` - Whether to only report replacements, without writing files
+/// - `skip_states` - States of lines, that are left untranslated
+/// - `skip_stateless` - Whether to leave lines without a state untranslated
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `map_names` - Whether to write translated map names
+/// - `event_names` - Whether to write translated map event names
+/// - `dev_names` - Whether to write translated names of switches and variables
+/// - `excluded_files` - Names of data files, that aren't written
+/// - `included_files` - Names of excluded data files, that are written anyway
+/// - `maps_layout` - Layout of the maps translation
+/// - `file_filter` - Filter of written files
+/// - `force` - Whether to write every file, ignoring the cache of written files
+/// - `streaming` - Whether to write maps block by block
+/// - `observer` - Observer of progress and events, shared by writers of every language
+#[derive(Clone)]
+pub struct WriterOptions {
+    pub maps_processing_mode: MapsProcessingMode,
+    pub romanize: bool,
+    pub logging: bool,
+    pub game_type: Option<GameType>,
+    pub trim: bool,
+    pub dry_run: bool,
+    pub skip_states: Vec<LineState>,
+    pub skip_stateless: bool,
+    pub header_mode: HeaderMode,
+    pub map_names: bool,
+    pub event_names: bool,
+    pub dev_names: bool,
+    pub excluded_files: Option<Vec<String>>,
+    pub included_files: Vec<String>,
+    pub maps_layout: MapsLayout,
+    pub file_filter: FileFilter,
+    pub force: bool,
+    pub streaming: bool,
+    pub observer: Option<Arc<dyn Observer>>,
+}
+
+impl Default for WriterOptions {
+    fn default() -> Self {
+        Self {
+            maps_processing_mode: MapsProcessingMode::Default,
+            romanize: false,
+            logging: false,
+            game_type: None,
+            trim: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            map_names: false,
+            event_names: false,
+            dev_names: false,
+            excluded_files: None,
+            included_files: Vec::new(),
+            maps_layout: MapsLayout::Single,
+            file_filter: FileFilter::default(),
+            force: false,
+            streaming: false,
+            observer: None,
+        }
+    }
+}
+
+/// Settings of the readers, that [`LanguageReader`] runs for every language.
+///
+/// Every field has the same meaning and default value, as the setter of the same name of the readers.
+/// Settings, that don't apply to a reader, are ignored by it.
+///
+/// # Fields
+///
+/// - `processing_mode` - Controls how files are processed
+/// - `maps_processing_mode` - Controls how maps are processed
+/// - `romanize` - Whether to romanize non-Latin text
+/// - `logging` - Whether to log processing information
+/// - `game_type` - Optional specific game type for specialized processing
+/// - `ignore` - Whether to ignore entries from the `.rvpacker-ignore` file
+/// - `trim` - Whether to trim whitespace from strings
+/// - `sort` - Whether to sort lines
+/// - `keyed` - Whether to read in keyed mode
+/// - `ids` - Whether to precede lines with ID comments
+/// - `event_names` - Whether to read map event names
+/// - `dev_names` - Whether to read names of switches and variables
+/// - `maps_layout` - Layout of the maps translation
+/// - `tree_order` - Whether to order maps by the map tree
+/// - `excluded_files` - Names of data files, that aren't read
+/// - `included_files` - Names of excluded data files, that are read anyway
+/// - `file_filter` - Filter of read files
+/// - `threads` - Number of threads, used to parse files
+/// - `streaming` - Whether to read maps block by block
+/// - `observer` - Observer of progress and events, shared by readers of every language
+#[derive(Clone)]
+pub struct ReaderOptions {
+    pub processing_mode: ProcessingMode,
+    pub maps_processing_mode: MapsProcessingMode,
+    pub romanize: bool,
+    pub logging: bool,
+    pub game_type: Option<GameType>,
+    pub ignore: bool,
+    pub trim: bool,
+    pub sort: bool,
+    pub keyed: bool,
+    pub ids: bool,
+    pub event_names: bool,
+    pub dev_names: bool,
+    pub maps_layout: MapsLayout,
+    pub tree_order: bool,
+    pub excluded_files: Option<Vec<String>>,
+    pub included_files: Vec<String>,
+    pub file_filter: FileFilter,
+    pub threads: usize,
+    pub streaming: bool,
+    pub observer: Option<Arc<dyn Observer>>,
+}
+
+impl Default for ReaderOptions {
+    fn default() -> Self {
+        Self {
+            processing_mode: ProcessingMode::Default,
+            maps_processing_mode: MapsProcessingMode::Default,
+            romanize: false,
+            logging: false,
+            game_type: None,
+            ignore: false,
+            trim: false,
+            sort: false,
+            keyed: false,
+            ids: false,
+            event_names: false,
+            dev_names: false,
+            maps_layout: MapsLayout::Single,
+            tree_order: false,
+            excluded_files: None,
+            included_files: Vec::new(),
+            file_filter: FileFilter::default(),
+            threads: 0,
+            streaming: false,
+            observer: None,
+        }
+    }
+}
+
+/// A struct for writing translation of a multi-language project.
+///
+/// `LanguageWriter` runs writers for every language, so each language gets its own output tree.
+/// Data files are written to `output_path/language/data_dir`, where `data_dir` is the name of the
+/// original data directory, and `plugins.js` is written to `output_path/language/js`.
+///
+/// # Fields
+///
+/// - `original_path` - Path to the directory containing the original data files
+/// - `translation_path` - Path to the root translation directory with a subdirectory per language
+/// - `output_path` - Path to the directory where output trees will be written
+/// - `engine_type` - The RPG Maker engine type
+/// - `languages` - Languages to write. If empty, every language in the translation directory is written
+/// - `plugins_file_path` - Optional path to the `plugins.js` file
+/// - `options` - Settings of the writers of every language
+pub struct LanguageWriter<P: AsRef<Path> + Sync> {
+    original_path: P,
+    translation_path: P,
+    output_path: P,
+    engine_type: EngineType,
+    languages: Vec<String>,
+    plugins_file_path: Option<P>,
+    options: WriterOptions,
+}
+
+impl<P: AsRef<Path> + Sync> LanguageWriter<P> {
+    /// Creates a new `LanguageWriter` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `translation_path` - Path to the root translation directory with a subdirectory per language
+    /// - `output_path` - Path to the directory where output trees will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn new(original_path: P, translation_path: P, output_path: P, engine_type: EngineType) -> Self {
+        Self::default(original_path, translation_path, output_path, engine_type)
+    }
+
+    /// Creates a new `LanguageWriter` with default values.
+    ///
+    /// Default values are:
+    /// - `languages`: empty
+    /// - `plugins_file_path`: `None`
+    /// - `options`: `WriterOptions::default()`
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `translation_path` - Path to the root translation directory with a subdirectory per language
+    /// - `output_path` - Path to the directory where output trees will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn default(original_path: P, translation_path: P, output_path: P, engine_type: EngineType) -> Self {
+        Self {
+            original_path,
+            translation_path,
+            output_path,
+            engine_type,
+            languages: Vec::new(),
+            plugins_file_path: None,
+            options: WriterOptions::default(),
+        }
+    }
+
+    /// Sets the languages to write.
+    ///
+    /// If empty, every language in the translation directory is written.
+    pub fn languages(mut self, languages: Vec<String>) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    /// Sets the path to the `plugins.js` file.
+    ///
+    /// When set, `plugins.js` is written for every language along with the data files.
+    pub fn plugins_file_path(mut self, plugins_file_path: Option<P>) -> Self {
+        self.plugins_file_path = plugins_file_path;
+        self
+    }
+
+    /// Sets the settings of the writers of every language.
+    ///
+    /// Settings, that must be the same as in previous read, must be the same for every language.
+    pub fn options(mut self, options: WriterOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// This method writes the translation of every language into its own output tree.
+    ///
+    /// # Returns
+    ///
+    /// Every written language, mapped to its [`WriteReport`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{
+    ///     language::{LanguageWriter, WriterOptions},
+    ///     types::EngineType,
+    /// };
+    ///
+    /// let reports = LanguageWriter::new("data", "translation", "output", EngineType::New)
+    ///     .plugins_file_path(Some("js/plugins.js"))
+    ///     .options(WriterOptions {
+    ///         dry_run: true,
+    ///         ..WriterOptions::default()
+    ///     })
+    ///     .write();
+    ///
+    /// for (language, report) in reports {
+    ///     println!("{language}: {} untranslated", report.untranslated.len());
+    /// }
+    /// ```
+    pub fn write(self) -> Vec<(String, WriteReport)> {
+        let languages: Vec<String> = if self.languages.is_empty() {
+            find_languages(self.translation_path.as_ref())
+        } else {
+            self.languages.clone()
+        };
+
+        languages
+            .into_iter()
+            .map(|language| {
+                let report: WriteReport = self.write_language(&language);
+                (language, report)
+            })
+            .collect()
+    }
+
+    fn write_language(&self, language: &str) -> WriteReport {
+        let options: &WriterOptions = &self.options;
+        let original_path: &Path = self.original_path.as_ref();
+        let translation_path: PathBuf = self.translation_path.as_ref().join(language);
+        let language_output_path: PathBuf = self.output_path.as_ref().join(language);
+        let output_path: PathBuf = language_output_path.join(original_path.file_name().unwrap_or("data".as_ref()));
+
+        if !options.dry_run {
+            create_dir_all(&output_path).unwrap_log();
+        }
+
+        let extension: &str = determine_extension(self.engine_type);
+        let system_file_path: PathBuf = original_path.join(format!("System{extension}"));
+        let scripts_file_path: PathBuf = original_path.join(format!("Scripts{extension}"));
+
+        let observer: Arc<dyn Observer> = resolve_observer(&options.observer, options.logging);
+        let mut report: WriteReport = WriteReport::default();
+
+        if maps_exist(&translation_path, options.maps_layout) {
+            report.extend(
+                MapWriter::new(original_path, &translation_path, &output_path, self.engine_type)
+                    .maps_processing_mode(options.maps_processing_mode)
+                    .romanize(options.romanize)
+                    .logging(options.logging)
+                    .game_type(options.game_type)
+                    .trim(options.trim)
+                    .dry_run(options.dry_run)
+                    .skip_states(options.skip_states.clone())
+                    .skip_stateless(options.skip_stateless)
+                    .header_mode(options.header_mode)
+                    .map_names(options.map_names)
+                    .event_names(options.event_names)
+                    .maps_layout(options.maps_layout)
+                    .file_filter(options.file_filter.clone())
+                    .force(options.force)
+                    .streaming(options.streaming)
+                    .observer(observer.clone())
+                    .write(),
+            );
+        }
+
+        report.extend(
+            OtherWriter::new(original_path, &translation_path, &output_path, self.engine_type)
+                .romanize(options.romanize)
+                .logging(options.logging)
+                .game_type(options.game_type)
+                .trim(options.trim)
+                .dry_run(options.dry_run)
+                .skip_states(options.skip_states.clone())
+                .skip_stateless(options.skip_stateless)
+                .header_mode(options.header_mode)
+                .excluded_files(options.excluded_files.clone())
+                .included_files(options.included_files.clone())
+                .file_filter(options.file_filter.clone())
+                .force(options.force)
+                .observer(observer.clone())
+                .write(),
+        );
+
+        if translation_path.join("system.txt").exists() {
+            report.extend(
+                SystemWriter::new(&system_file_path, &translation_path, &output_path, self.engine_type)
+                    .romanize(options.romanize)
+                    .logging(options.logging)
+                    .trim(options.trim)
+                    .dry_run(options.dry_run)
+                    .skip_states(options.skip_states.clone())
+                    .skip_stateless(options.skip_stateless)
+                    .header_mode(options.header_mode)
+                    .dev_names(options.dev_names)
+                    .file_filter(options.file_filter.clone())
+                    .observer(observer.clone())
+                    .write(),
+            );
+        }
+
+        if !self.engine_type.is_new() && scripts_file_path.exists() && translation_path.join("scripts.txt").exists() {
+            report.extend(
+                ScriptWriter::new(&scripts_file_path, &translation_path, &output_path)
+                    .romanize(options.romanize)
+                    .logging(options.logging)
+                    .dry_run(options.dry_run)
+                    .skip_states(options.skip_states.clone())
+                    .skip_stateless(options.skip_stateless)
+                    .header_mode(options.header_mode)
+                    .file_filter(options.file_filter.clone())
+                    .observer(observer.clone())
+                    .write(),
+            );
+        }
+
+        if let Some(plugins_file_path) = &self.plugins_file_path {
+            if translation_path.join("plugins.txt").exists() {
+                let plugins_output_path: PathBuf = language_output_path.join("js");
+
+                if !options.dry_run {
+                    create_dir_all(&plugins_output_path).unwrap_log();
+                }
+
+                report.extend(
+                    PluginWriter::new(plugins_file_path.as_ref(), &translation_path, &plugins_output_path)
+                        .romanize(options.romanize)
+                        .logging(options.logging)
+                        .dry_run(options.dry_run)
+                        .skip_states(options.skip_states.clone())
+                        .skip_stateless(options.skip_stateless)
+                        .header_mode(options.header_mode)
+                        .file_filter(options.file_filter.clone())
+                        .observer(observer.clone())
+                        .write(),
+                );
+            }
+        }
+
+        report
+    }
+}
+
+/// A struct for reading original files into every language of a multi-language project.
+///
+/// `LanguageReader` runs readers with the same settings for every language, so all languages
+/// share the same original lines. Languages, that don't have any `.txt` files yet, are read from
+/// scratch, even if `processing_mode` is `Append`. After reading, original lines of every language
+/// are compared with the first one, and every mismatched file is reported as
+/// [`Warning::OriginalsMismatch`].
+///
+/// Translation memories and packs are specific to a language, so they aren't applied. Use the readers
+/// of a single language for them.
+///
+/// # Fields
+///
+/// - `original_path` - Path to the directory containing the original data files
+/// - `translation_path` - Path to the root translation directory with a subdirectory per language
+/// - `engine_type` - The RPG Maker engine type
+/// - `languages` - Languages to read. If empty, every language in the translation directory is read
+/// - `plugins_file_path` - Optional path to the `plugins.js` file
+/// - `options` - Settings of the readers of every language
+pub struct LanguageReader<P: AsRef<Path>> {
+    original_path: P,
+    translation_path: P,
+    engine_type: EngineType,
+    languages: Vec<String>,
+    plugins_file_path: Option<P>,
+    options: ReaderOptions,
+}
+
+impl<P: AsRef<Path>> LanguageReader<P> {
+    /// Creates a new `LanguageReader` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `translation_path` - Path to the root translation directory with a subdirectory per language
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn new(original_path: P, translation_path: P, engine_type: EngineType) -> Self {
+        Self::default(original_path, translation_path, engine_type)
+    }
+
+    /// Creates a new `LanguageReader` with default values.
+    ///
+    /// Default values are:
+    /// - `languages`: empty
+    /// - `plugins_file_path`: `None`
+    /// - `options`: `ReaderOptions::default()`
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `translation_path` - Path to the root translation directory with a subdirectory per language
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn default(original_path: P, translation_path: P, engine_type: EngineType) -> Self {
+        Self {
+            original_path,
+            translation_path,
+            engine_type,
+            languages: Vec::new(),
+            plugins_file_path: None,
+            options: ReaderOptions::default(),
+        }
+    }
+
+    /// Sets the languages to read.
+    ///
+    /// If empty, every language in the translation directory is read. New languages must be set
+    /// explicitly, their directories are created.
+    pub fn languages(mut self, languages: Vec<String>) -> Self {
+        self.languages = languages;
+        self
+    }
+
+    /// Sets the path to the `plugins.js` file.
+    ///
+    /// When set, `plugins.js` is read for every language along with the data files.
+    pub fn plugins_file_path(mut self, plugins_file_path: Option<P>) -> Self {
+        self.plugins_file_path = plugins_file_path;
+        self
+    }
+
+    /// Sets the settings of the readers of every language.
+    pub fn options(mut self, options: ReaderOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// This method reads the original files into every language, and checks that original lines
+    /// of all languages match.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{
+    ///     language::{LanguageReader, ReaderOptions},
+    ///     types::{EngineType, ProcessingMode},
+    /// };
+    ///
+    /// LanguageReader::new("data", "translation", EngineType::New)
+    ///     .languages(vec!["en".into(), "fr".into(), "de".into()])
+    ///     .options(ReaderOptions {
+    ///         processing_mode: ProcessingMode::Append,
+    ///         ..ReaderOptions::default()
+    ///     })
+    ///     .read();
+    /// ```
+    pub fn read(self) {
+        let languages: Vec<String> = if self.languages.is_empty() {
+            find_languages(self.translation_path.as_ref())
+        } else {
+            self.languages.clone()
+        };
+
+        let observer: Arc<dyn Observer> = resolve_observer(&self.options.observer, self.options.logging);
+
+        for language in &languages {
+            self.read_language(language, &observer);
+        }
+
+        self.compare_originals(&languages, &observer);
+    }
+
+    fn read_language(&self, language: &str, observer: &Arc<dyn Observer>) {
+        let options: &ReaderOptions = &self.options;
+        let original_path: &Path = self.original_path.as_ref();
+        let output_path: PathBuf = self.translation_path.as_ref().join(language);
+
+        // Appending to a new language would skip every file
+        let processing_mode: ProcessingMode =
+            if options.processing_mode.is_append() && txt_files(&output_path).is_empty() {
+                ProcessingMode::Default
+            } else {
+                options.processing_mode
+            };
+
+        create_dir_all(&output_path).unwrap_log();
+
+        let extension: &str = determine_extension(self.engine_type);
+        let system_file_path: PathBuf = original_path.join(format!("System{extension}"));
+        let scripts_file_path: PathBuf = original_path.join(format!("Scripts{extension}"));
+
+        MapReader::new(original_path, &output_path, self.engine_type)
+            .maps_processing_mode(options.maps_processing_mode)
+            .romanize(options.romanize)
+            .logging(options.logging)
+            .game_type(options.game_type)
+            .processing_mode(processing_mode)
+            .ignore(options.ignore)
+            .trim(options.trim)
+            .sort(options.sort)
+            .keyed(options.keyed)
+            .ids(options.ids)
+            .event_names(options.event_names)
+            .maps_layout(options.maps_layout)
+            .tree_order(options.tree_order)
+            .file_filter(options.file_filter.clone())
+            .threads(options.threads)
+            .streaming(options.streaming)
+            .observer(observer.clone())
+            .read();
+
+        OtherReader::new(original_path, &output_path, self.engine_type)
+            .romanize(options.romanize)
+            .logging(options.logging)
+            .game_type(options.game_type)
+            .processing_mode(processing_mode)
+            .ignore(options.ignore)
+            .trim(options.trim)
+            .sort(options.sort)
+            .keyed(options.keyed)
+            .ids(options.ids)
+            .excluded_files(options.excluded_files.clone())
+            .included_files(options.included_files.clone())
+            .file_filter(options.file_filter.clone())
+            .threads(options.threads)
+            .observer(observer.clone())
+            .read();
+
+        if system_file_path.exists() {
+            SystemReader::new(&system_file_path, &output_path, self.engine_type)
+                .romanize(options.romanize)
+                .logging(options.logging)
+                .processing_mode(processing_mode)
+                .ignore(options.ignore)
+                .trim(options.trim)
+                .sort(options.sort)
+                .dev_names(options.dev_names)
+                .file_filter(options.file_filter.clone())
+                .observer(observer.clone())
+                .read();
+        }
+
+        if !self.engine_type.is_new() && scripts_file_path.exists() {
+            ScriptReader::new(&scripts_file_path, &output_path)
+                .romanize(options.romanize)
+                .logging(options.logging)
+                .processing_mode(processing_mode)
+                .ignore(options.ignore)
+                .sort(options.sort)
+                .file_filter(options.file_filter.clone())
+                .observer(observer.clone())
+                .read();
+        }
+
+        if let Some(plugins_file_path) = &self.plugins_file_path {
+            PluginReader::new(plugins_file_path.as_ref(), &output_path)
+                .romanize(options.romanize)
+                .logging(options.logging)
+                .processing_mode(processing_mode)
+                .ignore(options.ignore)
+                .sort(options.sort)
+                .file_filter(options.file_filter.clone())
+                .observer(observer.clone())
+                .read();
+        }
+    }
+
+    /// Reports every file of every language, which original lines don't match the ones of the first language.
+    fn compare_originals(&self, languages: &[String], observer: &Arc<dyn Observer>) {
+        let Some((reference, languages)) = languages.split_first() else {
+            return;
+        };
+
+        let maps_layout: MapsLayout = self.options.maps_layout;
+        let reference_path: PathBuf = self.translation_path.as_ref().join(reference);
+        let mut files: Vec<String> = txt_files(&reference_path);
+
+        if maps_layout.is_per_map() && maps_exist(&reference_path, maps_layout) {
+            files.push(MAPS_DIRECTORY.to_owned());
+        }
+
+        // Maps of per-map layouts are compared as a whole
+        let read_file = |path: &Path, file: &str| -> Option<String> {
+            if file == MAPS_DIRECTORY {
+                maps_exist(path, maps_layout).then(|| read_maps(path, maps_layout))
+            } else {
+                read_to_string(path.join(file)).ok()
+            }
+        };
+
+        for file in files {
+            let Some(reference_content) = read_file(&reference_path, &file) else {
+                continue;
+            };
+            let reference_originals: Vec<&str> = originals(&reference_content);
+
+            for language in languages {
+                let language_path: PathBuf = self.translation_path.as_ref().join(language);
+
+                if !read_file(&language_path, &file).is_some_and(|content| originals(&content) == reference_originals) {
+                    observer.warning(Warning::OriginalsMismatch {
+                        file: file.clone(),
+                        language: language.clone(),
+                        reference: reference.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,531 @@
+//! Layout of the maps translation.
+//!
+//! Translation of maps is either stored in a single `maps.txt` file, or split into a `maps`
+//! directory with a `.txt` file per map, like `maps/map001.txt`. Per-map files have the same
+//! content as the corresponding part of `maps.txt`, starting with the `<!-- Map --><#>N` comment,
+//! and each of them has its own header.
+//!
+//! Readers, writers and purgers of maps join per-map files into a single content, and split it back
+//! after processing, so both layouts are processed identically. In streaming mode, maps are read and written
+//! block by block instead, so only a single map is held in memory at once.
+#[cfg(feature = "log")]
+use crate::println;
+use crate::{
+    format_version,
+    header::{is_header_line, with_header, Header},
+    statics::{localization::CONVERTED_FILE_MSG, LINES_SEPARATOR},
+    types::{MapsLayout, OptionExt, ResultExt},
+    unescape,
+};
+use indexmap::IndexMap;
+use std::{
+    fs::{create_dir_all, read_dir, read_to_string, remove_file, write, File},
+    io::{BufRead, BufReader, BufWriter, Write},
+    mem::take,
+    path::{Path, PathBuf},
+};
+
+/// Name of the directory, which holds per-map translation files.
+pub const MAPS_DIRECTORY: &str = "maps";
+
+/// Comment, which starts translation of every map.
+const MAP_COMMENT: &str = "<!-- Map -->";
+
+/// Prefix of the comment, which holds the map name.
+const MAP_NAME_COMMENT_PREFIX: &str = "<!-- Map Name: ";
+
+/// Returns the name of the per-map translation file.
+///
+/// # Parameters
+///
+/// - `number` - Number of the map
+/// - `name` - Optional name of the map, appended to the file name
+///
+/// # Returns
+///
+/// File name like `map001.txt`, or `map001_Town.txt` if the name is passed and not empty.
+/// Characters, that aren't allowed in file names, are replaced with underscores.
+pub fn map_file_name(number: u16, name: Option<&str>) -> String {
+    let name: String = name
+        .unwrap_or_default()
+        .chars()
+        .map(|char| {
+            if char.is_control() || matches!(char, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') {
+                '_'
+            } else {
+                char
+            }
+        })
+        .collect::<String>()
+        .trim()
+        .trim_end_matches('.')
+        .to_owned();
+
+    if name.is_empty() {
+        format!("map{number:03}.txt")
+    } else {
+        format!("map{number:03}_{name}.txt")
+    }
+}
+
+/// Parses the map number from the `<!-- Map --><#>N` comment.
+///
+/// Returns `None` if the line isn't a map comment.
+fn parse_map_comment(line: &str) -> Option<u16> {
+    line.strip_prefix(MAP_COMMENT)
+        .and_then(|line| line.strip_prefix(LINES_SEPARATOR))
+        .and_then(|number| number.trim().parse().ok())
+}
+
+/// Parses the map number from the name of a per-map translation file.
+///
+/// Returns `None` if the file isn't a per-map translation file.
+fn parse_map_file_number(file_name: &str) -> Option<u16> {
+    let rest: &str = file_name.strip_prefix("map")?.strip_suffix(".txt")?;
+    let digits: usize = rest.bytes().take_while(u8::is_ascii_digit).count();
+
+    if digits == 0 || !(rest.len() == digits || rest[digits..].starts_with('_')) {
+        return None;
+    }
+
+    rest[..digits].parse().ok()
+}
+
+/// Returns per-map translation files in the `maps` directory, sorted by map number.
+fn map_files(translation_path: &Path) -> Vec<(u16, PathBuf)> {
+    let Ok(entries) = read_dir(translation_path.join(MAPS_DIRECTORY)) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<(u16, PathBuf)> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let number: u16 = parse_map_file_number(entry.file_name().to_str()?)?;
+            Some((number, entry.path()))
+        })
+        .collect();
+
+    files.sort_unstable();
+    files
+}
+
+/// Splits the `maps.txt` content by maps.
+///
+/// Format and header comments are dropped. Lines, that precede the first map comment, are assigned to the
+/// first map.
+///
+/// # Returns
+///
+/// Contents of maps, mapped to map numbers along with map names.
+pub fn split_maps(content: &str) -> IndexMap<u16, (Option<String>, String)> {
+    let version: u8 = format_version(content);
+
+    let mut maps: IndexMap<u16, (Option<String>, String)> = IndexMap::new();
+    let mut prelude: String = String::new();
+    let mut current: Option<u16> = None;
+
+    for line in content.split('\n').filter(|line| !is_header_line(line)) {
+        if let Some(number) = parse_map_comment(line) {
+            current = Some(number);
+
+            let (_, map_content) = maps.entry(number).or_default();
+
+            if !prelude.is_empty() {
+                map_content.push_str(&std::mem::take(&mut prelude));
+            }
+        }
+
+        let Some(number) = current else {
+            prelude.push_str(line);
+            prelude.push('\n');
+            continue;
+        };
+
+        let (name, map_content) = maps.get_mut(&number).unwrap_log();
+
+        if name.is_none() {
+            *name = line
+                .strip_prefix(MAP_NAME_COMMENT_PREFIX)
+                .and_then(|line| line.split_once(" -->"))
+                .map(|(name, _)| unescape(name, version));
+        }
+
+        map_content.push_str(line);
+        map_content.push('\n');
+    }
+
+    for (_, map_content) in maps.values_mut() {
+        map_content.pop();
+    }
+
+    maps
+}
+
+/// Returns the `maps.txt` content with only the maps, which numbers match the predicate.
+pub(crate) fn retain_maps<F: Fn(u16) -> bool>(content: &str, predicate: F) -> String {
+    let retained: Vec<String> = split_maps(content)
+        .into_iter()
+        .filter(|(map_number, _)| predicate(*map_number))
+        .map(|(_, (_, map_content))| map_content)
+        .collect();
+
+    let mut output: String = with_header(retained.join("\n"), Header::parse(content).as_ref());
+
+    if output.ends_with('\n') {
+        output.pop();
+    }
+
+    output
+}
+
+/// Replaces the maps of the `maps.txt` content with the maps of the `partial` content, keeping other maps.
+///
+/// Maps, that aren't present in the content, are inserted before the first map with a greater number.
+/// The header of the `partial` content is used.
+pub(crate) fn replace_maps(content: &str, partial: &str) -> String {
+    let mut maps: IndexMap<u16, (Option<String>, String)> = split_maps(content);
+
+    for (map_number, map) in split_maps(partial) {
+        if let Some(existing) = maps.get_mut(&map_number) {
+            *existing = map;
+        } else {
+            let index: usize = maps.keys().position(|x| *x > map_number).unwrap_or(maps.len());
+            maps.shift_insert(index, map_number, map);
+        }
+    }
+
+    let joined: Vec<String> = maps.into_values().map(|(_, map_content)| map_content).collect();
+    with_header(joined.join("\n"), Header::parse(partial).as_ref())
+}
+
+/// Returns the path of the file, which header is used for maps translation.
+///
+/// For per-map layouts, it's the first per-map file. If there's no such file, the returned path doesn't exist.
+pub(crate) fn maps_header_path(translation_path: &Path, layout: MapsLayout) -> PathBuf {
+    if layout.is_single() {
+        return translation_path.join("maps.txt");
+    }
+
+    map_files(translation_path).into_iter().next().map_or_else(
+        || translation_path.join(MAPS_DIRECTORY).join("map000.txt"),
+        |(_, path)| path,
+    )
+}
+
+/// Returns whether maps translation exists in the translation directory.
+pub(crate) fn maps_exist(translation_path: &Path, layout: MapsLayout) -> bool {
+    if layout.is_single() {
+        translation_path.join("maps.txt").exists()
+    } else {
+        !map_files(translation_path).is_empty()
+    }
+}
+
+/// Reads maps translation from the translation directory.
+///
+/// For per-map layouts, per-map files are joined into a single content with the header of the first file.
+pub(crate) fn read_maps(translation_path: &Path, layout: MapsLayout) -> String {
+    if layout.is_single() {
+        return read_to_string(translation_path.join("maps.txt")).unwrap_log();
+    }
+
+    let mut header: Option<Header> = None;
+    let mut content: String = String::new();
+
+    for (_, path) in map_files(translation_path) {
+        let map_content: String = read_to_string(path).unwrap_log();
+
+        if header.is_none() {
+            header = Header::parse(&map_content);
+        }
+
+        for line in map_content.split('\n').filter(|line| !is_header_line(line)) {
+            content.push_str(line);
+            content.push('\n');
+        }
+    }
+
+    content.pop();
+    with_header(content, header.as_ref())
+}
+
+/// Writes maps translation to the translation directory.
+///
+/// For per-map layouts, the content is split by maps, and every map is written to its own file with the
+/// header of the content. Per-map files of maps, that aren't present in the content, are removed after every
+/// map is written.
+pub(crate) fn write_maps(translation_path: &Path, layout: MapsLayout, content: String) {
+    if layout.is_single() {
+        write(translation_path.join("maps.txt"), content).unwrap_log();
+        return;
+    }
+
+    let maps_path: PathBuf = translation_path.join(MAPS_DIRECTORY);
+    create_dir_all(&maps_path).unwrap_log();
+
+    let header: Option<Header> = Header::parse(&content);
+    let maps: IndexMap<u16, (Option<String>, String)> = split_maps(&content);
+
+    let file_names: Vec<String> = maps
+        .iter()
+        .map(|(&number, (name, _))| {
+            map_file_name(number, name.as_deref().filter(|_| layout == MapsLayout::PerMapNamed))
+        })
+        .collect();
+
+    let stale_files: Vec<PathBuf> = map_files(translation_path)
+        .into_iter()
+        .map(|(_, path)| path)
+        .filter(|path| {
+            !path
+                .file_name()
+                .and_then(|x| x.to_str())
+                .is_some_and(|file_name| file_names.iter().any(|x| x == file_name))
+        })
+        .collect();
+
+    for ((_, map_content), file_name) in maps.into_values().zip(file_names) {
+        write(maps_path.join(file_name), with_header(map_content, header.as_ref())).unwrap_log();
+    }
+
+    for path in stale_files {
+        remove_file(path).unwrap_log();
+    }
+}
+
+/// Returns sorted names of `.txt` files in the translation directory.
+///
+/// For per-map layouts, maps translation is listed as a single `maps.txt` file, so it can be read with
+/// [`read_txt`] and written with [`write_txt`] like any other file.
+pub(crate) fn txt_files(translation_path: &Path, layout: MapsLayout) -> Vec<String> {
+    let mut files: Vec<String> = read_dir(translation_path)
+        .unwrap_log()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|file| file.ends_with(".txt") && (layout.is_single() || file != "maps.txt"))
+        .collect();
+
+    if layout.is_per_map() && maps_exist(translation_path, layout) {
+        files.push(String::from("maps.txt"));
+    }
+
+    files.sort_unstable();
+    files
+}
+
+/// Reads the `.txt` file, listed by [`txt_files`].
+pub(crate) fn read_txt(translation_path: &Path, file: &str, layout: MapsLayout) -> String {
+    if file == "maps.txt" {
+        read_maps(translation_path, layout)
+    } else {
+        read_to_string(translation_path.join(file)).unwrap_log()
+    }
+}
+
+/// Writes the `.txt` file, listed by [`txt_files`].
+pub(crate) fn write_txt(translation_path: &Path, file: &str, layout: MapsLayout, content: String) {
+    if file == "maps.txt" {
+        write_maps(translation_path, layout, content);
+    } else {
+        write(translation_path.join(file), content).unwrap_log();
+    }
+}
+
+/// Converts maps translation from one layout to another.
+///
+/// The translation is read in the `from` layout, written in the `to` layout, and only then the files of the
+/// `from` layout are removed, so the translation isn't lost if writing fails. Nothing is done, if there's no
+/// translation in the `from` layout.
+///
+/// # Parameters
+///
+/// - `translation_path` - Path to the directory containing the `.txt` files with translation
+/// - `from` - Current layout of the maps translation
+/// - `to` - Layout to convert the maps translation to
+/// - `logging` - Whether to log converted files
+///
+/// # Examples
+///
+/// ```no_run
+/// use rvpacker_txt_rs_lib::{layout::convert_maps_layout, types::MapsLayout};
+///
+/// // Split `maps.txt` into `maps/map001_Town.txt` and others.
+/// convert_maps_layout("translation", MapsLayout::Single, MapsLayout::PerMapNamed, true);
+/// ```
+pub fn convert_maps_layout<P: AsRef<Path>>(translation_path: P, from: MapsLayout, to: MapsLayout, logging: bool) {
+    let translation_path: &Path = translation_path.as_ref();
+
+    if from == to || !maps_exist(translation_path, from) {
+        return;
+    }
+
+    let content: String = read_maps(translation_path, from);
+    let source_files: Vec<(u16, PathBuf)> = map_files(translation_path);
+
+    write_maps(translation_path, to, content);
+
+    // Switching between per-map layouts renames files, and files with stale names are removed by `write_maps`
+    // after writing, so files, that were just written, are kept
+    if from.is_single() {
+        remove_file(translation_path.join("maps.txt")).unwrap_log();
+    } else if to.is_single() {
+        for (_, path) in source_files {
+            remove_file(path).unwrap_log();
+        }
+    }
+
+    if logging {
+        println!("{CONVERTED_FILE_MSG} maps.txt");
+    }
+}
+
+/// Reads maps translation block by block, so only a single map is held in memory at once.
+///
+/// Every block consists of the format and header comments of the translation, followed by the lines of a
+/// single map. For per-map layouts, every block is the content of a per-map file. Lines, that precede the first
+/// map comment, are assigned to the first map.
+///
+/// # Returns
+///
+/// Map numbers, blocks, and offsets, that should be added to line numbers inside blocks to get line numbers
+/// inside the file.
+pub(crate) fn map_blocks(
+    translation_path: &Path,
+    layout: MapsLayout,
+) -> Box<dyn Iterator<Item = (u16, String, usize)>> {
+    if layout.is_per_map() {
+        return Box::new(
+            map_files(translation_path)
+                .into_iter()
+                .map(|(number, path)| (number, read_to_string(path).unwrap_log(), 0)),
+        );
+    }
+
+    let mut lines = BufReader::new(File::open(translation_path.join("maps.txt")).unwrap_log()).lines();
+    let mut line_number: usize = 0;
+    let mut header: String = String::new();
+    let mut header_lines: usize = 0;
+    let mut prelude: String = String::new();
+    let mut current: Option<(u16, String, usize)> = None;
+
+    Box::new(std::iter::from_fn(move || loop {
+        let Some(line) = lines.next() else {
+            return current.take().map(|(number, mut content, offset)| {
+                content.pop();
+                (number, content, offset)
+            });
+        };
+        let line: String = line.unwrap_log();
+        line_number += 1;
+
+        if let Some(number) = parse_map_comment(&line) {
+            // Header is at the top of the file, so lines of the first block match the lines of the file
+            let offset: usize = if current.is_some() {
+                line_number - 1 - header_lines
+            } else {
+                0
+            };
+
+            let mut content: String = header.clone();
+            content.push_str(&take(&mut prelude));
+            content.push_str(&line);
+            content.push('\n');
+
+            if let Some((number, mut content, offset)) = current.replace((number, content, offset)) {
+                content.pop();
+                return Some((number, content, offset));
+            }
+
+            continue;
+        }
+
+        if current.is_none() && is_header_line(&line) {
+            header_lines += 1;
+        }
+
+        let content: &mut String = match &mut current {
+            Some((_, content, _)) => content,
+            None if is_header_line(&line) => &mut header,
+            None => &mut prelude,
+        };
+
+        content.push_str(&line);
+        content.push('\n');
+    }))
+}
+
+/// Writes maps translation block by block, so only a single map is held in memory at once.
+///
+/// Blocks must be written in the order of maps in the translation. The output is the same, as if the
+/// joined blocks were written with [`write_maps`].
+pub(crate) struct MapBlocksWriter {
+    translation_path: PathBuf,
+    layout: MapsLayout,
+    header: Header,
+    file: Option<BufWriter<File>>,
+    file_names: Vec<String>,
+}
+
+impl MapBlocksWriter {
+    /// Creates the writer. For the single layout, `maps.txt` is created with the header right away.
+    pub(crate) fn new(translation_path: &Path, layout: MapsLayout, header: Header) -> MapBlocksWriter {
+        let file: Option<BufWriter<File>> = if layout.is_single() {
+            let mut file: BufWriter<File> =
+                BufWriter::new(File::create(translation_path.join("maps.txt")).unwrap_log());
+            file.write_all(with_header(String::new(), Some(&header)).as_bytes())
+                .unwrap_log();
+            Some(file)
+        } else {
+            create_dir_all(translation_path.join(MAPS_DIRECTORY)).unwrap_log();
+            None
+        };
+
+        MapBlocksWriter {
+            translation_path: translation_path.to_path_buf(),
+            layout,
+            header,
+            file,
+            file_names: Vec::new(),
+        }
+    }
+
+    /// Writes the lines of a single map, which start with the `<!-- Map --><#>N` comment.
+    pub(crate) fn write_block(&mut self, number: u16, name: Option<&str>, content: &str) {
+        if let Some(file) = &mut self.file {
+            if !self.file_names.is_empty() {
+                file.write_all(b"\n").unwrap_log();
+            }
+
+            file.write_all(content.as_bytes()).unwrap_log();
+            self.file_names.push(String::new());
+            return;
+        }
+
+        let file_name: String = map_file_name(number, name.filter(|_| self.layout == MapsLayout::PerMapNamed));
+
+        write(
+            self.translation_path.join(MAPS_DIRECTORY).join(&file_name),
+            with_header(content.to_owned(), Some(&self.header)),
+        )
+        .unwrap_log();
+        self.file_names.push(file_name);
+    }
+
+    /// Flushes `maps.txt`, or removes per-map files of maps, that weren't written.
+    pub(crate) fn finish(self) {
+        if let Some(mut file) = self.file {
+            file.flush().unwrap_log();
+            return;
+        }
+
+        for (_, path) in map_files(&self.translation_path) {
+            if !path
+                .file_name()
+                .and_then(|x| x.to_str())
+                .is_some_and(|file_name| self.file_names.iter().any(|x| x == file_name))
+            {
+                remove_file(path).unwrap_log();
+            }
+        }
+    }
+}
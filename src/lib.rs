@@ -1,11 +1,30 @@
 //!Library providing functions for rvpacker-txt-rs. Not intended for use in other projects, but can be.
 
 mod functions;
-pub use functions::{determine_extension, parse_ignore, read_to_string_without_bom};
+pub use functions::{
+    default_excluded_files, determine_extension, escape, format_version, parse_ignore, read_to_string_without_bom,
+    unescape,
+};
 
+pub mod cache;
+pub mod comments;
+pub mod context;
+pub mod header;
 pub mod json;
+pub mod language;
+pub mod layout;
+pub mod memory;
+pub mod migrate;
+pub mod note;
+pub mod observer;
+pub mod pack;
 pub mod purge;
 pub mod read;
+pub mod sheet;
+pub mod state;
 pub mod statics;
+pub mod stats;
+pub mod tree;
 pub mod types;
+pub mod verify;
 pub mod write;
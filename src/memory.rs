@@ -0,0 +1,429 @@
+use crate::{
+    functions::parse_translation,
+    layout::{read_txt, txt_files},
+    observer::LOG_OBSERVER,
+    statics::LINES_SEPARATOR,
+    types::{IndexMapGx, MapsLayout, OptionExt, ResultExt},
+};
+use gxhash::GxBuildHasher;
+use indexmap::IndexMap;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Write,
+    fs::{read_to_string, write},
+    path::Path,
+    sync::OnceLock,
+};
+
+/// Name of the file, where auto-filled and suggested lines are listed for review.
+pub const REVIEW_FILE_NAME: &str = ".rvpacker-review";
+
+/// Lines, that were filled or suggested from a translation memory, grouped by file.
+///
+/// Maps `<!-- File: name -->` headers to `original` -> (`translation`, `similarity`) entries.
+/// Similarity of `1.0` means that the line was filled with an exact match, lower values
+/// mean that translation was only suggested.
+pub type ReviewMap = IndexMap<String, IndexMap<String, (String, f64), GxBuildHasher>, GxBuildHasher>;
+
+/// A match, found in a translation memory.
+///
+/// # Fields
+///
+/// - `original` - Original text of the matched entry
+/// - `translation` - Translation of the matched entry
+/// - `similarity` - Similarity between the looked up text and `original`, from `0.0` to `1.0`
+#[derive(Debug, PartialEq, Clone)]
+pub struct MemoryMatch<'a> {
+    pub original: &'a str,
+    pub translation: &'a str,
+    pub similarity: f64,
+}
+
+/// Returns normalized Levenshtein similarity between two strings, from `0.0` to `1.0`.
+pub fn similarity(a: &[char], b: &[char]) -> f64 {
+    let max_len: usize = a.len().max(b.len());
+
+    if max_len == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let cost: usize = (a_char != b_char) as usize;
+            current[j + 1] = (previous[j] + cost).min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    1.0 - previous[b.len()] as f64 / max_len as f64
+}
+
+/// Returns distinct character bigrams of the text.
+fn bigrams(chars: &[char]) -> HashSet<[char; 2], GxBuildHasher> {
+    chars.windows(2).map(|pair| [pair[0], pair[1]]).collect()
+}
+
+/// An index of memory entries, used to narrow down candidates of fuzzy lookups.
+///
+/// # Fields
+///
+/// - `by_length` - Character length of entries mapped to their indices
+/// - `by_bigram` - Character bigrams mapped to indices of entries, that contain them
+#[derive(Debug, Clone, Default)]
+struct FuzzyIndex {
+    by_length: BTreeMap<usize, Vec<usize>>,
+    by_bigram: HashMap<[char; 2], Vec<usize>, GxBuildHasher>,
+}
+
+impl FuzzyIndex {
+    fn new(entries: &IndexMapGx) -> Self {
+        let mut index: FuzzyIndex = FuzzyIndex::default();
+
+        for (i, original) in entries.keys().enumerate() {
+            let chars: Vec<char> = original.chars().collect();
+            index.by_length.entry(chars.len()).or_default().push(i);
+
+            for bigram in bigrams(&chars) {
+                index.by_bigram.entry(bigram).or_default().push(i);
+            }
+        }
+
+        index
+    }
+}
+
+/// A file-backed translation memory.
+///
+/// Stores original-translation pairs imported from translation directories, and looks them up
+/// to pre-fill untranslated lines when reading. Entries are stored in the same
+/// `original<#>translation` format, that is used in `.txt` files.
+///
+/// # Fields
+///
+/// - `entries` - Memory entries, original text mapped to its translation
+/// - `threshold` - Minimum similarity of fuzzy matches
+/// - `maps_layout` - Layout of the maps translation in imported translation directories
+/// - `index` - Length and bigram index of entries, built on the first fuzzy lookup
+#[derive(Debug, Clone)]
+pub struct TranslationMemory {
+    entries: IndexMapGx,
+    threshold: f64,
+    maps_layout: MapsLayout,
+    index: OnceLock<FuzzyIndex>,
+}
+
+impl Default for TranslationMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TranslationMemory {
+    /// Creates a new empty `TranslationMemory` with fuzzy threshold of `0.8`, that imports maps
+    /// translation in `MapsLayout::Single` layout.
+    pub fn new() -> Self {
+        Self {
+            entries: IndexMapGx::default(),
+            threshold: 0.8,
+            maps_layout: MapsLayout::Single,
+            index: OnceLock::new(),
+        }
+    }
+
+    /// Loads a translation memory from the file.
+    ///
+    /// If file doesn't exist, returns an empty memory.
+    ///
+    /// # Parameters
+    ///
+    /// - `memory_file_path` - Path to the memory file
+    pub fn load<P: AsRef<Path>>(memory_file_path: P) -> Self {
+        let mut memory: TranslationMemory = TranslationMemory::new();
+
+        if let Ok(content) = read_to_string(memory_file_path.as_ref()) {
+            memory.insert_pairs(parse_translation(&content, "memory", false, false, &LOG_OBSERVER));
+        }
+
+        memory
+    }
+
+    /// Saves the translation memory to the file.
+    ///
+    /// # Parameters
+    ///
+    /// - `memory_file_path` - Path to the memory file
+    pub fn save<P: AsRef<Path>>(&self, memory_file_path: P) {
+        let mut content: String = String::from_iter(
+            self.entries
+                .iter()
+                .map(|(original, translation)| format!("{original}{LINES_SEPARATOR}{translation}\n")),
+        );
+
+        content.pop();
+        write(memory_file_path, content).unwrap_log();
+    }
+
+    /// Sets the minimum similarity of fuzzy matches, from `0.0` to `1.0`.
+    ///
+    /// Setting it to `1.0` effectively disables fuzzy matching.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the layout of the maps translation in translation directories, passed to [`TranslationMemory::import`].
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    fn insert_pairs(&mut self, pairs: impl Iterator<Item = (String, String)>) {
+        for (original, translation) in pairs {
+            if original.starts_with("<!--") || translation.is_empty() {
+                continue;
+            }
+
+            self.entries.insert(original, translation);
+        }
+
+        self.index = OnceLock::new();
+    }
+
+    /// Imports all translated pairs from `.txt` files of the translation directory.
+    ///
+    /// Pairs that are already present in the memory are overwritten.
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the `.txt` files with translation
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::memory::TranslationMemory;
+    ///
+    /// let mut memory = TranslationMemory::load("memory.txt");
+    /// memory.import("other_game/translation");
+    /// memory.save("memory.txt");
+    /// ```
+    pub fn import<P: AsRef<Path>>(&mut self, translation_path: P) {
+        let translation_path: &Path = translation_path.as_ref();
+
+        for filename in txt_files(translation_path, self.maps_layout) {
+            if filename == "stat.txt" {
+                continue;
+            }
+
+            let content: String = read_txt(translation_path, &filename, self.maps_layout);
+            self.insert_pairs(parse_translation(&content, &filename, false, false, &LOG_OBSERVER));
+        }
+    }
+
+    /// Inserts a single pair into the memory.
+    pub fn insert(&mut self, original: String, translation: String) {
+        self.insert_pairs(std::iter::once((original, translation)));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the translation of exactly matching entry.
+    pub fn get(&self, original: &str) -> Option<&str> {
+        self.entries.get(original).map(String::as_str)
+    }
+
+    /// Returns the most similar entry, if its similarity is not lower than the threshold.
+    ///
+    /// Exact matches are returned with similarity of `1.0`. Fuzzy candidates are looked up in
+    /// an index by their length and shared character bigrams, so similarity is only computed
+    /// for entries, that can reach the threshold.
+    pub fn fuzzy(&self, original: &str) -> Option<MemoryMatch<'_>> {
+        if let Some((original, translation)) = self.entries.get_key_value(original) {
+            return Some(MemoryMatch {
+                original,
+                translation,
+                similarity: 1.0,
+            });
+        }
+
+        if self.threshold >= 1.0 {
+            return None;
+        }
+
+        let index: &FuzzyIndex = self.index.get_or_init(|| FuzzyIndex::new(&self.entries));
+        let chars: Vec<char> = original.chars().collect();
+        let tolerance: f64 = 1.0 - self.threshold;
+
+        // Length difference alone must not make the similarity lower than the threshold
+        let min_len: usize = (chars.len() as f64 * self.threshold).floor() as usize;
+        let max_len: usize = if self.threshold > 0.0 {
+            (chars.len() as f64 / self.threshold).ceil() as usize
+        } else {
+            usize::MAX
+        };
+
+        let query_bigrams = bigrams(&chars);
+        let mut shared_bigrams: HashMap<usize, usize, GxBuildHasher> = HashMap::default();
+
+        for bigram in &query_bigrams {
+            for &i in index.by_bigram.get(bigram).map_or(&[][..], Vec::as_slice) {
+                *shared_bigrams.entry(i).or_default() += 1;
+            }
+        }
+
+        let mut best: Option<(usize, MemoryMatch)> = None;
+
+        for (&entry_len, indices) in index.by_length.range(min_len..=max_len) {
+            let longest: usize = entry_len.max(chars.len());
+
+            if longest == 0 || (entry_len.abs_diff(chars.len()) as f64 / longest as f64) > tolerance {
+                continue;
+            }
+
+            // Every edit removes at most two bigrams of the looked up text. Epsilon compensates
+            // rounding of the tolerance, which is a bit lower than `1.0 - threshold`
+            let max_distance: usize = (tolerance * longest as f64 + 1e-9).floor() as usize;
+
+            for &i in indices {
+                let shared: usize = shared_bigrams.get(&i).copied().unwrap_or_default();
+
+                if shared + 2 * max_distance < query_bigrams.len() {
+                    continue;
+                }
+
+                let (entry_original, entry_translation) = self.entries.get_index(i).unwrap_log();
+                let entry_chars: Vec<char> = entry_original.chars().collect();
+                let similarity: f64 = similarity(&chars, &entry_chars);
+
+                // On equal similarity, the entry that was inserted first wins
+                if similarity >= self.threshold
+                    && best.as_ref().map_or(true, |(best_i, x)| {
+                        similarity > x.similarity || (similarity == x.similarity && i < *best_i)
+                    })
+                {
+                    best = Some((
+                        i,
+                        MemoryMatch {
+                            original: entry_original,
+                            translation: entry_translation,
+                            similarity,
+                        },
+                    ));
+                }
+            }
+        }
+
+        best.map(|(_, memory_match)| memory_match)
+    }
+
+    /// Fills untranslated lines of the `.txt` content with exact matches, and collects
+    /// fuzzy matches as suggestions.
+    pub(crate) fn apply(&self, content: String, file: &str, review_map: &mut ReviewMap) -> String {
+        let review_entry = review_map.entry(format!("<!-- File: {file} -->")).or_default();
+        review_entry.clear();
+
+        let mut output: String = String::with_capacity(content.len());
+
+        for line in content.split('\n') {
+            let filled: Option<String> = line
+                .strip_suffix(LINES_SEPARATOR)
+                .filter(|original| !original.starts_with("<!--") && !original.contains(LINES_SEPARATOR))
+                .and_then(|original| {
+                    let memory_match: MemoryMatch = self.fuzzy(original)?;
+
+                    review_entry.insert(
+                        original.to_owned(),
+                        (memory_match.translation.to_owned(), memory_match.similarity),
+                    );
+
+                    (memory_match.similarity >= 1.0)
+                        .then(|| format!("{original}{LINES_SEPARATOR}{}", memory_match.translation))
+                });
+
+            output.push_str(filled.as_deref().unwrap_or(line));
+            output.push('\n');
+        }
+
+        output.pop();
+
+        if review_entry.is_empty() {
+            review_map.shift_remove(&format!("<!-- File: {file} -->"));
+        }
+
+        output
+    }
+}
+
+/// Parses the `.rvpacker-review` file.
+///
+/// # Parameters
+///
+/// - `translation_path` - The directory where the `.rvpacker-review` file is located
+pub fn parse_review<P: AsRef<Path>>(translation_path: P) -> ReviewMap {
+    let mut review_map: ReviewMap = ReviewMap::default();
+    let Ok(content) = read_to_string(translation_path.as_ref().join(REVIEW_FILE_NAME)) else {
+        return review_map;
+    };
+
+    let mut current_file: Option<String> = None;
+
+    for line in content.split('\n') {
+        if line.starts_with("<!-- File:") {
+            current_file = Some(line.to_owned());
+            review_map.entry(line.to_owned()).or_default();
+            continue;
+        }
+
+        let Some(file) = &current_file else {
+            continue;
+        };
+
+        let mut split = line.rsplitn(3, LINES_SEPARATOR);
+
+        if let (Some(similarity), Some(translation), Some(original)) = (split.next(), split.next(), split.next()) {
+            review_map.get_mut(file).unwrap_log().insert(
+                original.to_owned(),
+                (translation.to_owned(), similarity.parse().unwrap_or_default()),
+            );
+        }
+    }
+
+    review_map
+}
+
+/// Writes the review map to the `.rvpacker-review` file.
+///
+/// Every line is written as `original<#>translation<#>similarity`.
+///
+/// # Parameters
+///
+/// - `review_map` - A map of files to the lines, filled or suggested from the translation memory
+/// - `translation_path` - The directory where the `.rvpacker-review` file will be written
+pub fn write_review<P: AsRef<Path>>(review_map: &ReviewMap, translation_path: P) {
+    let mut content: String = String::new();
+
+    for (file, lines) in review_map {
+        let _ = writeln!(content, "{file}");
+
+        for (original, (translation, similarity)) in lines {
+            let _ = writeln!(
+                content,
+                "{original}{LINES_SEPARATOR}{translation}{LINES_SEPARATOR}{similarity:.2}"
+            );
+        }
+    }
+
+    content.pop();
+    write(translation_path.as_ref().join(REVIEW_FILE_NAME), content).unwrap_log();
+}
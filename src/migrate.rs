@@ -0,0 +1,517 @@
+#[cfg(feature = "log")]
+use crate::println;
+use crate::{
+    comments::{is_annotation_comment, NEEDS_REVIEW_COMMENT, NOTE_COMMENT},
+    context::{attach_comments, detach_comments, merge_by_ids, parse_ids, Attached},
+    determine_extension,
+    functions::parse_translation,
+    header::{with_header, Header},
+    memory::similarity,
+    observer::LOG_OBSERVER,
+    read::{MapReader, OtherReader, SystemReader},
+    state::state_comment,
+    statics::{localization::MIGRATED_FILE_MSG, LINES_SEPARATOR},
+    types::{EngineType, GameType, LineState, MapsProcessingMode, ProcessingMode, ResultExt},
+};
+use gxhash::GxBuildHasher;
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::{Path, PathBuf},
+};
+
+/// A line, which original was changed by a game update.
+///
+/// # Fields
+///
+/// - `old_original` - Original text before the update
+/// - `new_original` - Original text after the update
+/// - `translation` - Translation, carried over from the old original
+/// - `similarity` - Similarity between the old and the new original, from `0.0` to `1.0`
+/// - `by_location` - Whether the line was matched by its location, rather than only by similarity
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChangedLine {
+    pub old_original: String,
+    pub new_original: String,
+    pub translation: String,
+    pub similarity: f64,
+    pub by_location: bool,
+}
+
+/// Migration result of a single `.txt` file.
+///
+/// # Fields
+///
+/// - `added` - Originals, that are new in the update
+/// - `removed` - Translated originals, that were removed in the update, with their translations
+/// - `changed` - Lines, which originals were changed in the update
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FileMigration {
+    pub added: Vec<String>,
+    pub removed: Vec<(String, String)>,
+    pub changed: Vec<ChangedLine>,
+}
+
+impl FileMigration {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Result of the migration.
+///
+/// Holds every migrated `.txt` file, mapped to its migration result.
+#[derive(Debug, Default, Clone)]
+pub struct MigrationReport {
+    pub files: IndexMap<String, FileMigration, GxBuildHasher>,
+}
+
+impl MigrationReport {
+    /// Returns an iterator over files, that have at least one added, removed or changed line.
+    pub fn changed_files(&self) -> impl Iterator<Item = (&String, &FileMigration)> {
+        self.files.iter().filter(|(_, migration)| !migration.is_empty())
+    }
+}
+
+/// Returns the translated non-comment lines of the `.txt` content.
+fn translated_lines(content: &str, file: &str) -> IndexMap<String, String, GxBuildHasher> {
+    parse_translation(content, file, false, false, &LOG_OBSERVER)
+        .filter(|(original, translation)| !original.starts_with("<!--") && !translation.is_empty())
+        .collect()
+}
+
+/// Returns the non-comment originals of the `.txt` content.
+fn originals(content: &str) -> IndexSet<&str, GxBuildHasher> {
+    content
+        .split('\n')
+        .filter(|line| !line.starts_with("<!--"))
+        .filter_map(|line| line.split_once(LINES_SEPARATOR).map(|x| x.0))
+        .collect()
+}
+
+/// Inserts the needs review comment before every changed line.
+fn mark_changed(content: String, changed: &[ChangedLine]) -> String {
+    let changed: HashMap<&str, &str, GxBuildHasher> = changed
+        .iter()
+        .map(|line| (line.new_original.as_str(), line.old_original.as_str()))
+        .collect();
+
+    let mut output: String = String::with_capacity(content.len());
+    let mut annotations: Vec<&str> = Vec::new();
+
+    for line in content.split('\n') {
+        let original: &str = line.split_once(LINES_SEPARATOR).map_or(line, |(original, _)| original);
+
+        if is_annotation_comment(original) {
+            annotations.push(line);
+            continue;
+        }
+
+        let old_original: Option<&&str> = changed.get(original).filter(|_| !original.starts_with("<!--"));
+
+        for annotation in &annotations {
+            // Previous state and review comments of changed lines are replaced, notes are kept
+            if old_original.is_some() && !annotation.starts_with(NOTE_COMMENT) {
+                continue;
+            }
+
+            output.push_str(annotation);
+            output.push('\n');
+        }
+
+        if let Some(old_original) = old_original {
+            let _ = writeln!(output, "{NEEDS_REVIEW_COMMENT}{LINES_SEPARATOR}{old_original}");
+            let _ = writeln!(output, "{}", state_comment(LineState::NeedsReview));
+        }
+
+        annotations.clear();
+        output.push_str(line);
+        output.push('\n');
+    }
+
+    output.pop();
+    output
+}
+
+/// A struct for migrating translation to an updated version of a game.
+///
+/// Migrator reads the old and the new original data, and compares both with the existing
+/// translation. Translations of unchanged lines are kept. Lines, which originals were modified
+/// in the update, are detected by their location first and by similarity otherwise, and get
+/// the old translation marked with `<!-- Needs Review --><#>old original` comment and
+/// `needs-review` state. Lines, which originals were removed, are kept at the end of their map
+/// section, so they can be purged later.
+///
+/// Migrates `maps.txt`, `system.txt` and `.txt` files of other data files.
+///
+/// # Fields
+///
+/// - `old_path` - Path to the directory containing the original data files before the update
+/// - `new_path` - Path to the directory containing the original data files after the update
+/// - `translation_path` - Path to the directory containing the `.txt` files with translation
+/// - `work_path` - Path to the scratch directory, where intermediate files will be written
+/// - `maps_processing_mode` - Controls how maps are processed
+/// - `romanize` - Whether to romanize non-Latin text
+/// - `logging` - Whether to log processing information
+/// - `game_type` - Optional specific game type for specialized processing
+/// - `engine_type` - The RPG Maker engine type
+/// - `trim` - Whether to trim whitespace from strings
+/// - `ids` - Whether translation was read with stable IDs
+/// - `keyed` - Whether translation was read in keyed mode
+/// - `threshold` - Minimum similarity of the old and the new original to treat a line as changed
+/// - `dry_run` - Whether to only report the migration, without modifying the translation
+pub struct Migrator<P: AsRef<Path>> {
+    old_path: P,
+    new_path: P,
+    translation_path: P,
+    work_path: P,
+    maps_processing_mode: MapsProcessingMode,
+    romanize: bool,
+    logging: bool,
+    game_type: Option<GameType>,
+    engine_type: EngineType,
+    trim: bool,
+    ids: bool,
+    keyed: bool,
+    threshold: f64,
+    dry_run: bool,
+}
+
+impl<P: AsRef<Path>> Migrator<P> {
+    /// Creates a new `Migrator` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `old_path` - Path to the directory containing the original data files before the update
+    /// - `new_path` - Path to the directory containing the original data files after the update
+    /// - `translation_path` - Path to the directory containing the `.txt` files with translation
+    /// - `work_path` - Path to the scratch directory, where intermediate files will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn new(old_path: P, new_path: P, translation_path: P, work_path: P, engine_type: EngineType) -> Self {
+        Self::default(old_path, new_path, translation_path, work_path, engine_type)
+    }
+
+    /// Creates a new `Migrator` with default values.
+    ///
+    /// Default values are:
+    /// - `maps_processing_mode`: `MapsProcessingMode::Default`
+    /// - `romanize`: `false`
+    /// - `logging`: `false`
+    /// - `game_type`: `None`
+    /// - `trim`: `false`
+    /// - `ids`: `false`
+    /// - `keyed`: `false`
+    /// - `threshold`: `0.6`
+    /// - `dry_run`: `false`
+    ///
+    /// # Parameters
+    ///
+    /// - `old_path` - Path to the directory containing the original data files before the update
+    /// - `new_path` - Path to the directory containing the original data files after the update
+    /// - `translation_path` - Path to the directory containing the `.txt` files with translation
+    /// - `work_path` - Path to the scratch directory, where intermediate files will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn default(old_path: P, new_path: P, translation_path: P, work_path: P, engine_type: EngineType) -> Self {
+        Self {
+            old_path,
+            new_path,
+            translation_path,
+            work_path,
+            maps_processing_mode: MapsProcessingMode::Default,
+            romanize: false,
+            logging: false,
+            game_type: None,
+            engine_type,
+            trim: false,
+            ids: false,
+            keyed: false,
+            threshold: 0.6,
+            dry_run: false,
+        }
+    }
+
+    /// Sets the maps processing mode.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn maps_processing_mode(mut self, maps_processing_mode: MapsProcessingMode) -> Self {
+        self.maps_processing_mode = maps_processing_mode;
+        self
+    }
+
+    /// Sets whether to romanize text.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn romanize(mut self, romanize: bool) -> Self {
+        self.romanize = romanize;
+        self
+    }
+
+    /// Sets whether to log processing information.
+    ///
+    /// When enabled, the migrator will log information about the files being processed.
+    pub fn logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// Sets the game type for specialized processing.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn game_type(mut self, game_type: Option<GameType>) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
+    /// Sets whether to trim whitespace from strings.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether translation was read with stable IDs.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn ids(mut self, ids: bool) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets whether translation was read in keyed mode.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.keyed = keyed;
+        self
+    }
+
+    /// Sets the minimum similarity of the old and the new original, from `0.0` to `1.0`.
+    ///
+    /// Lines with lower similarity are reported as removed and added.
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the migrator doesn't modify translation files, and only returns the report.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Reads the original data files into the directory.
+    fn read_into(&self, original_path: &Path, output_path: &Path, ids: bool, keyed: bool) {
+        create_dir_all(output_path).unwrap_log();
+
+        let system_file_path: PathBuf = original_path.join(format!("System{}", determine_extension(self.engine_type)));
+
+        MapReader::new(original_path, output_path, self.engine_type)
+            .maps_processing_mode(self.maps_processing_mode)
+            .romanize(self.romanize)
+            .game_type(self.game_type)
+            .processing_mode(ProcessingMode::Force)
+            .trim(self.trim)
+            .ids(ids)
+            .keyed(keyed)
+            .read();
+
+        OtherReader::new(original_path, output_path, self.engine_type)
+            .romanize(self.romanize)
+            .game_type(self.game_type)
+            .processing_mode(ProcessingMode::Force)
+            .trim(self.trim)
+            .ids(ids)
+            .keyed(keyed)
+            .read();
+
+        if system_file_path.exists() {
+            SystemReader::new(system_file_path.as_path(), output_path, self.engine_type)
+                .romanize(self.romanize)
+                .processing_mode(ProcessingMode::Force)
+                .trim(self.trim)
+                .read();
+        }
+    }
+
+    /// Detects added, removed and changed lines of a single file.
+    fn migrate_file(&self, file: &str, old: &str, new: &str, existing: &str) -> FileMigration {
+        let old_ids = parse_ids(old);
+        let new_ids = parse_ids(new);
+
+        let old_locations: HashMap<&str, &str, GxBuildHasher> = old_ids
+            .iter()
+            .map(|(id, (original, _))| (original.as_str(), id.as_str()))
+            .collect();
+
+        let new_originals: IndexSet<&str, GxBuildHasher> = originals(new);
+        let existing_lines = translated_lines(existing, file);
+        let existing_originals: HashSet<&str, GxBuildHasher> = originals(existing).into_iter().collect();
+
+        let mut migration: FileMigration = FileMigration::default();
+        let mut claimed: HashSet<&str, GxBuildHasher> = HashSet::default();
+
+        for (original, translation) in &existing_lines {
+            if new_originals.contains(original.as_str()) {
+                continue;
+            }
+
+            let original_chars: Vec<char> = original.chars().collect();
+            let is_candidate = |new_original: &str| -> bool {
+                !existing_originals.contains(new_original) && !claimed.contains(new_original)
+            };
+
+            // Line at the same location first
+            let by_location: Option<(&str, f64)> = old_locations
+                .get(original.as_str())
+                .and_then(|id| new_ids.get(*id))
+                .map(|(new_original, _)| new_original.as_str())
+                .filter(|new_original| is_candidate(new_original))
+                .map(|new_original| {
+                    let new_chars: Vec<char> = new_original.chars().collect();
+                    (new_original, similarity(&original_chars, &new_chars))
+                })
+                .filter(|(_, similarity)| *similarity >= self.threshold);
+
+            // The most similar new line otherwise
+            let matched: Option<(&str, f64, bool)> = by_location
+                .map(|(new_original, similarity)| (new_original, similarity, true))
+                .or_else(|| {
+                    new_originals
+                        .iter()
+                        .filter(|new_original| is_candidate(new_original))
+                        .map(|new_original| {
+                            let new_chars: Vec<char> = new_original.chars().collect();
+                            (*new_original, similarity(&original_chars, &new_chars))
+                        })
+                        .filter(|(_, similarity)| *similarity >= self.threshold)
+                        .max_by(|a, b| a.1.total_cmp(&b.1))
+                        .map(|(new_original, similarity)| (new_original, similarity, false))
+                });
+
+            match matched {
+                Some((new_original, similarity, by_location)) => {
+                    claimed.insert(new_original);
+                    migration.changed.push(ChangedLine {
+                        old_original: original.to_owned(),
+                        new_original: new_original.to_owned(),
+                        translation: translation.to_owned(),
+                        similarity,
+                        by_location,
+                    });
+                }
+                None => migration.removed.push((original.to_owned(), translation.to_owned())),
+            }
+        }
+
+        migration.added = new_originals
+            .into_iter()
+            .filter(|new_original| !existing_originals.contains(new_original) && !claimed.contains(new_original))
+            .map(str::to_owned)
+            .collect();
+
+        migration
+    }
+
+    /// This method reads the old and the new original data into the work path, detects added,
+    /// removed and changed lines of every existing `.txt` file, and, unless dry run is enabled,
+    /// rewrites the files to match the new data.
+    ///
+    /// # Returns
+    ///
+    /// A [`MigrationReport`] with the added, removed and changed lines of every file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{migrate::Migrator, types::EngineType};
+    ///
+    /// let report = Migrator::new("old/data", "data", "translation", "migrate", EngineType::New).migrate();
+    ///
+    /// for (file, migration) in report.changed_files() {
+    ///     for line in &migration.changed {
+    ///         println!("{file}: {} -> {}", line.old_original, line.new_original);
+    ///     }
+    /// }
+    /// ```
+    pub fn migrate(self) -> MigrationReport {
+        let work_path: &Path = self.work_path.as_ref();
+        let old_dir: PathBuf = work_path.join("old");
+        let new_dir: PathBuf = work_path.join("new");
+        let base_dir: PathBuf = work_path.join("base");
+
+        for dir in [&old_dir, &new_dir, &base_dir] {
+            if dir.exists() {
+                remove_dir_all(dir).unwrap_log();
+            }
+        }
+
+        // IDs are always read, as they're used to match changed lines by location
+        self.read_into(self.old_path.as_ref(), &old_dir, true, false);
+        self.read_into(self.new_path.as_ref(), &new_dir, true, false);
+        self.read_into(self.new_path.as_ref(), &base_dir, self.ids, self.keyed);
+
+        let mut entries: Vec<(String, PathBuf)> = read_dir(self.translation_path.as_ref())
+            .unwrap_log()
+            .flatten()
+            .map(|entry| (entry.file_name().to_string_lossy().into_owned(), entry.path()))
+            .filter(|(filename, _)| filename.ends_with(".txt"))
+            .collect();
+        entries.sort_unstable();
+
+        let mut report: MigrationReport = MigrationReport::default();
+
+        for (filename, translation_file_path) in entries {
+            let (Ok(old), Ok(new), Ok(base)) = (
+                read_to_string(old_dir.join(&filename)),
+                read_to_string(new_dir.join(&filename)),
+                read_to_string(base_dir.join(&filename)),
+            ) else {
+                continue;
+            };
+
+            let existing: String = read_to_string(&translation_file_path).unwrap_log();
+            let migration: FileMigration = self.migrate_file(&filename, &old, &new, &existing);
+
+            if !self.dry_run && !migration.is_empty() {
+                // Carry translations of changed lines over to their new originals
+                let changed: HashMap<&str, &str, GxBuildHasher> = migration
+                    .changed
+                    .iter()
+                    .map(|line| (line.old_original.as_str(), line.new_original.as_str()))
+                    .collect();
+
+                let rewritten: String = existing
+                    .split('\n')
+                    .map(|line| match line.split_once(LINES_SEPARATOR) {
+                        Some((original, translation)) if changed.contains_key(original) => {
+                            format!("{}{LINES_SEPARATOR}{translation}", changed[original])
+                        }
+                        _ => line.to_owned(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                let header: Option<Header> = Header::parse(&base);
+                let mut annotations: Attached = detach_comments(&rewritten, is_annotation_comment).1;
+                let merged: String = mark_changed(
+                    attach_comments(merge_by_ids(base, &rewritten), &mut annotations),
+                    &migration.changed,
+                );
+                write(&translation_file_path, with_header(merged, header.as_ref())).unwrap_log();
+            }
+
+            if self.logging {
+                println!("{MIGRATED_FILE_MSG} {filename}");
+            }
+
+            report.files.insert(filename, migration);
+        }
+
+        report
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    comments::{map_lines, NOTE_COMMENT},
+    functions::{escape, unescape},
+    statics::{FORMAT_VERSION, LINES_SEPARATOR},
+};
+
+/// Returns the `<!-- Note --><#>text` comment line for the note.
+#[inline]
+pub fn note_comment(note: &str) -> String {
+    format!("{NOTE_COMMENT}{LINES_SEPARATOR}{}", escape(note))
+}
+
+/// Returns the notes from the comment lines, attached to a line, with new lines restored.
+#[inline]
+pub(crate) fn find_notes(comments: &[&str]) -> Vec<String> {
+    comments
+        .iter()
+        .filter_map(|line| line.strip_prefix(NOTE_COMMENT)?.strip_prefix(LINES_SEPARATOR))
+        .filter(|note| !note.is_empty())
+        .map(|note| unescape(note, FORMAT_VERSION))
+        .collect()
+}
+
+/// Parses translator notes from the `.txt` content.
+///
+/// # Parameters
+///
+/// - `translation` - Content of the `.txt` file
+///
+/// # Returns
+///
+/// Original, translation and notes of every line, that has notes, in order of appearance.
+/// Notes have their new lines restored, while original and translation are returned as is.
+pub fn parse_notes(translation: &str) -> Vec<(String, String, Vec<String>)> {
+    let mut notes: Vec<(String, String, Vec<String>)> = Vec::new();
+
+    map_lines(translation, |comments, line, _| {
+        let line_notes: Vec<String> = find_notes(comments);
+
+        if line_notes.is_empty() {
+            return;
+        }
+
+        if let Some((original, translation)) = line.split_once(LINES_SEPARATOR) {
+            notes.push((original.to_owned(), translation.to_owned(), line_notes));
+        }
+    });
+
+    notes
+}
@@ -0,0 +1,181 @@
+//! Progress and events of readers, writers and purgers.
+//!
+//! Every reader, writer and purger reports its progress to an [`Observer`]: found files, started, finished
+//! and skipped files, and warnings. By default, events are logged with [`LogObserver`], which prints the same
+//! messages, as before observers were introduced, or passes them to `log` with the `log` feature.
+use crate::statics::localization::{
+    AT_POSITION_MSG, COULD_NOT_SPLIT_LINE_MSG, FILES_ARE_NOT_PARSED_MSG, FILE_ALREADY_EXISTS_MSG, HEADER_MISMATCH_MSG,
+    IN_FILE_MSG, ORIGINALS_MISMATCH_MSG, PARSED_FILE_MSG, PURGED_FILE_MSG, STREAMING_UNSUPPORTED_MSG, WROTE_FILE_MSG,
+};
+#[cfg(feature = "log")]
+use crate::{eprintln, println};
+use std::sync::Arc;
+
+/// Action, performed on a file.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FileAction {
+    /// Original file was parsed into a `.txt` file by a reader.
+    Parsed,
+    /// Translated file was written by a writer.
+    Wrote,
+    /// Unused lines were purged from a `.txt` file by a purger.
+    Purged,
+}
+
+/// Reason, why a file was skipped.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SkipReason {
+    /// The `.txt` file already exists, and the processing mode is `Default`.
+    AlreadyExists,
+    /// The `.txt` file doesn't exist, and the processing mode is `Append`.
+    NotParsed,
+    /// The output file was written with the same inputs before, see [`CACHE_FILE_NAME`](crate::cache::CACHE_FILE_NAME).
+    Unchanged,
+}
+
+/// Warning, reported while processing files.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Warning {
+    /// A line of a `.txt` file doesn't contain the `<#>` separator, and is skipped.
+    ///
+    /// `line` is the 1-based line number inside the file.
+    UnsplittableLine { file: String, line: usize, content: String },
+    /// Settings of a reader, writer or purger don't match the header of a `.txt` file, so it's skipped.
+    HeaderMismatch { file: String, mismatches: Vec<String> },
+    /// Original lines of a `.txt` file of a language don't match the ones of the `reference` language,
+    /// see [`LanguageReader`](crate::language::LanguageReader).
+    OriginalsMismatch {
+        file: String,
+        language: String,
+        reference: String,
+    },
+    /// Streaming was requested, but `settings` need the whole file at once, so it's processed as usual.
+    StreamingUnsupported { file: String, settings: Vec<String> },
+}
+
+/// Receives progress and events of readers, writers and purgers.
+///
+/// Every method has an empty default implementation, so only required events can be handled. Methods may be
+/// called from multiple threads at once.
+///
+/// # Examples
+///
+/// ```no_run
+/// use rvpacker_txt_rs_lib::{
+///     observer::{FileAction, Observer},
+///     read::MapReader,
+///     types::EngineType,
+/// };
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// #[derive(Default)]
+/// struct Progress {
+///     total: AtomicUsize,
+///     done: AtomicUsize,
+/// }
+///
+/// impl Observer for Progress {
+///     fn files_found(&self, count: usize) {
+///         self.total.fetch_add(count, Ordering::Relaxed);
+///     }
+///
+///     fn file_finished(&self, file: &str, _action: FileAction) {
+///         let done: usize = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+///         println!("{file}: {done}/{}", self.total.load(Ordering::Relaxed));
+///     }
+/// }
+///
+/// MapReader::new("data", "translation", EngineType::New)
+///     .observer(Arc::new(Progress::default()))
+///     .read();
+/// ```
+pub trait Observer: Send + Sync {
+    /// Called with the number of files, that are going to be processed.
+    ///
+    /// May be called multiple times, e.g. once per map in streaming mode, in which case counts add up.
+    fn files_found(&self, _count: usize) {}
+
+    /// Called when processing of a file starts.
+    fn file_started(&self, _file: &str) {}
+
+    /// Called when a file is processed.
+    fn file_finished(&self, _file: &str, _action: FileAction) {}
+
+    /// Called when a file is skipped.
+    fn file_skipped(&self, _file: &str, _reason: SkipReason) {}
+
+    /// Called when a warning occurs.
+    fn warning(&self, _warning: Warning) {}
+}
+
+/// Observer, that logs events the way readers, writers and purgers always did.
+///
+/// Finished files are only logged when `logging` is enabled, skipped files and warnings are always logged,
+/// except for files, skipped as unchanged.
+///
+/// # Fields
+///
+/// - `logging` - Whether to log finished files
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LogObserver {
+    pub logging: bool,
+}
+
+impl Observer for LogObserver {
+    fn file_finished(&self, file: &str, action: FileAction) {
+        if !self.logging {
+            return;
+        }
+
+        let message: &str = match action {
+            FileAction::Parsed => PARSED_FILE_MSG,
+            FileAction::Wrote => WROTE_FILE_MSG,
+            FileAction::Purged => PURGED_FILE_MSG,
+        };
+
+        println!("{message} {file}");
+    }
+
+    fn file_skipped(&self, file: &str, reason: SkipReason) {
+        match reason {
+            SkipReason::AlreadyExists => println!("{file} {FILE_ALREADY_EXISTS_MSG}"),
+            SkipReason::NotParsed => println!("{FILES_ARE_NOT_PARSED_MSG}"),
+            SkipReason::Unchanged => {}
+        }
+    }
+
+    fn warning(&self, warning: Warning) {
+        match warning {
+            Warning::UnsplittableLine { file, line, content } => {
+                eprintln!("{COULD_NOT_SPLIT_LINE_MSG} ({content})\n{AT_POSITION_MSG} {line}\n{IN_FILE_MSG} {file}")
+            }
+            Warning::HeaderMismatch { file, mismatches } => {
+                eprintln!("{HEADER_MISMATCH_MSG} {}\n{IN_FILE_MSG} {file}", mismatches.join(", "))
+            }
+            Warning::OriginalsMismatch {
+                file,
+                language,
+                reference,
+            } => {
+                eprintln!("{ORIGINALS_MISMATCH_MSG} {reference}\n{IN_FILE_MSG} {language}/{file}")
+            }
+            Warning::StreamingUnsupported { file, settings } => {
+                eprintln!(
+                    "{STREAMING_UNSUPPORTED_MSG} {}\n{IN_FILE_MSG} {file}",
+                    settings.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// Observer, that is used by functions, which don't report finished files.
+pub(crate) const LOG_OBSERVER: LogObserver = LogObserver { logging: false };
+
+/// Returns the observer, passed to a reader, writer or purger, or [`LogObserver`] if none was passed.
+pub(crate) fn resolve_observer(observer: &Option<Arc<dyn Observer>>, logging: bool) -> Arc<dyn Observer> {
+    observer.clone().unwrap_or_else(|| Arc::new(LogObserver { logging }))
+}
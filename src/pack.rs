@@ -0,0 +1,350 @@
+use crate::{
+    comments::PACK_COMMENT_PREFIX,
+    functions::parse_translation,
+    observer::LOG_OBSERVER,
+    statics::LINES_SEPARATOR,
+    types::{EngineType, IndexMapGx, ResultExt},
+};
+use std::{
+    fs::{read_to_string, write},
+    path::Path,
+};
+
+/// Default terms shared between all engines.
+const JA_EN_COMMON: &[(&str, &str)] = &[
+    ("攻撃", "Attack"),
+    ("防御", "Guard"),
+    ("アイテム", "Item"),
+    ("スキル", "Skill"),
+    ("装備", "Equip"),
+    ("武器", "Weapon"),
+    ("防具", "Armor"),
+    ("頭", "Head"),
+    ("体", "Body"),
+    ("盾", "Shield"),
+    ("装飾品", "Accessory"),
+    ("ＨＰ", "HP"),
+    ("ＭＰ", "MP"),
+    ("ＳＰ", "SP"),
+    ("ＴＰ", "TP"),
+    ("攻撃力", "Attack"),
+    ("防御力", "Defense"),
+    ("敏捷性", "Agility"),
+    ("経験値", "EXP"),
+    ("レベル", "Level"),
+    ("戦う", "Fight"),
+    ("逃げる", "Escape"),
+    ("セーブ", "Save"),
+    ("ゲーム終了", "Game End"),
+    ("ニューゲーム", "New Game"),
+    ("コンティニュー", "Continue"),
+    ("タイトルへ", "To Title"),
+    ("やめる", "Cancel"),
+    ("購入する", "Buy"),
+    ("売却する", "Sell"),
+    ("持っている数", "Possession"),
+];
+
+/// Default terms of MV and MZ.
+const JA_EN_NEW: &[(&str, &str)] = &[
+    ("ステータス", "Status"),
+    ("並び替え", "Formation"),
+    ("オプション", "Options"),
+    ("大事なもの", "Key Item"),
+    ("最強装備", "Optimize"),
+    ("全て外す", "Clear"),
+    ("最大ＨＰ", "Max HP"),
+    ("最大ＭＰ", "Max MP"),
+    ("魔法力", "M.Attack"),
+    ("魔法防御", "M.Defense"),
+    ("運", "Luck"),
+    ("命中率", "Hit"),
+    ("回避率", "Evasion"),
+    ("常時ダッシュ", "Always Dash"),
+    ("コマンド記憶", "Command Remember"),
+    ("BGM 音量", "BGM Volume"),
+    ("BGS 音量", "BGS Volume"),
+    ("ME 音量", "ME Volume"),
+    ("SE 音量", "SE Volume"),
+    ("現在の経験値", "Current Exp"),
+    ("次のレベルまで", "To Next Level"),
+    ("どのファイルにセーブしますか？", "Save to which file?"),
+    ("どのファイルをロードしますか？", "Load which file?"),
+    ("ファイル", "File"),
+    ("%1の勝利！", "%1 was victorious!"),
+    ("%1は戦いに敗れた。", "%1 was defeated."),
+    ("%1が出現！", "%1 emerges!"),
+    ("%1は先手を取った！", "%1 gets the upper hand!"),
+    ("%1は不意をつかれた！", "%1 is surprised!"),
+    ("%1は逃げ出した！", "%1 has started to escape!"),
+    ("しかし逃げることはできなかった！", "However, it was unable to escape!"),
+    ("%1 の%2を獲得！", "%1 %2 received!"),
+    ("お金を %1\\G 手に入れた！", "%1\\G found!"),
+    ("%1を手に入れた！", "%1 found!"),
+    ("%1は%2 %3 に上がった！", "%1 is now %2 %3!"),
+    ("%1を覚えた！", "%1 learned!"),
+    ("%1は%2を使った！", "%1 uses %2!"),
+    ("会心の一撃！！", "An excellent hit!!"),
+    ("痛恨の一撃！！", "A painful blow!!"),
+    ("%1は %2 のダメージを受けた！", "%1 took %2 damage!"),
+    ("%1の%2が %3 回復した！", "%1 recovered %2 %3!"),
+    ("%1の%2が %3 増えた！", "%1 gained %2 %3!"),
+    ("%1の%2が %3 減った！", "%1 lost %2 %3!"),
+    ("%1は%2を %3 奪われた！", "%1 was drained of %2 %3!"),
+    ("%1はダメージを受けていない！", "%1 took no damage!"),
+    ("ミス！　%1はダメージを受けていない！", "Miss! %1 took no damage!"),
+    ("%1に %2 のダメージを与えた！", "%1 took %2 damage!"),
+    ("%1の%2を %3 奪った！", "%1 drained %2 %3!"),
+    ("%1にダメージを与えられない！", "%1 took no damage!"),
+    ("ミス！　%1にダメージを与えられない！", "Miss! %1 took no damage!"),
+    ("%1は攻撃をかわした！", "%1 evaded the attack!"),
+    ("%1は魔法を打ち消した！", "%1 nullified the magic!"),
+    ("%1は魔法を跳ね返した！", "%1 reflected the magic!"),
+    ("%1の反撃！", "%1 counterattacked!"),
+    ("%1が%2をかばった！", "%1 protected %2!"),
+    ("%1の%2が上がった！", "%1's %2 went up!"),
+    ("%1の%2が下がった！", "%1's %2 went down!"),
+    ("%1の%2が元に戻った！", "%1's %2 returned to normal!"),
+    ("%1には効かなかった！", "There was no effect on %1!"),
+];
+
+/// Default terms of VX Ace.
+const JA_EN_VXACE: &[(&str, &str)] = &[
+    ("ステータス", "Status"),
+    ("並び替え", "Formation"),
+    ("大事なもの", "Key Item"),
+    ("最強装備", "Optimize"),
+    ("全て外す", "Clear"),
+    ("最大ＨＰ", "Max HP"),
+    ("最大ＭＰ", "Max MP"),
+    ("魔法力", "M.Attack"),
+    ("魔法防御", "M.Defense"),
+    ("運", "Luck"),
+    ("シャットダウン", "Shutdown"),
+];
+
+/// Default terms of VX.
+const JA_EN_VX: &[(&str, &str)] = &[
+    ("ステータス", "Status"),
+    ("精神力", "Spirit"),
+    ("シャットダウン", "Shutdown"),
+];
+
+/// Default terms of XP.
+const JA_EN_XP: &[(&str, &str)] = &[
+    ("腕力", "STR"),
+    ("器用さ", "DEX"),
+    ("素早さ", "AGI"),
+    ("魔力", "INT"),
+    ("物理防御", "PDEF"),
+    ("魔法防御", "MDEF"),
+    ("身体防具", "Body Armor"),
+];
+
+/// Returns the name of the engine, used in pack file names.
+const fn engine_name(engine_type: EngineType) -> &'static str {
+    match engine_type {
+        EngineType::New => "new",
+        EngineType::VXAce => "vxace",
+        EngineType::VX => "vx",
+        EngineType::XP => "xp",
+    }
+}
+
+/// A translation pack of the default engine terms.
+///
+/// Packs are used by `SystemReader` to pre-fill `system.txt` with translations of the
+/// default terms, like `Attack`, `Guard`, `Save` or battle messages. Every line filled from a
+/// pack is preceded by a `<!-- Pack: name --><#>` comment.
+///
+/// # Fields
+///
+/// - `name` - Name of the pack, used in the marking comment
+/// - `entries` - Pack entries, original text mapped to its translation
+#[derive(Debug, Default, Clone)]
+pub struct TranslationPack {
+    name: String,
+    entries: IndexMapGx,
+}
+
+impl TranslationPack {
+    /// Creates a new empty `TranslationPack` with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            entries: IndexMapGx::default(),
+        }
+    }
+
+    /// Returns the built-in pack for the engine and language pair, if it exists.
+    ///
+    /// Currently, only `ja` to `en` packs are built in.
+    ///
+    /// # Parameters
+    ///
+    /// - `engine_type` - The RPG Maker engine type
+    /// - `source_language` - Language code of the original text
+    /// - `target_language` - Language code of the translation
+    pub fn builtin(engine_type: EngineType, source_language: &str, target_language: &str) -> Option<Self> {
+        if source_language != "ja" || target_language != "en" {
+            return None;
+        }
+
+        let engine_terms: &[(&str, &str)] = match engine_type {
+            EngineType::New => JA_EN_NEW,
+            EngineType::VXAce => JA_EN_VXACE,
+            EngineType::VX => JA_EN_VX,
+            EngineType::XP => JA_EN_XP,
+        };
+
+        let mut pack: TranslationPack = TranslationPack::new(&format!("{source_language}-{target_language}"));
+        pack.entries.extend(
+            JA_EN_COMMON
+                .iter()
+                .chain(engine_terms)
+                .map(|(original, translation)| ((*original).to_owned(), (*translation).to_owned())),
+        );
+
+        Some(pack)
+    }
+
+    /// Loads a translation pack from the file.
+    ///
+    /// Pack files use the same `original<#>translation` format, that is used in `.txt` files.
+    /// Name of the pack is the name of the file without extension.
+    ///
+    /// # Parameters
+    ///
+    /// - `pack_file_path` - Path to the pack file
+    pub fn load<P: AsRef<Path>>(pack_file_path: P) -> Self {
+        let pack_file_path: &Path = pack_file_path.as_ref();
+        let name: String = pack_file_path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        let content: String = read_to_string(pack_file_path).unwrap_log();
+
+        let mut pack: TranslationPack = TranslationPack::new(&name);
+        pack.entries.extend(
+            parse_translation(&content, &name, false, false, &LOG_OBSERVER)
+                .filter(|(original, translation)| !original.starts_with("<!--") && !translation.is_empty()),
+        );
+
+        pack
+    }
+
+    /// Finds the pack for the engine and language pair.
+    ///
+    /// Looks for `{engine}.{source}-{target}.txt` file in the packs directory first, where
+    /// `engine` is one of `new`, `vxace`, `vx` or `xp`. If it doesn't exist, returns the
+    /// built-in pack.
+    ///
+    /// # Parameters
+    ///
+    /// - `packs_path` - Path to the directory containing pack files
+    /// - `engine_type` - The RPG Maker engine type
+    /// - `source_language` - Language code of the original text
+    /// - `target_language` - Language code of the translation
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use rvpacker_txt_rs_lib::{pack::TranslationPack, read::SystemReader, types::EngineType};
+    ///
+    /// let pack = TranslationPack::find("packs", EngineType::New, "ja", "en");
+    ///
+    /// SystemReader::new("data/System.json", "translation", EngineType::New)
+    ///     .pack(pack.map(Arc::new))
+    ///     .read();
+    /// ```
+    pub fn find<P: AsRef<Path>>(
+        packs_path: P,
+        engine_type: EngineType,
+        source_language: &str,
+        target_language: &str,
+    ) -> Option<Self> {
+        let pack_file_path = packs_path.as_ref().join(format!(
+            "{}.{source_language}-{target_language}.txt",
+            engine_name(engine_type)
+        ));
+
+        if pack_file_path.exists() {
+            Some(TranslationPack::load(pack_file_path))
+        } else {
+            TranslationPack::builtin(engine_type, source_language, target_language)
+        }
+    }
+
+    /// Saves the translation pack to the file.
+    ///
+    /// Can be used to export a built-in pack for customization.
+    ///
+    /// # Parameters
+    ///
+    /// - `pack_file_path` - Path to the pack file
+    pub fn save<P: AsRef<Path>>(&self, pack_file_path: P) {
+        let mut content: String = String::from_iter(
+            self.entries
+                .iter()
+                .map(|(original, translation)| format!("{original}{LINES_SEPARATOR}{translation}\n")),
+        );
+
+        content.pop();
+        write(pack_file_path, content).unwrap_log();
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Inserts a single pair into the pack.
+    pub fn insert(&mut self, original: String, translation: String) {
+        self.entries.insert(original, translation);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, original: &str) -> Option<&str> {
+        self.entries.get(original).map(String::as_str)
+    }
+
+    /// Fills untranslated lines of the `.txt` content from the pack, and marks them with
+    /// the pack comment.
+    ///
+    /// Lines, which translation still equals to the pack translation, are marked again, so
+    /// marking survives re-reading in `Append` mode.
+    pub(crate) fn apply(&self, content: String) -> String {
+        let comment: String = format!("{PACK_COMMENT_PREFIX}{} -->{LINES_SEPARATOR}", self.name);
+        let mut output: String = String::with_capacity(content.len());
+
+        for line in content.split('\n') {
+            if let Some((original, translation)) = line.split_once(LINES_SEPARATOR) {
+                if let Some(pack_translation) = self.get(original) {
+                    if translation.is_empty() || translation == pack_translation {
+                        output.push_str(&comment);
+                        output.push('\n');
+                        output.push_str(original);
+                        output.push_str(LINES_SEPARATOR);
+                        output.push_str(pack_translation);
+                        output.push('\n');
+                        continue;
+                    }
+                }
+            }
+
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        output.pop();
+        output
+    }
+}
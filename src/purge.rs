@@ -1,15 +1,21 @@
-#[cfg(feature = "log")]
-use crate::println;
 use crate::{
+    comments::is_meta_comment,
+    context::{attach_comments, detach_comments, Attached},
     functions::{
-        extract_strings, filter_maps, filter_other, get_maps_labels, get_object_data, get_other_labels,
-        get_system_labels, is_allowed_code, is_bad_code, parse_map_number, parse_rpgm_file, parse_translation,
-        process_parameter, process_variable, romanize_string, string_is_only_symbols, traverse_json,
+        escape, extract_strings, filter_maps, filter_other, get_maps_labels, get_object_data, get_objects,
+        get_other_labels, get_system_labels, is_allowed_code, is_bad_code, par_map_ordered, parse_map_number,
+        parse_rpgm_file, parse_translation, process_parameter, process_variable, resolve_excluded_files,
+        romanize_string, string_is_only_symbols, traverse_json,
     },
-    statics::{localization::PURGED_FILE_MSG, ENCODINGS, LINES_SEPARATOR, NEW_LINE},
+    header::{apply_header, with_header, Header},
+    layout::{maps_header_path, read_maps, replace_maps, retain_maps, write_maps},
+    observer::{resolve_observer, FileAction, Observer},
+    state::purge_states,
+    statics::{ENCODINGS, LINES_SEPARATOR},
     types::{
-        Code, EngineType, GameType, IgnoreEntry, IgnoreMap, IndexMapGx, IndexSetGx, MapsProcessingMode, OptionExt,
-        ProcessingMode, ResultExt, TrimReplace, Variable,
+        Code, EngineType, FileFilter, GameType, HeaderMode, IgnoreEntry, IgnoreMap, IndexMapGx, IndexSetGx, LineState,
+        MapsLayout, MapsProcessingMode, OptionExt, ProcessingMode, PurgeReason, PurgedLine, ResultExt, TrimReplace,
+        Variable,
     },
 };
 use flate2::read::ZlibDecoder;
@@ -21,11 +27,12 @@ use smallvec::SmallVec;
 use sonic_rs::{from_str, from_value, prelude::*, Array, Value};
 use std::{
     cell::UnsafeCell,
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     fs::{read, read_dir, read_to_string, write},
     io::Read,
     mem::{take, transmute},
-    path::Path,
+    path::{Path, PathBuf},
+    sync::Arc,
 };
 
 /// Writes the ignore map to a `.rvpacker-ignore` file.
@@ -83,6 +90,28 @@ pub fn write_stat<P: AsRef<Path>>(stat_vec: Vec<(String, String)>, translation_p
     .unwrap_log();
 }
 
+#[inline]
+fn purged_line(
+    file: &str,
+    map: Option<u16>,
+    original: &str,
+    translation: &str,
+    reason: PurgeReason,
+    ignore_entry: Option<&IgnoreEntry>,
+) -> PurgedLine {
+    PurgedLine {
+        file: file.to_owned(),
+        map,
+        original: original.to_owned(),
+        translation: translation.to_owned(),
+        reason: if ignore_entry.is_some_and(|entry| entry.contains(original)) {
+            PurgeReason::Ignored
+        } else {
+            reason
+        },
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
 fn parse_list(
@@ -131,7 +160,7 @@ fn parse_list(
             && (!code.is_any_dialogue() || (engine_type.is_xp() && code.is_dialogue_start() && !lines.is_empty()))
         {
             if !lines.is_empty() {
-                let joined: String = lines.join(NEW_LINE);
+                let joined: String = escape(&lines.join("\n"));
 
                 process_parameter(Code::Dialogue, &joined);
 
@@ -177,7 +206,7 @@ fn parse_list(
                 .unwrap_or_else(|| match value.as_object() {
                     Some(obj) => unsafe {
                         (*buf.get()).push(get_object_data(obj));
-                        std::str::from_utf8_unchecked(&(*buf.get())[lines.len()])
+                        std::str::from_utf8_unchecked(&(&(*buf.get()))[lines.len()])
                     },
                     None => "",
                 })
@@ -217,6 +246,13 @@ fn parse_list(
 /// - `purge_empty` - Whether to purge empty translation
 /// - `create_ignore` - Whether to create an ignore file for purged entries
 /// - `trim` - Whether to trim whitespace from strings
+/// - `dry_run` - Whether to only report lines that would be purged, without modifying files
+/// - `purge_states` - Review states of lines, that will be purged
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `maps_layout` - Layout of the maps translation
+/// - `file_filter` - Filter of processed files
+/// - `threads` - Maximum number of threads, used to parse files
+/// - `observer` - Observer of progress and events
 pub struct MapPurger<P: AsRef<Path>> {
     original_path: P,
     translation_path: P,
@@ -230,6 +266,13 @@ pub struct MapPurger<P: AsRef<Path>> {
     purge_empty: bool,
     create_ignore: bool,
     trim: bool,
+    dry_run: bool,
+    purge_states: Vec<LineState>,
+    header_mode: HeaderMode,
+    maps_layout: MapsLayout,
+    file_filter: FileFilter,
+    threads: usize,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> MapPurger<P> {
@@ -256,6 +299,13 @@ impl<P: AsRef<Path>> MapPurger<P> {
     /// - `create_ignore`: `false`
     /// - `purge_empty`: `false`
     /// - `trim`: `false`
+    /// - `dry_run`: `false`
+    /// - `purge_states`: empty
+    /// - `header_mode`: `HeaderMode::Validate`
+    /// - `maps_layout`: `MapsLayout::Single`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `threads`: `0`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -276,6 +326,13 @@ impl<P: AsRef<Path>> MapPurger<P> {
             create_ignore: false,
             purge_empty: false,
             trim: false,
+            dry_run: false,
+            purge_states: Vec::new(),
+            header_mode: HeaderMode::Validate,
+            maps_layout: MapsLayout::Single,
+            file_filter: FileFilter::default(),
+            threads: 0,
+            observer: None,
         }
     }
 
@@ -354,6 +411,99 @@ impl<P: AsRef<Path>> MapPurger<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the purger doesn't modify any files, and only returns the lines
+    /// it would purge.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that will be purged.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are purged along with
+    /// their comments, regardless of other settings.
+    pub fn purge_states(mut self, purge_states: Vec<LineState>) -> Self {
+        self.purge_states = purge_states;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// Must be the same value, as in previous read.
+    ///
+    /// With `MapsLayout::PerMap` and `MapsLayout::PerMapNamed`, every map is stored in its own file in
+    /// the `maps` directory instead of the single `maps.txt` file. Use
+    /// [`convert_maps_layout`](crate::layout::convert_maps_layout) to convert existing translation.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the maximum number of threads, used to parse files.
+    ///
+    /// Files are parsed in parallel, but processed in the same order, so the output doesn't depend on it.
+    /// `0` means the number of logical CPUs, `1` parses files sequentially.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            maps_processing_mode: Some(self.maps_processing_mode),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &maps_header_path(self.translation_path.as_ref(), self.maps_layout),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.maps_processing_mode = settings.maps_processing_mode.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
     /// This method analyzes the map files and their translation, removing unused
     /// or empty translation based on the configured settings.
     ///
@@ -362,6 +512,11 @@ impl<P: AsRef<Path>> MapPurger<P> {
     /// - `ignore_map` - Optional map to store ignored entries
     /// - `stat_vec` - Optional vector to store statistics
     ///
+    /// # Returns
+    ///
+    /// Lines that were purged, or would be purged with dry run, along with the reason.
+    /// Lines are reported as ignored, when they're already present in the passed `ignore_map`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -380,8 +535,23 @@ impl<P: AsRef<Path>> MapPurger<P> {
     /// purger.purge(None, None);
     /// ```
     #[inline(always)]
-    pub fn purge(self, mut ignore_map: Option<&mut IgnoreMap>, mut stat_vec: Option<&mut Vec<(String, String)>>) {
-        let txt_file_path: &Path = &self.translation_path.as_ref().join("maps.txt");
+    pub fn purge(
+        mut self,
+        mut ignore_map: Option<&mut IgnoreMap>,
+        mut stat_vec: Option<&mut Vec<(String, String)>>,
+    ) -> Vec<PurgedLine> {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.apply_header(&*observer) {
+            return Vec::new();
+        }
+
+        observer.files_found(1);
+        observer.file_started("maps.txt");
+
+        let mut purged: Vec<PurgedLine> = Vec::new();
+
+        let translation_path: &Path = self.translation_path.as_ref();
 
         // Allocated when maps processing mode is DEFAULT or SEPARATE.
         let mut lines_set: IndexSetGx = IndexSet::default();
@@ -398,9 +568,24 @@ impl<P: AsRef<Path>> MapPurger<P> {
 
         let mut new_translation_map_vec: Vec<(String, String)> = Vec::new();
 
-        let translation: String = read_to_string(txt_file_path).unwrap_log();
+        // With the file filter, only matching maps are purged, and other maps are kept as is.
+        let full_translation: Option<String> = self
+            .file_filter
+            .is_active()
+            .then(|| read_maps(translation_path, self.maps_layout));
+
+        // Comments are detached, so they're removed along with their lines, and don't collide in maps.
+        let (translation, mut attached): (String, Attached) = detach_comments(
+            &match &full_translation {
+                Some(full_translation) => {
+                    retain_maps(full_translation, |map_number| self.file_filter.matches_map(map_number))
+                }
+                None => read_maps(translation_path, self.maps_layout),
+            },
+            is_meta_comment,
+        );
         let parsed_translation: Box<dyn Iterator<Item = (String, String)>> =
-            parse_translation(&translation, "maps.txt", false, true);
+            parse_translation(&translation, "maps.txt", false, true, &*observer);
 
         match self.maps_processing_mode {
             MapsProcessingMode::Default | MapsProcessingMode::Separate => {
@@ -437,13 +622,35 @@ impl<P: AsRef<Path>> MapPurger<P> {
             MapsProcessingMode::Preserve => translation_map_vec.extend(parsed_translation),
         }
 
+        // Skip indices are applied to all maps at once, so each map's indices are offset
+        let map_offsets: HashMap<u16, usize, GxBuildHasher> = translation_maps
+            .iter()
+            .scan(0, |offset, (map_number, map)| {
+                let map_offset: usize = *offset;
+                *offset += map.len();
+                Some((*map_number, map_offset))
+            })
+            .collect();
+
         let mut skip_indices: HashSet<usize, GxBuildHasher> = HashSet::default();
 
         if self.purge_empty {
             if self.maps_processing_mode == MapsProcessingMode::Preserve {
+                let mut map_number: Option<u16> = None;
+
                 for (i, (original, translation)) in translation_map_vec.iter().enumerate() {
-                    if !original.starts_with("<!--") && translation.is_empty() {
+                    if original == "<!-- Map -->" {
+                        map_number = Some(parse_map_number(translation));
+                    } else if !original.starts_with("<!--") && translation.is_empty() {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "maps.txt",
+                            map_number,
+                            original,
+                            translation,
+                            PurgeReason::Empty,
+                            None,
+                        ));
                     }
                 }
             } else {
@@ -475,7 +682,15 @@ impl<P: AsRef<Path>> MapPurger<P> {
                                     .unwrap()
                                     .push((original.to_owned(), translation.to_owned()));
                             } else {
-                                skip_indices.insert(i);
+                                skip_indices.insert(map_offsets[map_number] + i);
+                                purged.push(purged_line(
+                                    "maps.txt",
+                                    Some(*map_number),
+                                    original,
+                                    translation,
+                                    PurgeReason::Empty,
+                                    ignore_entry.as_deref(),
+                                ));
 
                                 if self.create_ignore {
                                     ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -489,12 +704,18 @@ impl<P: AsRef<Path>> MapPurger<P> {
             let (_, events_label, pages_label, list_label, code_label, parameters_label) =
                 get_maps_labels(self.engine_type);
 
-            let obj_vec_iter = read_dir(self.original_path)
+            // Maps are processed in the same order, as in the reader
+            let mut maps: Vec<(String, PathBuf)> = read_dir(self.original_path)
                 .unwrap_log()
-                .filter_map(|entry| filter_maps(entry, self.engine_type));
+                .filter_map(|entry| filter_maps(entry, self.engine_type))
+                .filter(|(filename, _)| self.file_filter.matches(filename))
+                .collect();
+            maps.sort_unstable_by_key(|(filename, _)| parse_map_number(filename));
 
-            for (filename, path) in obj_vec_iter {
-                let obj: Value = parse_rpgm_file(&path, self.engine_type);
+            let engine_type: EngineType = self.engine_type;
+
+            for (filename, _, obj) in par_map_ordered(maps, self.threads, |_, path| parse_rpgm_file(path, engine_type))
+            {
                 let map_number: u16 = parse_map_number(&filename);
 
                 if self.stat {
@@ -566,7 +787,15 @@ impl<P: AsRef<Path>> MapPurger<P> {
                                     .unwrap()
                                     .push((original.to_owned(), translation.to_owned()));
                             } else {
-                                skip_indices.insert(i);
+                                skip_indices.insert(map_offsets[&map_number] + i);
+                                purged.push(purged_line(
+                                    "maps.txt",
+                                    Some(map_number),
+                                    original,
+                                    translation,
+                                    PurgeReason::NotInSource,
+                                    ignore_entry.as_deref(),
+                                ));
 
                                 if self.create_ignore {
                                     ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -589,14 +818,34 @@ impl<P: AsRef<Path>> MapPurger<P> {
                         .map(|(_, (original, translation))| format!("{original}{LINES_SEPARATOR}{translation}\n")),
                 ),
                 MapsProcessingMode::Preserve => {
+                    // Lines of all maps, since in preserve mode they're collected into the vec instead of the set
+                    let source_lines: HashSet<&str, GxBuildHasher> = new_translation_map_vec
+                        .iter()
+                        .map(|(original, _)| original.as_str())
+                        .collect();
+
+                    let mut map_number: Option<u16> = None;
+
                     for (i, (original, translation)) in translation_map_vec.iter().enumerate() {
+                        if original == "<!-- Map -->" {
+                            map_number = Some(parse_map_number(translation));
+                        }
+
                         if self.leave_filled && !translation.is_empty() {
                             continue;
                         }
 
                         // ! I have no idea, how to implement other args for preserve
-                        if !original.starts_with("<!--") && !lines_set.contains(original) {
+                        if !original.starts_with("<!--") && !source_lines.contains(original.as_str()) {
                             skip_indices.insert(i);
+                            purged.push(purged_line(
+                                "maps.txt",
+                                map_number,
+                                original,
+                                translation,
+                                PurgeReason::NotInSource,
+                                None,
+                            ));
                         }
                     }
 
@@ -611,12 +860,23 @@ impl<P: AsRef<Path>> MapPurger<P> {
             };
 
             output_content.pop();
-            write(txt_file_path, output_content).unwrap_log();
+            output_content = attach_comments(output_content, &mut attached);
+            output_content = purge_states(output_content, &self.purge_states, "maps.txt", &mut purged);
+
+            if !self.dry_run {
+                output_content = with_header(output_content, Header::parse(&translation).as_ref());
+
+                if let Some(full_translation) = &full_translation {
+                    output_content = replace_maps(full_translation, &output_content);
+                }
 
-            if self.logging {
-                println!("{PURGED_FILE_MSG} maps.txt");
+                write_maps(translation_path, self.maps_layout, output_content);
+
+                observer.file_finished("maps.txt", FileAction::Purged);
             }
         };
+
+        purged
     }
 }
 
@@ -640,6 +900,14 @@ impl<P: AsRef<Path>> MapPurger<P> {
 /// - `purge_empty` - Whether to purge empty translation
 /// - `create_ignore` - Whether to create an ignore file for purged entries
 /// - `trim` - Whether to trim whitespace from strings
+/// - `dry_run` - Whether to only report lines that would be purged, without modifying files
+/// - `purge_states` - Review states of lines, that will be purged
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `excluded_files` - Names of data files, that aren't processed
+/// - `included_files` - Names of excluded data files, that are processed anyway
+/// - `file_filter` - Filter of processed files
+/// - `threads` - Maximum number of threads, used to parse files
+/// - `observer` - Observer of progress and events
 pub struct OtherPurger<P: AsRef<Path>> {
     original_path: P,
     translation_path: P,
@@ -652,6 +920,14 @@ pub struct OtherPurger<P: AsRef<Path>> {
     purge_empty: bool,
     create_ignore: bool,
     trim: bool,
+    dry_run: bool,
+    purge_states: Vec<LineState>,
+    header_mode: HeaderMode,
+    excluded_files: Option<Vec<String>>,
+    included_files: Vec<String>,
+    file_filter: FileFilter,
+    threads: usize,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> OtherPurger<P> {
@@ -678,6 +954,14 @@ impl<P: AsRef<Path>> OtherPurger<P> {
     /// - `create_ignore`: `false`
     /// - `purge_empty`: `false`
     /// - `trim`: `false`
+    /// - `dry_run`: `false`
+    /// - `purge_states`: empty
+    /// - `header_mode`: `HeaderMode::Validate`
+    /// - `excluded_files`: `None`
+    /// - `included_files`: empty
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `threads`: `0`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -697,6 +981,14 @@ impl<P: AsRef<Path>> OtherPurger<P> {
             create_ignore: false,
             purge_empty: false,
             trim: false,
+            dry_run: false,
+            purge_states: Vec::new(),
+            header_mode: HeaderMode::Validate,
+            excluded_files: None,
+            included_files: Vec::new(),
+            file_filter: FileFilter::default(),
+            threads: 0,
+            observer: None,
         }
     }
 
@@ -767,6 +1059,110 @@ impl<P: AsRef<Path>> OtherPurger<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the purger doesn't modify any files, and only returns the lines
+    /// it would purge.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that will be purged.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are purged along with
+    /// their comments, regardless of other settings.
+    pub fn purge_states(mut self, purge_states: Vec<LineState>) -> Self {
+        self.purge_states = purge_states;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the names of data files, that aren't processed, without extension.
+    ///
+    /// If `None`, [`default_excluded_files`](crate::default_excluded_files) are used.
+    pub fn excluded_files(mut self, excluded_files: Option<Vec<String>>) -> Self {
+        self.excluded_files = excluded_files;
+        self
+    }
+
+    /// Sets the names of excluded data files, that are processed anyway, without extension.
+    ///
+    /// For example, `vec![String::from("Tilesets")]` enables processing of tileset names.
+    pub fn included_files(mut self, included_files: Vec<String>) -> Self {
+        self.included_files = included_files;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the maximum number of threads, used to parse files.
+    ///
+    /// Files are parsed in parallel, but processed in the same order, so the output doesn't depend on it.
+    /// `0` means the number of logical CPUs, `1` parses files sequentially.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let excluded_files: Vec<String> =
+            resolve_excluded_files(self.excluded_files.as_deref(), &self.included_files, self.game_type);
+
+        let Some(txt_path) = read_dir(self.original_path.as_ref())
+            .ok()
+            .and_then(|mut entries| entries.find_map(|entry| filter_other(entry, self.engine_type, &excluded_files)))
+            .map(|(filename, _)| {
+                let basename: &str = filename.rsplit_once('.').unwrap_log().0;
+                self.translation_path.as_ref().join(basename.to_lowercase() + ".txt")
+            })
+        else {
+            return true;
+        };
+
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(&txt_path, self.header_mode, &mut settings, observer) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
     /// This method analyzes the data files and their translation, removing unused
     /// or empty translation based on the configured settings.
     ///
@@ -775,6 +1171,11 @@ impl<P: AsRef<Path>> OtherPurger<P> {
     /// - `ignore_map` - Optional map to store ignored entries
     /// - `stat_vec` - Optional vector to store statistics
     ///
+    /// # Returns
+    ///
+    /// Lines that were purged, or would be purged with dry run, along with the reason.
+    /// Lines are reported as ignored, when they're already present in the passed `ignore_map`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -793,7 +1194,19 @@ impl<P: AsRef<Path>> OtherPurger<P> {
     /// purger.purge(None, None);
     /// ```
     #[inline(always)]
-    pub fn purge(self, mut ignore_map: Option<&mut IgnoreMap>, mut stat_vec: Option<&mut Vec<(String, String)>>) {
+    pub fn purge(
+        mut self,
+        mut ignore_map: Option<&mut IgnoreMap>,
+        mut stat_vec: Option<&mut Vec<(String, String)>>,
+    ) -> Vec<PurgedLine> {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.apply_header(&*observer) {
+            return Vec::new();
+        }
+
+        let mut purged: Vec<PurgedLine> = Vec::new();
+
         let (
             name_label,
             nickname_label,
@@ -810,14 +1223,31 @@ impl<P: AsRef<Path>> OtherPurger<P> {
         ) = get_other_labels(self.engine_type);
 
         let mut skip_indices: HashSet<usize, GxBuildHasher> = HashSet::default();
+        let excluded_files: Vec<String> =
+            resolve_excluded_files(self.excluded_files.as_deref(), &self.included_files, self.game_type);
 
-        for (filename, path) in read_dir(self.original_path)
+        // Files are processed in the same order, as in the reader
+        let mut files: Vec<(String, PathBuf)> = read_dir(self.original_path)
             .unwrap_log()
-            .filter_map(|entry| filter_other(entry, self.engine_type, self.game_type))
-        {
+            .filter_map(|entry| filter_other(entry, self.engine_type, &excluded_files))
+            .filter(|(filename, _)| self.file_filter.matches(filename))
+            .collect();
+        files.sort_unstable();
+        observer.files_found(files.len());
+
+        let engine_type: EngineType = self.engine_type;
+        let purge_empty: bool = self.purge_empty;
+
+        // Files aren't parsed, if only empty lines are purged
+        let parsed_files = par_map_ordered(files, self.threads, |_, path| {
+            (!purge_empty).then(|| parse_rpgm_file(path, engine_type))
+        });
+
+        for (filename, _, obj_arr) in parsed_files {
             let basename: String = filename.rsplit_once('.').unwrap_log().0.to_owned().to_lowercase();
             let txt_filename: String = basename.clone() + ".txt";
             let txt_output_path: &Path = &self.translation_path.as_ref().join(txt_filename.clone());
+            observer.file_started(&txt_filename);
 
             let mut lines: IndexSetGx = IndexSet::default();
             let lines_mut_ref: &mut IndexSetGx = unsafe { &mut *(&mut lines as *mut IndexSetGx) };
@@ -841,12 +1271,10 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                 None
             };
 
-            let translation_map: IndexMapGx = IndexMap::from_iter(parse_translation(
-                &read_to_string(txt_output_path).unwrap_log(),
-                &txt_filename,
-                false,
-                true,
-            ));
+            let (translation, mut attached): (String, Attached) =
+                detach_comments(&read_to_string(txt_output_path).unwrap_log(), is_meta_comment);
+            let translation_map: IndexMapGx =
+                IndexMap::from_iter(parse_translation(&translation, &txt_filename, false, true, &*observer));
 
             if self.purge_empty {
                 for (i, (original, translation)) in translation_map.iter().enumerate() {
@@ -858,6 +1286,14 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                                 .push((original.to_owned(), translation.to_owned()));
                         } else {
                             skip_indices.insert(i);
+                            purged.push(purged_line(
+                                &txt_filename,
+                                None,
+                                original,
+                                translation,
+                                PurgeReason::Empty,
+                                ignore_entry.as_deref(),
+                            ));
 
                             if self.create_ignore {
                                 ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -866,7 +1302,7 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                     }
                 }
             } else {
-                let obj_arr: Value = parse_rpgm_file(&path, self.engine_type);
+                let obj_arr: Value = obj_arr.unwrap_log();
 
                 // Other files except CommonEvents and Troops have the structure that consists
                 // of name, nickname, description and note
@@ -880,8 +1316,8 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                         ]);
                     }
 
-                    if let Some(obj_real_arr) = obj_arr.as_array() {
-                        'obj: for obj in obj_real_arr {
+                    if let Some(objects) = get_objects(&obj_arr) {
+                        'obj: for obj in objects {
                             for (variable_label, variable_type) in [
                                 (name_label, Variable::Name),
                                 (nickname_label, Variable::Nickname),
@@ -937,12 +1373,13 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                                 );
 
                                 if let Some(parsed) = parsed {
-                                    let mut replaced: String = String::from_iter(parsed.split('\n').map(
-                                        |x: &str| if self.trim { x.trim_replace() } else { x.to_owned() } + NEW_LINE,
-                                    ));
+                                    let mut replaced: String =
+                                        String::from_iter(parsed.split('\n').map(
+                                            |x: &str| if self.trim { x.trim_replace() } else { x.to_owned() } + "\n",
+                                        ));
 
-                                    replaced.drain(replaced.len() - 2..);
-                                    lines_mut_ref.insert(replaced);
+                                    replaced.pop();
+                                    lines_mut_ref.insert(escape(&replaced));
                                 } else if variable_type.is_name() {
                                     continue 'obj;
                                 }
@@ -1000,6 +1437,14 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                                 .push((original.to_owned(), translation.to_owned()));
                         } else {
                             skip_indices.insert(i);
+                            purged.push(purged_line(
+                                &txt_filename,
+                                None,
+                                original,
+                                translation,
+                                PurgeReason::NotInSource,
+                                ignore_entry.as_deref(),
+                            ));
 
                             if self.create_ignore {
                                 ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1019,16 +1464,24 @@ impl<P: AsRef<Path>> OtherPurger<P> {
                 );
 
                 output_content.pop();
+                output_content = attach_comments(output_content, &mut attached);
+                output_content = purge_states(output_content, &self.purge_states, &txt_filename, &mut purged);
 
-                write(txt_output_path, output_content).unwrap_log();
+                if !self.dry_run {
+                    write(
+                        txt_output_path,
+                        with_header(output_content, Header::parse(&translation).as_ref()),
+                    )
+                    .unwrap_log();
 
-                if self.logging {
-                    println!("{PURGED_FILE_MSG} {basename}.txt",);
+                    observer.file_finished(&txt_filename, FileAction::Purged);
                 }
             }
 
             skip_indices.clear();
         }
+
+        purged
     }
 }
 
@@ -1050,6 +1503,11 @@ impl<P: AsRef<Path>> OtherPurger<P> {
 /// - `purge_empty` - Whether to purge empty translation
 /// - `create_ignore` - Whether to create an ignore file for purged entries
 /// - `trim` - Whether to trim whitespace from strings
+/// - `dry_run` - Whether to only report lines that would be purged, without modifying files
+/// - `purge_states` - Review states of lines, that will be purged
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct SystemPurger<P: AsRef<Path>> {
     system_file_path: P,
     translation_path: P,
@@ -1061,6 +1519,11 @@ pub struct SystemPurger<P: AsRef<Path>> {
     purge_empty: bool,
     create_ignore: bool,
     trim: bool,
+    dry_run: bool,
+    purge_states: Vec<LineState>,
+    header_mode: HeaderMode,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> SystemPurger<P> {
@@ -1085,6 +1548,11 @@ impl<P: AsRef<Path>> SystemPurger<P> {
     /// - `create_ignore`: `false`
     /// - `purge_empty`: `false`
     /// - `trim`: `false`
+    /// - `dry_run`: `false`
+    /// - `purge_states`: empty
+    /// - `header_mode`: `HeaderMode::Validate`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1103,6 +1571,11 @@ impl<P: AsRef<Path>> SystemPurger<P> {
             create_ignore: false,
             purge_empty: false,
             trim: false,
+            dry_run: false,
+            purge_states: Vec::new(),
+            header_mode: HeaderMode::Validate,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1165,6 +1638,76 @@ impl<P: AsRef<Path>> SystemPurger<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the purger doesn't modify any files, and only returns the lines
+    /// it would purge.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that will be purged.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are purged along with
+    /// their comments, regardless of other settings.
+    pub fn purge_states(mut self, purge_states: Vec<LineState>) -> Self {
+        self.purge_states = purge_states;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("system.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
     /// This method analyzes the `System` file and its translation, removing unused
     /// or empty translation based on the configured settings.
     ///
@@ -1173,6 +1716,11 @@ impl<P: AsRef<Path>> SystemPurger<P> {
     /// - `ignore_map` - Optional map to store ignored entries
     /// - `stat_vec` - Optional vector to store statistics
     ///
+    /// # Returns
+    ///
+    /// Lines that were purged, or would be purged with dry run, along with the reason.
+    /// Lines are reported as ignored, when they're already present in the passed `ignore_map`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1191,7 +1739,22 @@ impl<P: AsRef<Path>> SystemPurger<P> {
     /// purger.purge(None, None);
     /// ```
     #[inline(always)]
-    pub fn purge(self, mut ignore_map: Option<&mut IgnoreMap>, mut stat_vec: Option<&mut Vec<(String, String)>>) {
+    pub fn purge(
+        mut self,
+        mut ignore_map: Option<&mut IgnoreMap>,
+        mut stat_vec: Option<&mut Vec<(String, String)>>,
+    ) -> Vec<PurgedLine> {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("System") || !self.apply_header(&*observer) {
+            return Vec::new();
+        }
+
+        observer.files_found(1);
+        observer.file_started("system.txt");
+
+        let mut purged: Vec<PurgedLine> = Vec::new();
+
         let txt_output_path: &Path = &self.translation_path.as_ref().join("system.txt");
 
         let lines: UnsafeCell<IndexSetGx> = UnsafeCell::new(IndexSet::default());
@@ -1216,12 +1779,11 @@ impl<P: AsRef<Path>> SystemPurger<P> {
             None
         };
 
-        let translation_map: IndexMapGx = IndexMap::from_iter(parse_translation(
-            &read_to_string(txt_output_path).unwrap_log(),
-            "system.txt",
-            false,
-            true,
-        ));
+        let (translation, mut attached): (String, Attached) =
+            detach_comments(&read_to_string(txt_output_path).unwrap_log(), is_meta_comment);
+        // Not a map, since identical strings may repeat in different sections
+        let translation_map: Vec<(String, String)> =
+            Vec::from_iter(parse_translation(&translation, "system.txt", false, true, &*observer));
 
         let mut skip_indices: HashSet<usize, GxBuildHasher> = HashSet::default();
 
@@ -1235,6 +1797,14 @@ impl<P: AsRef<Path>> SystemPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "system.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::Empty,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1268,7 +1838,7 @@ impl<P: AsRef<Path>> SystemPurger<P> {
                     string = romanize_string(string)
                 }
 
-                lines_mut_ref.insert(string);
+                lines_mut_ref.insert(escape(&string));
             };
 
             let (
@@ -1355,7 +1925,7 @@ impl<P: AsRef<Path>> SystemPurger<P> {
                     game_title_string = romanize_string(game_title_string)
                 }
 
-                lines_mut_ref.insert(game_title_string);
+                lines_mut_ref.insert(escape(&game_title_string));
             }
 
             for (i, (original, translation)) in translation_map.iter().enumerate() {
@@ -1371,6 +1941,14 @@ impl<P: AsRef<Path>> SystemPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "system.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::NotInSource,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1390,13 +1968,21 @@ impl<P: AsRef<Path>> SystemPurger<P> {
             );
 
             output_content.pop();
+            output_content = attach_comments(output_content, &mut attached);
+            output_content = purge_states(output_content, &self.purge_states, "system.txt", &mut purged);
 
-            write(txt_output_path, output_content).unwrap_log();
+            if !self.dry_run {
+                write(
+                    txt_output_path,
+                    with_header(output_content, Header::parse(&translation).as_ref()),
+                )
+                .unwrap_log();
 
-            if self.logging {
-                println!("{PURGED_FILE_MSG} system.txt");
+                observer.file_finished("system.txt", FileAction::Purged);
             }
         }
+
+        purged
     }
 }
 
@@ -1416,6 +2002,10 @@ impl<P: AsRef<Path>> SystemPurger<P> {
 /// - `leave_filled` - Whether to leave filled translation even if they're unused
 /// - `purge_empty` - Whether to purge empty translation
 /// - `create_ignore` - Whether to create an ignore file for purged entries
+/// - `dry_run` - Whether to only report lines that would be purged, without modifying files
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct PluginPurger<P: AsRef<Path>> {
     plugins_file_path: P,
     translation_path: P,
@@ -1425,6 +2015,10 @@ pub struct PluginPurger<P: AsRef<Path>> {
     leave_filled: bool,
     purge_empty: bool,
     create_ignore: bool,
+    dry_run: bool,
+    header_mode: HeaderMode,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> PluginPurger<P> {
@@ -1447,6 +2041,10 @@ impl<P: AsRef<Path>> PluginPurger<P> {
     /// - `leave_filled`: `false`
     /// - `create_ignore`: `false`
     /// - `purge_empty`: `false`
+    /// - `dry_run`: `false`
+    /// - `header_mode`: `HeaderMode::Validate`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1462,6 +2060,10 @@ impl<P: AsRef<Path>> PluginPurger<P> {
             leave_filled: false,
             create_ignore: false,
             purge_empty: false,
+            dry_run: false,
+            header_mode: HeaderMode::Validate,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1516,6 +2118,63 @@ impl<P: AsRef<Path>> PluginPurger<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the purger doesn't modify any files, and only returns the lines
+    /// it would purge.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            romanize: Some(self.romanize),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("plugins.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.romanize = settings.romanize.unwrap_log();
+
+        true
+    }
+
     /// This method analyzes the `plugins.js` file and its translation, removing unused
     /// or empty translation based on the configured settings.
     ///
@@ -1524,6 +2183,11 @@ impl<P: AsRef<Path>> PluginPurger<P> {
     /// - `ignore_map` - Optional map to store ignored entries
     /// - `stat_vec` - Optional vector to store statistics
     ///
+    /// # Returns
+    ///
+    /// Lines that were purged, or would be purged with dry run, along with the reason.
+    /// Lines are reported as ignored, when they're already present in the passed `ignore_map`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1540,7 +2204,22 @@ impl<P: AsRef<Path>> PluginPurger<P> {
     /// purger.purge(None, None);
     /// ```
     #[inline(always)]
-    pub fn purge(self, mut ignore_map: Option<&mut IgnoreMap>, mut stat_vec: Option<&mut Vec<(String, String)>>) {
+    pub fn purge(
+        mut self,
+        mut ignore_map: Option<&mut IgnoreMap>,
+        mut stat_vec: Option<&mut Vec<(String, String)>>,
+    ) -> Vec<PurgedLine> {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("plugins") || !self.apply_header(&*observer) {
+            return Vec::new();
+        }
+
+        observer.files_found(1);
+        observer.file_started("plugins.txt");
+
+        let mut purged: Vec<PurgedLine> = Vec::new();
+
         let txt_output_path: &Path = &self.translation_path.as_ref().join("plugins.txt");
 
         let mut ignore_entry: Option<&mut IgnoreEntry> = if self.create_ignore {
@@ -1555,12 +2234,9 @@ impl<P: AsRef<Path>> PluginPurger<P> {
             None
         };
 
-        let mut translation_map: IndexMapGx = IndexMapGx::from_iter(parse_translation(
-            &read_to_string(txt_output_path).unwrap_log(),
-            "plugins.txt",
-            false,
-            false,
-        ));
+        let translation: String = read_to_string(txt_output_path).unwrap_log();
+        let mut translation_map: IndexMapGx =
+            IndexMapGx::from_iter(parse_translation(&translation, "plugins.txt", false, false, &*observer));
 
         let mut skip_indices: HashSet<usize, GxBuildHasher> = HashSet::default();
 
@@ -1581,6 +2257,14 @@ impl<P: AsRef<Path>> PluginPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "plugins.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::Empty,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1625,6 +2309,14 @@ impl<P: AsRef<Path>> PluginPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "plugins.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::NotInSource,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1634,7 +2326,7 @@ impl<P: AsRef<Path>> PluginPurger<P> {
             }
         }
 
-        if !self.stat {
+        if !self.stat && !self.dry_run {
             let mut output_content: String = String::from_iter(
                 translation_map
                     .into_iter()
@@ -1645,12 +2337,16 @@ impl<P: AsRef<Path>> PluginPurger<P> {
 
             output_content.pop();
 
-            write(txt_output_path, output_content).unwrap_log();
+            write(
+                txt_output_path,
+                with_header(output_content, Header::parse(&translation).as_ref()),
+            )
+            .unwrap_log();
 
-            if self.logging {
-                println!("{PURGED_FILE_MSG} plugins.txt");
-            }
+            observer.file_finished("plugins.txt", FileAction::Purged);
         }
+
+        purged
     }
 }
 
@@ -1671,6 +2367,10 @@ impl<P: AsRef<Path>> PluginPurger<P> {
 /// - `leave_filled` - Whether to leave filled translation even if they're unused
 /// - `purge_empty` - Whether to purge empty translation
 /// - `create_ignore` - Whether to create an ignore file for purged entries
+/// - `dry_run` - Whether to only report lines that would be purged, without modifying files
+/// - `header_mode` - How to treat the header of `.txt` files
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct ScriptPurger<P: AsRef<Path>> {
     scripts_file_path: P,
     translation_path: P,
@@ -1680,6 +2380,10 @@ pub struct ScriptPurger<P: AsRef<Path>> {
     leave_filled: bool,
     purge_empty: bool,
     create_ignore: bool,
+    dry_run: bool,
+    header_mode: HeaderMode,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> ScriptPurger<P> {
@@ -1702,6 +2406,10 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
     /// - `leave_filled`: `false`
     /// - `create_ignore`: `false`
     /// - `purge_empty`: `false`
+    /// - `dry_run`: `false`
+    /// - `header_mode`: `HeaderMode::Validate`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1717,6 +2425,10 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
             leave_filled: false,
             create_ignore: false,
             purge_empty: false,
+            dry_run: false,
+            header_mode: HeaderMode::Validate,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1771,6 +2483,63 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the purger doesn't modify any files, and only returns the lines
+    /// it would purge.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            romanize: Some(self.romanize),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("scripts.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.romanize = settings.romanize.unwrap_log();
+
+        true
+    }
+
     /// This method analyzes the `Scripts` file and its translation, removing unused
     /// or empty translation based on the configured settings.
     ///
@@ -1779,6 +2548,11 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
     /// - `ignore_map` - Optional map to store ignored entries
     /// - `stat_vec` - Optional vector to store statistics
     ///
+    /// # Returns
+    ///
+    /// Lines that were purged, or would be purged with dry run, along with the reason.
+    /// Lines are reported as ignored, when they're already present in the passed `ignore_map`.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1795,16 +2569,28 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
     /// purger.purge(None, None);
     /// ```
     #[inline(always)]
-    pub fn purge(self, mut ignore_map: Option<&mut IgnoreMap>, mut stat_vec: Option<&mut Vec<(String, String)>>) {
+    pub fn purge(
+        mut self,
+        mut ignore_map: Option<&mut IgnoreMap>,
+        mut stat_vec: Option<&mut Vec<(String, String)>>,
+    ) -> Vec<PurgedLine> {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("Scripts") || !self.apply_header(&*observer) {
+            return Vec::new();
+        }
+
+        observer.files_found(1);
+        observer.file_started("scripts.txt");
+
+        let mut purged: Vec<PurgedLine> = Vec::new();
+
         let txt_output_path: &Path = &self.translation_path.as_ref().join("scripts.txt");
 
         let mut lines_vec: Vec<String> = Vec::new();
-        let translation_map: Vec<(String, String)> = Vec::from_iter(parse_translation(
-            &read_to_string(txt_output_path).unwrap_log(),
-            "scripts.txt",
-            false,
-            false,
-        ));
+        let translation: String = read_to_string(txt_output_path).unwrap_log();
+        let translation_map: Vec<(String, String)> =
+            Vec::from_iter(parse_translation(&translation, "scripts.txt", false, false, &*observer));
 
         if self.stat {
             stat_vec
@@ -1837,6 +2623,14 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "scripts.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::Empty,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1927,6 +2721,14 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
                             .push((original.to_owned(), translation.to_owned()));
                     } else {
                         skip_indices.insert(i);
+                        purged.push(purged_line(
+                            "scripts.txt",
+                            None,
+                            original,
+                            translation,
+                            PurgeReason::NotInSource,
+                            ignore_entry.as_deref(),
+                        ));
 
                         if self.create_ignore {
                             ignore_entry.as_mut().unwrap().insert(original.to_owned());
@@ -1936,7 +2738,7 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
             }
         }
 
-        if !self.stat {
+        if !self.stat && !self.dry_run {
             let mut output_content: String = String::from_iter(
                 translation_map
                     .into_iter()
@@ -1947,11 +2749,15 @@ impl<P: AsRef<Path>> ScriptPurger<P> {
 
             output_content.pop();
 
-            write(txt_output_path, output_content).unwrap_log();
+            write(
+                txt_output_path,
+                with_header(output_content, Header::parse(&translation).as_ref()),
+            )
+            .unwrap_log();
 
-            if self.logging {
-                println!("{PURGED_FILE_MSG} scripts.txt");
-            }
+            observer.file_finished("scripts.txt", FileAction::Purged);
         }
+
+        purged
     }
 }
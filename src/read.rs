@@ -1,19 +1,26 @@
-#[cfg(feature = "log")]
-use crate::println;
 use crate::{
+    comments::{is_annotation_comment, is_meta_comment},
+    context::{
+        apply_ids, apply_overrides, attach_comments, detach_comments, list_location, merge_by_ids, object_id,
+        parse_overrides, Attached, Occurrences,
+    },
     determine_extension,
     functions::{
-        extract_strings, filter_maps, filter_other, get_maps_labels, get_object_data, get_other_labels,
-        get_system_labels, is_allowed_code, is_bad_code, parse_ignore, parse_map_number, parse_rpgm_file,
-        parse_translation, process_parameter, process_variable, romanize_string, string_is_only_symbols, traverse_json,
-    },
-    statics::{
-        localization::{FILES_ARE_NOT_PARSED_MSG, FILE_ALREADY_EXISTS_MSG, PARSED_FILE_MSG},
-        ENCODINGS, LINES_SEPARATOR, NEW_LINE,
+        escape, extract_strings, filter_maps, filter_other, get_maps_labels, get_object_data, get_objects,
+        get_other_labels, get_system_dev_labels, get_system_labels, is_allowed_code, is_bad_code, par_map_ordered,
+        parse_ignore, parse_map_number, parse_rpgm_file, parse_translation, process_parameter, process_variable,
+        resolve_excluded_files, romanize_string, string_is_only_symbols, system_section_comment, traverse_json,
     },
+    header::{with_header, Header},
+    layout::{maps_exist, read_maps, replace_maps, retain_maps, write_maps, MapBlocksWriter},
+    memory::{parse_review, write_review, ReviewMap, TranslationMemory},
+    observer::{resolve_observer, FileAction, Observer, SkipReason, Warning},
+    pack::TranslationPack,
+    statics::{ENCODINGS, LINES_SEPARATOR},
+    tree::{insert_map_paths, is_map_path_comment, MapTree},
     types::{
-        Code, EngineType, GameType, IgnoreEntry, IgnoreMap, IndexMapGx, IndexSetGx, MapsProcessingMode, OptionExt,
-        ProcessingMode, ResultExt, TrimReplace, Variable,
+        Code, EngineType, FileFilter, GameType, HashMapGx, IgnoreEntry, IgnoreMap, IndexMapGx, IndexSetGx, MapsLayout,
+        MapsProcessingMode, OptionExt, ProcessingMode, ResultExt, TrimReplace, Variable,
     },
 };
 use flate2::read::ZlibDecoder;
@@ -25,10 +32,13 @@ use smallvec::SmallVec;
 use sonic_rs::{from_str, from_value, prelude::*, Array, Value};
 use std::{
     cell::UnsafeCell,
+    collections::HashMap,
+    fmt::Write,
     fs::{read, read_dir, read_to_string, write},
     io::Read,
     mem::{take, transmute},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -48,13 +58,15 @@ fn parse_list<'a>(
     ignore_entry: Option<&IgnoreEntry>,
     trim: bool,
     sort: bool,
+    mut locations: Option<(&str, &mut Occurrences)>,
 ) {
     let mut in_sequence: bool = false;
+    let mut sequence_start: usize = 0;
 
     let mut lines_vec: SmallVec<[&str; 4]> = SmallVec::with_capacity(4);
     let buf: UnsafeCell<SmallVec<[Vec<u8>; 4]>> = UnsafeCell::new(SmallVec::with_capacity(4));
 
-    let mut process_parameter = |code: Code, parameter: &str| {
+    let mut process_parameter = |code: Code, parameter: &str, index: usize, choice: Option<usize>| {
         if let Some(parsed) = process_parameter(code, parameter, game_type, engine_type, romanize, None, None, false) {
             if let Some(entry) = ignore_entry {
                 if entry.contains(&parsed) {
@@ -62,6 +74,15 @@ fn parse_list<'a>(
                 }
             }
 
+            if let Some((prefix, occurrences)) = locations.as_mut() {
+                let location: String = match choice {
+                    Some(choice) => format!("{prefix}/{index}:{choice}"),
+                    None => format!("{prefix}/{index}"),
+                };
+
+                occurrences.entry(parsed.clone()).or_default().push(location);
+            }
+
             if maps_processing_mode == Some(MapsProcessingMode::Preserve) {
                 let vec: &mut &mut Vec<(String, String)> = unsafe { translation_map_vec.as_mut().unwrap_unchecked() };
                 let pos: usize = *lines_pos;
@@ -131,7 +152,7 @@ fn parse_list<'a>(
         }
     };
 
-    for item in list {
+    for (it, item) in list.iter().enumerate() {
         let code: u16 = item[code_label].as_u64().unwrap_log() as u16;
 
         let code: Code = if is_allowed_code(code) {
@@ -150,9 +171,9 @@ fn parse_list<'a>(
             && (!code.is_any_dialogue() || (engine_type.is_xp() && code.is_dialogue_start() && !lines_vec.is_empty()))
         {
             if !lines_vec.is_empty() {
-                let joined: String = lines_vec.join(NEW_LINE);
+                let joined: String = escape(&lines_vec.join("\n"));
 
-                process_parameter(Code::Dialogue, &joined);
+                process_parameter(Code::Dialogue, &joined, sequence_start, None);
 
                 lines_vec.clear();
                 unsafe { (*buf.get()).clear() };
@@ -188,7 +209,7 @@ fn parse_list<'a>(
                     continue;
                 }
 
-                process_parameter(code, if trim { trimmed } else { subparameter_string });
+                process_parameter(code, if trim { trimmed } else { subparameter_string }, it, Some(i));
             }
         } else {
             let parameter_string: &str = value
@@ -196,7 +217,7 @@ fn parse_list<'a>(
                 .unwrap_or_else(|| match value.as_object() {
                     Some(obj) => unsafe {
                         (*buf.get()).push(get_object_data(obj));
-                        std::str::from_utf8_unchecked(&(*buf.get())[lines_vec.len()])
+                        std::str::from_utf8_unchecked(&(&(*buf.get()))[lines_vec.len()])
                     },
                     None => "",
                 })
@@ -207,10 +228,14 @@ fn parse_list<'a>(
             }
 
             if code.is_any_dialogue() {
+                if lines_vec.is_empty() {
+                    sequence_start = it;
+                }
+
                 lines_vec.push(parameter_string);
                 in_sequence = true;
             } else {
-                process_parameter(code, parameter_string);
+                process_parameter(code, parameter_string, it, None);
             }
         }
     }
@@ -295,16 +320,25 @@ fn process_map_comments_vec(
     map_display_name_comment: String,
 ) {
     if !map_name_comment.is_empty() {
+        let mut translation: String = String::new();
+
         if let Some(index) = find_starting_comment(
             translation_map_vec.iter().map(|(a, b)| (a, b)),
             *lines_pos,
             "<!-- Map Name",
-        ) {
-            translation_map_vec.remove(index);
+        )
+        .map(|index| index + *lines_pos)
+        {
+            let (original, t) = translation_map_vec.remove(index);
+
+            // Translation of the map name is kept, unless the map was renamed
+            if original == map_name_comment {
+                translation = t;
+            }
         }
 
         *map_number_comment_index += 1;
-        translation_map_vec.insert(*map_number_comment_index, (map_name_comment, String::new()));
+        translation_map_vec.insert(*map_number_comment_index, (map_name_comment, translation));
         *lines_pos += 1;
     }
 
@@ -315,7 +349,9 @@ fn process_map_comments_vec(
             translation_map_vec.iter().map(|(a, b)| (a, b)),
             *lines_pos,
             "<!-- In-game",
-        ) {
+        )
+        .map(|index| index + *lines_pos)
+        {
             if translation_map_vec.get(index).is_some() {
                 let (_, t) = translation_map_vec.remove(index);
                 translation = t;
@@ -328,6 +364,60 @@ fn process_map_comments_vec(
     }
 }
 
+/// Inserts the comment with the name of the map event, which precedes the lines of the event.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+fn process_event_name_comment(
+    event_name_comment: String,
+    processing_mode: ProcessingMode,
+    maps_processing_mode: MapsProcessingMode,
+    translation_map: &mut IndexMapGx,
+    lines_set: &mut IndexSetGx,
+    translation_map_vec: &mut Vec<(String, String)>,
+    lines_pos: &mut usize,
+) {
+    match maps_processing_mode {
+        MapsProcessingMode::Preserve => {
+            if processing_mode.is_append() {
+                match translation_map_vec.get(*lines_pos) {
+                    Some((original, _)) if *original == event_name_comment => {}
+                    Some(_) => translation_map_vec.insert(*lines_pos, (event_name_comment, String::new())),
+                    None => translation_map_vec.push((event_name_comment, String::new())),
+                }
+            } else {
+                translation_map_vec.push((event_name_comment, String::new()));
+            }
+
+            *lines_pos += 1;
+        }
+        MapsProcessingMode::Default => {
+            if !translation_map.contains_key(&event_name_comment) {
+                if processing_mode.is_append() {
+                    let pos: usize = (*lines_pos).min(translation_map.len());
+                    translation_map.shift_insert(pos, event_name_comment, String::new());
+                } else {
+                    translation_map.insert(event_name_comment, String::new());
+                }
+            }
+
+            *lines_pos += 1;
+        }
+        MapsProcessingMode::Separate => {
+            lines_set.insert(event_name_comment.clone());
+
+            if !translation_map.contains_key(&event_name_comment) {
+                let pos: usize = lines_set.len() - 1;
+
+                if processing_mode.is_append() && pos <= translation_map.len() {
+                    translation_map.shift_insert(pos, event_name_comment, String::new());
+                } else {
+                    translation_map.insert(event_name_comment, String::new());
+                }
+            }
+        }
+    }
+}
+
 /// A struct for reading map files and parsing them into `.txt` files.
 ///
 /// This reader extracts translatable text from RPG Maker map files and writes them to a
@@ -347,6 +437,16 @@ fn process_map_comments_vec(
 /// - `ignore` - Whether to ignore entries specified in `.rvpacker-ignore`
 /// - `trim` - Whether to trim whitespace from extracted strings
 /// - `sort` - Whether to sort entries on `Append` processing mode
+/// - `memory` - Optional translation memory to fill untranslated entries from
+/// - `keyed` - Whether to emit location-specific overrides for duplicated lines
+/// - `ids` - Whether to emit stable IDs of lines
+/// - `event_names` - Whether to emit names of map events
+/// - `maps_layout` - Layout of the maps translation
+/// - `tree_order` - Whether to order maps by the map tree
+/// - `file_filter` - Filter of processed files
+/// - `threads` - Maximum number of threads, used to parse files
+/// - `streaming` - Whether to process maps block by block
+/// - `observer` - Observer of progress and events
 pub struct MapReader<P: AsRef<Path>> {
     original_path: P,
     output_path: P,
@@ -359,6 +459,16 @@ pub struct MapReader<P: AsRef<Path>> {
     ignore: bool,
     trim: bool,
     sort: bool,
+    memory: Option<Arc<TranslationMemory>>,
+    keyed: bool,
+    ids: bool,
+    event_names: bool,
+    maps_layout: MapsLayout,
+    tree_order: bool,
+    file_filter: FileFilter,
+    threads: usize,
+    streaming: bool,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> MapReader<P> {
@@ -384,6 +494,16 @@ impl<P: AsRef<Path>> MapReader<P> {
     /// - `ignore`: `false`
     /// - `trim`: `false`
     /// - `sort`: `false`
+    /// - `memory`: `None`
+    /// - `keyed`: `false`
+    /// - `ids`: `false`
+    /// - `event_names`: `false`
+    /// - `maps_layout`: `MapsLayout::Single`
+    /// - `tree_order`: `false`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `threads`: `0`
+    /// - `streaming`: `false`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -403,6 +523,16 @@ impl<P: AsRef<Path>> MapReader<P> {
             ignore: false,
             trim: false,
             sort: false,
+            memory: None,
+            keyed: false,
+            ids: false,
+            event_names: false,
+            maps_layout: MapsLayout::Single,
+            tree_order: false,
+            file_filter: FileFilter::default(),
+            threads: 0,
+            streaming: false,
+            observer: None,
         }
     }
 
@@ -484,6 +614,117 @@ impl<P: AsRef<Path>> MapReader<P> {
         self
     }
 
+    /// Sets the translation memory.
+    ///
+    /// When set and `processing_mode` is `Append` or `Force`, untranslated entries are filled
+    /// with exact matches from the memory, and fuzzy matches are suggested. Both are listed
+    /// in the `.rvpacker-review` file in the output path.
+    pub fn memory(mut self, memory: Option<Arc<TranslationMemory>>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets whether to read in keyed mode.
+    ///
+    /// When enabled, every event line, that occurs in more than one location, is preceded by
+    /// `<!-- Override: location --><#>` comments, one per location. Translation of such comment
+    /// is used instead of the shared translation at that location, when writing.
+    /// Location is `file/id/page/command`, see [`list_location`].
+    ///
+    /// Has no effect in `Preserve` maps processing mode, as it already keeps every occurrence.
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.keyed = keyed;
+        self
+    }
+
+    /// Sets whether to emit stable IDs of lines.
+    ///
+    /// When enabled, every line with a known location is preceded by `<!-- ID --><#>location`
+    /// comment, where location is the location of the first occurrence of the line, see
+    /// [`list_location`].
+    ///
+    /// In `Append` processing mode, the file is read from scratch and existing translation is
+    /// merged into it by IDs, falling back to originals, so translation survives reordering of
+    /// lines. Lines, which original changed under the same ID, keep their translation and are
+    /// marked for review.
+    pub fn ids(mut self, ids: bool) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets whether to emit names of map events.
+    ///
+    /// When enabled, lines of every named event are preceded by `<!-- Event Name: name --><#>`
+    /// comment. Translation of such comment is written to the event name with
+    /// [`MapWriter::event_names`](crate::write::MapWriter::event_names).
+    ///
+    /// Map names from `MapInfos` are always emitted as `<!-- Map Name: name --><#>` comments,
+    /// and their translation is written with [`MapWriter::map_names`](crate::write::MapWriter::map_names).
+    pub fn event_names(mut self, event_names: bool) -> Self {
+        self.event_names = event_names;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// With `MapsLayout::PerMap` and `MapsLayout::PerMapNamed`, every map is stored in its own file in
+    /// the `maps` directory instead of the single `maps.txt` file. Use
+    /// [`convert_maps_layout`](crate::layout::convert_maps_layout) to convert existing translation.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets whether to order maps by the map tree.
+    ///
+    /// When enabled, maps are ordered as they're shown in the editor, so translators can work
+    /// region by region, and every map is preceded by `<!-- Map Path: World/Town/Inn --><#>`
+    /// comment. Otherwise, maps are ordered by their numbers.
+    ///
+    /// Must be the same value, as in previous read, with `MapsProcessingMode::Preserve`.
+    pub fn tree_order(mut self, tree_order: bool) -> Self {
+        self.tree_order = tree_order;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the maximum number of threads, used to parse files.
+    ///
+    /// Files are parsed in parallel, but processed in the same order, so the output doesn't depend on it.
+    /// `0` means the number of logical CPUs, `1` parses files sequentially.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets whether to process maps block by block.
+    ///
+    /// When enabled, every map is written to the `.txt` content as soon as it's parsed, so memory use scales with
+    /// the largest map rather than with the whole project. The output is the same, as without streaming.
+    ///
+    /// Streaming is only used in `Default` and `Force` processing modes with `Default` or `Separate` maps
+    /// processing mode, and without IDs, keyed mode, translation memory and file filter. Otherwise, maps are
+    /// processed as usual, and [`Warning::StreamingUnsupported`] is reported.
+    pub fn streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// This method reads all map files from the original path, extracts translatable
     /// text, and writes it to a structured text file in the output path. The behavior
     /// is controlled by the various settings configured on the MapReader instance.
@@ -504,13 +745,35 @@ impl<P: AsRef<Path>> MapReader<P> {
     /// ```
     #[inline(always)]
     pub fn read(self) {
-        let txt_output_path: &Path = &self.output_path.as_ref().join("maps.txt");
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
 
-        if self.processing_mode.is_default() && txt_output_path.exists() {
-            println!("maps.txt {FILE_ALREADY_EXISTS_MSG}");
+        let output_path: &Path = self.output_path.as_ref();
+        let maps_exist: bool = maps_exist(output_path, self.maps_layout);
+
+        if self.processing_mode.is_default() && maps_exist {
+            observer.file_skipped("maps.txt", SkipReason::AlreadyExists);
             return;
         }
 
+        // With the file filter, only matching maps are read, and other maps are kept as is.
+        let full_translation: Option<String> =
+            (self.file_filter.is_active() && maps_exist).then(|| read_maps(output_path, self.maps_layout));
+        let read_translation = || match &full_translation {
+            Some(full_translation) => {
+                retain_maps(full_translation, |map_number| self.file_filter.matches_map(map_number))
+            }
+            None => read_maps(output_path, self.maps_layout),
+        };
+
+        // In `Append` mode with IDs, file is read from scratch and then merged with existing translation.
+        let mut processing_mode: ProcessingMode = self.processing_mode;
+        let mut existing_translation: Option<String> = None;
+
+        if self.ids && processing_mode.is_append() && maps_exist {
+            processing_mode = ProcessingMode::Force;
+            existing_translation = Some(read_translation());
+        }
+
         // Allocated when maps processing mode is DEFAULT or SEPARATE.
         let mut lines_set: IndexSetGx = IndexSet::default();
 
@@ -529,15 +792,36 @@ impl<P: AsRef<Path>> MapReader<P> {
 
         let mut ignore_map: IndexMap<String, IgnoreEntry, GxBuildHasher> = IndexMap::default();
 
-        if self.processing_mode.is_append() {
-            if txt_output_path.exists() {
+        // Used in keyed mode.
+        let keyed: bool = self.keyed && self.maps_processing_mode != MapsProcessingMode::Preserve;
+        let mut occurrences: Occurrences = Occurrences::default();
+        let mut overrides: HashMapGx = HashMapGx::default();
+        // State and review comments of existing lines, carried over when appending.
+        let mut annotations: Attached = Attached::default();
+
+        if let Some(existing_translation) = &existing_translation {
+            annotations = detach_comments(existing_translation, is_annotation_comment).1;
+
+            if self.ignore {
+                ignore_map = parse_ignore(self.output_path.as_ref().join(".rvpacker-ignore"));
+            }
+        }
+
+        if processing_mode.is_append() {
+            if maps_exist {
                 if self.ignore {
                     ignore_map = parse_ignore(self.output_path.as_ref().join(".rvpacker-ignore"));
                 }
 
-                let translation: String = read_to_string(txt_output_path).unwrap_log();
-                let parsed_translation: Box<dyn Iterator<Item = (String, String)>> =
-                    parse_translation(&translation, "maps.txt", false, self.trim);
+                let translation: String = read_translation();
+                overrides = parse_overrides(&translation, false);
+                annotations = detach_comments(&translation, is_annotation_comment).1;
+
+                // Map paths are inserted anew, as the tree may change
+                let parsed_translation: Box<dyn Iterator<Item = (String, String)>> = Box::new(
+                    parse_translation(&translation, "maps.txt", false, self.trim, &*observer)
+                        .filter(|(original, _)| !is_meta_comment(original) && !is_map_path_comment(original)),
+                );
 
                 match self.maps_processing_mode {
                     MapsProcessingMode::Default | MapsProcessingMode::Separate => {
@@ -571,13 +855,18 @@ impl<P: AsRef<Path>> MapReader<P> {
                     MapsProcessingMode::Preserve => translation_map_vec.extend(parsed_translation),
                 }
             } else {
-                println!("{FILES_ARE_NOT_PARSED_MSG}");
+                observer.file_skipped("maps.txt", SkipReason::NotParsed);
                 return;
             }
         };
 
         let (display_name_label, events_label, pages_label, list_label, code_label, parameters_label) =
             get_maps_labels(self.engine_type);
+        let event_name_label: &str = if self.engine_type.is_new() {
+            "name"
+        } else {
+            "__symbol__name"
+        };
 
         let mapinfos_path: PathBuf = self
             .original_path
@@ -585,12 +874,66 @@ impl<P: AsRef<Path>> MapReader<P> {
             .join("MapInfos".to_owned() + determine_extension(self.engine_type));
         let mapinfos: Value = parse_rpgm_file(&mapinfos_path, self.engine_type);
 
-        let maps_iter = read_dir(&self.original_path)
+        // Maps are sorted by numbers, so the order of maps in the `.txt` content doesn't depend on the file system
+        let mut maps: Vec<(String, PathBuf)> = read_dir(&self.original_path)
             .unwrap_log()
-            .filter_map(|entry| filter_maps(entry, self.engine_type));
+            .filter_map(|entry| filter_maps(entry, self.engine_type))
+            .filter(|(filename, _)| self.file_filter.matches(filename))
+            .collect();
+        maps.sort_unstable_by_key(|(filename, _)| parse_map_number(filename));
+
+        let tree: Option<MapTree> = self
+            .tree_order
+            .then(|| MapTree::from_value(&mapinfos, self.engine_type));
+        // Positions of maps in the tree, maps that aren't present in the tree go last
+        let tree_positions: HashMap<u16, usize, GxBuildHasher> = tree
+            .as_ref()
+            .map(|tree| tree.traverse().into_iter().enumerate().map(|(i, x)| (x, i)).collect())
+            .unwrap_or_default();
+        let tree_position = |map_number: u16| tree_positions.get(&map_number).copied().unwrap_or(usize::MAX);
+
+        if tree.is_some() {
+            maps.sort_by_key(|(filename, _)| tree_position(parse_map_number(filename)));
+        }
 
-        for (filename, path) in maps_iter {
-            let obj: Value = parse_rpgm_file(&path, self.engine_type);
+        let header: Header = Header {
+            engine_type: Some(self.engine_type),
+            maps_processing_mode: Some(self.maps_processing_mode),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        // Settings, which need the whole content at once, and therefore prevent streaming
+        let streaming_blockers: Vec<String> = [
+            (processing_mode.is_append(), "processing_mode"),
+            (self.maps_processing_mode == MapsProcessingMode::Preserve, "maps_processing_mode"),
+            (self.keyed, "keyed"),
+            (self.ids, "ids"),
+            (self.memory.is_some(), "memory"),
+            (self.file_filter.is_active(), "file_filter"),
+        ]
+        .into_iter()
+        .filter(|(blocks, _)| *blocks)
+        .map(|(_, setting)| setting.to_owned())
+        .collect();
+
+        if self.streaming && !streaming_blockers.is_empty() {
+            observer.warning(Warning::StreamingUnsupported {
+                file: String::from("maps.txt"),
+                settings: streaming_blockers.clone(),
+            });
+        }
+
+        // Maps are iterated in the order of the output, so every map can be written as soon as it's parsed
+        let mut blocks_writer: Option<MapBlocksWriter> = (self.streaming && streaming_blockers.is_empty())
+            .then(|| MapBlocksWriter::new(output_path, self.maps_layout, header.clone()));
+
+        let engine_type: EngineType = self.engine_type;
+        observer.files_found(maps.len());
+
+        for (filename, _, obj) in par_map_ordered(maps, self.threads, |_, path| parse_rpgm_file(path, engine_type)) {
+            observer.file_started(&filename);
             let map_number: u16 = parse_map_number(&filename);
             let map_number_string: String = map_number.to_string();
 
@@ -605,7 +948,7 @@ impl<P: AsRef<Path>> MapReader<P> {
             let map_name: String = get_map_name(&mapinfos, &entry, map_number, self.engine_type);
 
             if !map_name.is_empty() {
-                map_name_comment = format!("<!-- Map Name: {map_name} -->");
+                map_name_comment = format!("<!-- Map Name: {} -->", escape(&map_name));
             }
 
             let mut map_display_name_comment: String = String::new();
@@ -618,7 +961,8 @@ impl<P: AsRef<Path>> MapReader<P> {
                         display_name_string = romanize_string(display_name_string);
                     }
 
-                    map_display_name_comment = format!("<!-- In-game Displayed Name: {display_name_string} -->");
+                    map_display_name_comment =
+                        format!("<!-- In-game Displayed Name: {} -->", escape(&display_name_string));
                 }
             }
 
@@ -626,7 +970,7 @@ impl<P: AsRef<Path>> MapReader<P> {
 
             let order: String = String::from("<!-- Order -->");
 
-            match (self.processing_mode, self.maps_processing_mode) {
+            match (processing_mode, self.maps_processing_mode) {
                 (
                     ProcessingMode::Default | ProcessingMode::Force,
                     MapsProcessingMode::Default | MapsProcessingMode::Separate,
@@ -639,7 +983,6 @@ impl<P: AsRef<Path>> MapReader<P> {
                     }
 
                     translation_map.insert(order, order_number);
-                    translation_maps.insert(map_number, take(translation_map));
 
                     if self.maps_processing_mode == MapsProcessingMode::Separate {
                         lines_set.clear();
@@ -706,7 +1049,8 @@ impl<P: AsRef<Path>> MapReader<P> {
                         .skip(lines_pos)
                         .take(4)
                         .position(|(k, _)| k == "<!-- Map -->")
-                        .unwrap_log();
+                        .unwrap_log()
+                        + lines_pos;
                     lines_pos += 1;
 
                     process_map_comments_vec(
@@ -717,7 +1061,10 @@ impl<P: AsRef<Path>> MapReader<P> {
                         map_display_name_comment,
                     );
 
-                    translation_map_vec.insert(map_number_comment_index + 1, (order, order_number));
+                    match translation_map_vec.get_mut(map_number_comment_index + 1) {
+                        Some((original, translation)) if *original == order => *translation = order_number,
+                        _ => translation_map_vec.insert(map_number_comment_index + 1, (order, order_number)),
+                    }
                     lines_pos += 1;
                 }
             }
@@ -744,13 +1091,37 @@ impl<P: AsRef<Path>> MapReader<P> {
                     continue;
                 }
 
-                for page in event[pages_label].as_array().unwrap_log() {
+                let event_id: u64 = object_id(event, self.engine_type);
+
+                if self.event_names {
+                    if let Some(event_name) = event[event_name_label].as_str().filter(|x| !x.is_empty()) {
+                        let mut event_name: String = event_name.to_owned();
+
+                        if self.romanize {
+                            event_name = romanize_string(event_name);
+                        }
+
+                        process_event_name_comment(
+                            format!("<!-- Event Name: {} -->", escape(&event_name)),
+                            processing_mode,
+                            self.maps_processing_mode,
+                            translation_map,
+                            &mut lines_set,
+                            &mut translation_map_vec,
+                            &mut lines_pos,
+                        );
+                    }
+                }
+
+                for (page_index, page) in event[pages_label].as_array().unwrap_log().iter().enumerate() {
+                    let location: String = list_location(&format!("map{map_number}"), event_id, page_index);
+
                     parse_list(
                         page[list_label].as_array().unwrap_log(),
                         self.romanize,
                         self.game_type,
                         self.engine_type,
-                        self.processing_mode,
+                        processing_mode,
                         (code_label, parameters_label),
                         translation_map,
                         &mut lines_set,
@@ -760,18 +1131,44 @@ impl<P: AsRef<Path>> MapReader<P> {
                         ignore_entry,
                         self.trim,
                         self.sort,
+                        (keyed || self.ids).then_some((location.as_str(), &mut occurrences)),
                     );
                 }
             }
 
-            if self.logging {
-                println!("{PARSED_FILE_MSG} {filename}");
+            if let Some(blocks_writer) = &mut blocks_writer {
+                let mut block: String = String::from_iter(
+                    take(translation_map)
+                        .into_iter()
+                        .map(|(original, translation)| format!("{original}{LINES_SEPARATOR}{translation}\n")),
+                );
+                block.pop();
+
+                if let Some(tree) = &tree {
+                    block = insert_map_paths(block, tree);
+                }
+
+                blocks_writer.write_block(map_number, Some(&map_name), &block);
+            } else if !processing_mode.is_append() && self.maps_processing_mode != MapsProcessingMode::Preserve {
+                // In append mode `translation_map` already points into `translation_maps`
+                translation_maps.insert(map_number, take(translation_map));
             }
+
+            observer.file_finished(&filename, FileAction::Parsed);
+        }
+
+        if let Some(blocks_writer) = blocks_writer {
+            blocks_writer.finish();
+            return;
         }
 
         let mut output_content: String = match self.maps_processing_mode {
             MapsProcessingMode::Default | MapsProcessingMode::Separate => {
-                translation_maps.sort_unstable_keys();
+                if tree.is_some() {
+                    translation_maps.sort_by(|a, _, b, _| (tree_position(*a), a).cmp(&(tree_position(*b), b)));
+                } else {
+                    translation_maps.sort_unstable_keys();
+                }
 
                 String::from_iter(
                     translation_maps
@@ -788,7 +1185,47 @@ impl<P: AsRef<Path>> MapReader<P> {
         };
 
         output_content.pop();
-        write(txt_output_path, output_content).unwrap_log();
+
+        if self.ids {
+            output_content = apply_ids(
+                output_content,
+                &occurrences,
+                self.maps_processing_mode == MapsProcessingMode::Preserve,
+            );
+        }
+
+        if keyed {
+            output_content = apply_overrides(
+                output_content,
+                &occurrences,
+                &overrides,
+                self.maps_processing_mode == MapsProcessingMode::Separate,
+            );
+        }
+
+        if let Some(existing_translation) = &existing_translation {
+            output_content = merge_by_ids(output_content, existing_translation);
+        }
+
+        output_content = attach_comments(output_content, &mut annotations);
+
+        if let Some(memory) = self.memory.as_ref().filter(|_| !self.processing_mode.is_default()) {
+            let mut review_map: ReviewMap = parse_review(&self.output_path);
+            output_content = memory.apply(output_content, "maps", &mut review_map);
+            write_review(&review_map, &self.output_path);
+        }
+
+        if let Some(tree) = &tree {
+            output_content = insert_map_paths(output_content, tree);
+        }
+
+        output_content = with_header(output_content, Some(&header));
+
+        if let Some(full_translation) = &full_translation {
+            output_content = replace_maps(full_translation, &output_content);
+        }
+
+        write_maps(output_path, self.maps_layout, output_content);
     }
 }
 
@@ -810,6 +1247,14 @@ impl<P: AsRef<Path>> MapReader<P> {
 /// - `ignore` - Whether to ignore entries specified in `.rvpacker-ignore`
 /// - `trim` - Whether to trim whitespace from extracted strings
 /// - `sort` - Whether to sort entries on `Append` processing mode
+/// - `memory` - Optional translation memory to fill untranslated entries from
+/// - `keyed` - Whether to emit location-specific overrides for duplicated lines
+/// - `ids` - Whether to emit stable IDs of lines
+/// - `excluded_files` - Names of data files, that aren't processed
+/// - `included_files` - Names of excluded data files, that are processed anyway
+/// - `file_filter` - Filter of processed files
+/// - `threads` - Maximum number of threads, used to parse files
+/// - `observer` - Observer of progress and events
 pub struct OtherReader<P: AsRef<Path>> {
     original_path: P,
     output_path: P,
@@ -821,6 +1266,14 @@ pub struct OtherReader<P: AsRef<Path>> {
     ignore: bool,
     trim: bool,
     sort: bool,
+    memory: Option<Arc<TranslationMemory>>,
+    keyed: bool,
+    ids: bool,
+    excluded_files: Option<Vec<String>>,
+    included_files: Vec<String>,
+    file_filter: FileFilter,
+    threads: usize,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> OtherReader<P> {
@@ -845,6 +1298,14 @@ impl<P: AsRef<Path>> OtherReader<P> {
     /// - `ignore`: `false`
     /// - `trim`: `false`
     /// - `sort`: `false`
+    /// - `memory`: `None`
+    /// - `keyed`: `false`
+    /// - `ids`: `false`
+    /// - `excluded_files`: `None`
+    /// - `included_files`: empty
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `threads`: `0`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -863,6 +1324,14 @@ impl<P: AsRef<Path>> OtherReader<P> {
             ignore: false,
             trim: false,
             sort: false,
+            memory: None,
+            keyed: false,
+            ids: false,
+            excluded_files: None,
+            included_files: Vec::new(),
+            file_filter: FileFilter::default(),
+            threads: 0,
+            observer: None,
         }
     }
 
@@ -933,6 +1402,87 @@ impl<P: AsRef<Path>> OtherReader<P> {
         self
     }
 
+    /// Sets the translation memory.
+    ///
+    /// When set and `processing_mode` is `Append` or `Force`, untranslated entries are filled
+    /// with exact matches from the memory, and fuzzy matches are suggested. Both are listed
+    /// in the `.rvpacker-review` file in the output path.
+    pub fn memory(mut self, memory: Option<Arc<TranslationMemory>>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets whether to read in keyed mode.
+    ///
+    /// When enabled, every event line, that occurs in more than one location, is preceded by
+    /// `<!-- Override: location --><#>` comments, one per location. Translation of such comment
+    /// is used instead of the shared translation at that location, when writing.
+    /// Location is `file/id/page/command`, see [`list_location`].
+    ///
+    /// Only affects `CommonEvents` and `Troops`.
+    pub fn keyed(mut self, keyed: bool) -> Self {
+        self.keyed = keyed;
+        self
+    }
+
+    /// Sets whether to emit stable IDs of lines.
+    ///
+    /// When enabled, every line with a known location is preceded by `<!-- ID --><#>location`
+    /// comment, where location is the location of the first occurrence of the line, see
+    /// [`list_location`].
+    ///
+    /// Database entries get `file/id/field` IDs.
+    ///
+    /// In `Append` processing mode, the file is read from scratch and existing translation is
+    /// merged into it by IDs, falling back to originals, so translation survives reordering of
+    /// lines. Lines, which original changed under the same ID, keep their translation and are
+    /// marked for review.
+    pub fn ids(mut self, ids: bool) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets the names of data files, that aren't processed, without extension.
+    ///
+    /// If `None`, [`default_excluded_files`](crate::default_excluded_files) are used.
+    pub fn excluded_files(mut self, excluded_files: Option<Vec<String>>) -> Self {
+        self.excluded_files = excluded_files;
+        self
+    }
+
+    /// Sets the names of excluded data files, that are processed anyway, without extension.
+    ///
+    /// For example, `vec![String::from("Tilesets")]` enables processing of tileset names.
+    pub fn included_files(mut self, included_files: Vec<String>) -> Self {
+        self.included_files = included_files;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the maximum number of threads, used to parse files.
+    ///
+    /// Files are parsed in parallel, but processed in the same order, so the output doesn't depend on it.
+    /// `0` means the number of logical CPUs, `1` parses files sequentially.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// This method reads all data files from the original path (like `Items`, `Actors`,
     /// `CommonEvents`, etc.), extracts translatable text, and writes it to structured
     /// text files in the output path. The behavior is controlled by the various
@@ -954,6 +1504,8 @@ impl<P: AsRef<Path>> OtherReader<P> {
     /// ```
     #[inline(always)]
     pub fn read(self) {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
         let (
             name_label,
             nickname_label,
@@ -975,37 +1527,93 @@ impl<P: AsRef<Path>> OtherReader<P> {
             ignore_map = parse_ignore(self.output_path.as_ref().join(".rvpacker-ignore"));
         }
 
-        for (filename, path) in read_dir(&self.original_path)
+        let excluded_files: Vec<String> =
+            resolve_excluded_files(self.excluded_files.as_deref(), &self.included_files, self.game_type);
+
+        // Files are sorted, so the order of processing doesn't depend on the file system
+        let mut files: Vec<(String, PathBuf)> = read_dir(&self.original_path)
             .unwrap_log()
-            .filter_map(|entry| filter_other(entry, self.engine_type, self.game_type))
-        {
+            .filter_map(|entry| filter_other(entry, self.engine_type, &excluded_files))
+            .filter(|(filename, _)| self.file_filter.matches(filename))
+            .collect();
+        files.sort_unstable();
+        observer.files_found(files.len());
+
+        let engine_type: EngineType = self.engine_type;
+        let processing_mode: ProcessingMode = self.processing_mode;
+        let output_path: &Path = self.output_path.as_ref();
+
+        // Files, that are skipped below, aren't parsed
+        let parsed_files = par_map_ordered(files, self.threads, |filename, path| {
+            let txt_filename: String = filename.rsplit_once('.').unwrap_log().0.to_lowercase() + ".txt";
+            let txt_exists: bool = output_path.join(txt_filename).exists();
+
+            (!(processing_mode.is_default() && txt_exists || processing_mode.is_append() && !txt_exists))
+                .then(|| parse_rpgm_file(path, engine_type))
+        });
+
+        // Review file is updated once, after all files are processed
+        let mut review_map: Option<ReviewMap> = self
+            .memory
+            .as_ref()
+            .filter(|_| !processing_mode.is_default())
+            .map(|_| parse_review(output_path));
+
+        for (filename, _, obj_arr) in parsed_files {
             let basename: String = filename.rsplit_once('.').unwrap_log().0.to_owned().to_lowercase();
             let txt_filename: String = basename.clone() + ".txt";
             let txt_output_path: &Path = &self.output_path.as_ref().join(txt_filename.clone());
 
             if self.processing_mode.is_default() && txt_output_path.exists() {
-                println!("{txt_filename} {FILE_ALREADY_EXISTS_MSG}");
+                observer.file_skipped(&txt_filename, SkipReason::AlreadyExists);
                 continue;
             }
 
             let ignore_entry: Option<&IgnoreEntry> = ignore_map.get(&format!("<!-- File: {basename} -->"));
 
+            // In `Append` mode with IDs, file is read from scratch and then merged with existing translation.
+            let mut processing_mode: ProcessingMode = self.processing_mode;
+            let mut existing_translation: Option<String> = None;
+
+            if self.ids && processing_mode.is_append() && txt_output_path.exists() {
+                processing_mode = ProcessingMode::Force;
+                existing_translation = Some(read_to_string(txt_output_path).unwrap_log());
+            }
+
             let mut lines_set: IndexSetGx = IndexSet::default();
             let lines_mut_ref: &mut IndexSetGx = unsafe { &mut *(&mut lines_set as *mut IndexSetGx) };
 
             let mut translation_map: IndexMapGx = IndexMap::default();
 
-            if self.processing_mode.is_append() {
+            // Used in keyed mode.
+            let mut occurrences: Occurrences = Occurrences::default();
+            let mut overrides: HashMapGx = HashMapGx::default();
+            // Used when emitting IDs.
+            let mut field_ids: Occurrences = Occurrences::default();
+            // State and review comments of existing lines, carried over when appending.
+            let mut annotations: Attached = existing_translation
+                .as_deref()
+                .map(|translation| detach_comments(translation, is_annotation_comment).1)
+                .unwrap_or_default();
+
+            if processing_mode.is_append() {
                 if txt_output_path.exists() {
                     let translation: String = read_to_string(txt_output_path).unwrap_log();
-                    translation_map.extend(parse_translation(&translation, &txt_filename, false, self.trim));
+                    overrides = parse_overrides(&translation, false);
+                    annotations = detach_comments(&translation, is_annotation_comment).1;
+
+                    translation_map.extend(
+                        parse_translation(&translation, &txt_filename, false, self.trim, &*observer)
+                            .filter(|(original, _)| !is_meta_comment(original)),
+                    );
                 } else {
-                    println!("{FILES_ARE_NOT_PARSED_MSG}");
+                    observer.file_skipped(&txt_filename, SkipReason::NotParsed);
                     continue;
                 }
             }
 
-            let obj_arr: Value = parse_rpgm_file(&path, self.engine_type);
+            observer.file_started(&filename);
+            let obj_arr: Value = obj_arr.unwrap_log();
 
             // Other files except CommonEvents and Troops have the structure that consists
             // of name, nickname, description and note
@@ -1019,8 +1627,8 @@ impl<P: AsRef<Path>> OtherReader<P> {
                     ]);
                 }
 
-                if let Some(obj_real_arr) = obj_arr.as_array() {
-                    'obj: for obj in obj_real_arr {
+                if let Some(objects) = get_objects(&obj_arr) {
+                    'obj: for obj in objects {
                         for (variable_label, variable_type) in [
                             (name_label, Variable::Name),
                             (nickname_label, Variable::Nickname),
@@ -1078,10 +1686,11 @@ impl<P: AsRef<Path>> OtherReader<P> {
                             if let Some(parsed) = parsed {
                                 let mut replaced: String =
                                     String::from_iter(parsed.split('\n').map(
-                                        |x: &str| if variable_type.is_any_message() || self.trim { x.trim_replace() } else { x.to_owned() } + NEW_LINE,
+                                        |x: &str| if variable_type.is_any_message() || self.trim { x.trim_replace() } else { x.to_owned() } + "\n",
                                     ));
 
-                                replaced.drain(replaced.len() - 2..);
+                                replaced.pop();
+                                let replaced: String = escape(&replaced);
 
                                 if let Some(entry) = ignore_entry {
                                     if entry.contains(&replaced) {
@@ -1089,10 +1698,17 @@ impl<P: AsRef<Path>> OtherReader<P> {
                                     }
                                 }
 
+                                if self.ids {
+                                    let field: &str = variable_label.trim_start_matches("__symbol__");
+                                    let location: String =
+                                        format!("{basename}/{}/{field}", object_id(obj, self.engine_type));
+                                    field_ids.entry(replaced.clone()).or_default().push(location);
+                                }
+
                                 lines_mut_ref.insert(replaced);
                                 let string_ref: &str = unsafe { lines_mut_ref.last().unwrap_unchecked() }.as_str();
 
-                                if self.processing_mode.is_append() {
+                                if processing_mode.is_append() {
                                     if translation_map.contains_key(string_ref) {
                                         if self.sort {
                                             translation_map.swap_indices(
@@ -1126,7 +1742,7 @@ impl<P: AsRef<Path>> OtherReader<P> {
                         lines_set.insert(event_name_comment.clone());
                         let pos: usize = lines_set.len() - 1;
 
-                        if self.processing_mode.is_append() && !translation_map.contains_key(&event_name_comment) {
+                        if processing_mode.is_append() && !translation_map.contains_key(&event_name_comment) {
                             if pos <= translation_map.len() {
                                 translation_map.shift_insert(pos, event_name_comment, String::new());
                             } else {
@@ -1153,12 +1769,14 @@ impl<P: AsRef<Path>> OtherReader<P> {
                             continue;
                         }
 
+                        let location: String = list_location(&basename, object_id(obj, self.engine_type), i);
+
                         parse_list(
                             list.as_array().unwrap_log(),
                             self.romanize,
                             self.game_type,
                             self.engine_type,
-                            self.processing_mode,
+                            processing_mode,
                             (code_label, parameters_label),
                             unsafe { &mut *(&mut translation_map as *mut IndexMapGx) },
                             lines_mut_ref,
@@ -1168,12 +1786,13 @@ impl<P: AsRef<Path>> OtherReader<P> {
                             ignore_entry,
                             self.trim,
                             self.sort,
+                            (self.keyed || self.ids).then_some((location.as_str(), &mut occurrences)),
                         );
                     }
                 }
             }
 
-            let mut output_content: String = match self.processing_mode {
+            let mut output_content: String = match processing_mode {
                 ProcessingMode::Append => String::from_iter(
                     translation_map
                         .into_iter()
@@ -1188,11 +1807,44 @@ impl<P: AsRef<Path>> OtherReader<P> {
 
             output_content.pop();
 
-            write(txt_output_path, output_content).unwrap_log();
+            if self.ids {
+                output_content = apply_ids(output_content, &occurrences, false);
+                output_content = apply_ids(output_content, &field_ids, false);
+            }
 
-            if self.logging {
-                println!("{PARSED_FILE_MSG} {filename}");
+            if self.keyed {
+                output_content = apply_overrides(output_content, &occurrences, &overrides, false);
             }
+
+            if let Some(existing_translation) = &existing_translation {
+                output_content = merge_by_ids(output_content, existing_translation);
+            }
+
+            output_content = attach_comments(output_content, &mut annotations);
+
+            if let (Some(memory), Some(review_map)) = (&self.memory, &mut review_map) {
+                output_content = memory.apply(output_content, &basename, review_map);
+            }
+
+            write(
+                txt_output_path,
+                with_header(
+                    output_content,
+                    Some(&Header {
+                        engine_type: Some(self.engine_type),
+                        romanize: Some(self.romanize),
+                        trim: Some(self.trim),
+                        ..Header::default()
+                    }),
+                ),
+            )
+            .unwrap_log();
+
+            observer.file_finished(&filename, FileAction::Parsed);
+        }
+
+        if let Some(review_map) = &review_map {
+            write_review(review_map, output_path);
         }
     }
 }
@@ -1214,6 +1866,11 @@ impl<P: AsRef<Path>> OtherReader<P> {
 /// - `ignore` - Whether to ignore entries specified in `.rvpacker-ignore`
 /// - `trim` - Whether to trim whitespace from extracted strings
 /// - `sort` - Whether to sort entries on `Append` processing mode
+/// - `memory` - Optional translation memory to fill untranslated entries from
+/// - `pack` - Optional translation pack to fill untranslated default terms from
+/// - `dev_names` - Whether to read names of switches and variables into `system_dev.txt`
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct SystemReader<P: AsRef<Path>> {
     system_file_path: P,
     output_path: P,
@@ -1224,6 +1881,11 @@ pub struct SystemReader<P: AsRef<Path>> {
     ignore: bool,
     trim: bool,
     sort: bool,
+    memory: Option<Arc<TranslationMemory>>,
+    pack: Option<Arc<TranslationPack>>,
+    dev_names: bool,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> SystemReader<P> {
@@ -1247,6 +1909,11 @@ impl<P: AsRef<Path>> SystemReader<P> {
     /// - `ignore`: `false`
     /// - `trim`: `false`
     /// - `sort`: `false`
+    /// - `memory`: `None`
+    /// - `pack`: `None`
+    /// - `dev_names`: `false`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1264,6 +1931,11 @@ impl<P: AsRef<Path>> SystemReader<P> {
             ignore: false,
             trim: false,
             sort: false,
+            memory: None,
+            pack: None,
+            dev_names: false,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1318,13 +1990,143 @@ impl<P: AsRef<Path>> SystemReader<P> {
     /// Sets whether to sort translation entries.
     ///
     /// When enabled and `processing_mode` is `Append`, will
-    /// sort the translation entries by their chronological order
-    /// in-game.
+    /// sort the translation entries within their sections by
+    /// their chronological order in-game.
     pub fn sort(mut self, sort: bool) -> Self {
         self.sort = sort;
         self
     }
 
+    /// Sets the translation memory.
+    ///
+    /// When set and `processing_mode` is `Append` or `Force`, untranslated entries are filled
+    /// with exact matches from the memory, and fuzzy matches are suggested. Both are listed
+    /// in the `.rvpacker-review` file in the output path.
+    pub fn memory(mut self, memory: Option<Arc<TranslationMemory>>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets the translation pack of the default terms.
+    ///
+    /// When set, untranslated default terms are filled from the pack, and every filled line
+    /// is preceded by a `<!-- Pack: name --><#>` comment.
+    pub fn pack(mut self, pack: Option<Arc<TranslationPack>>) -> Self {
+        self.pack = pack;
+        self
+    }
+
+    /// Sets whether to read names of switches and variables.
+    ///
+    /// When enabled, names of switches and variables are written to a separate `system_dev.txt`
+    /// file, so developer-facing names aren't mixed with player-facing terms. Translation from it
+    /// is written with [`SystemWriter::dev_names`](crate::write::SystemWriter::dev_names).
+    pub fn dev_names(mut self, dev_names: bool) -> Self {
+        self.dev_names = dev_names;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Reads names of switches and variables into `system_dev.txt`.
+    ///
+    /// In `Append` processing mode, translation of names, that are still present, is kept.
+    fn read_dev_names(&self, observer: &dyn Observer) {
+        let txt_output_path: &Path = &self.output_path.as_ref().join("system_dev.txt");
+
+        if self.processing_mode.is_default() && txt_output_path.exists() {
+            observer.file_skipped("system_dev.txt", SkipReason::AlreadyExists);
+            return;
+        }
+
+        let translation_map: HashMapGx = if self.processing_mode.is_append() && txt_output_path.exists() {
+            let translation: String = read_to_string(txt_output_path).unwrap_log();
+            HashMapGx::from_iter(parse_translation(
+                &translation,
+                "system_dev.txt",
+                false,
+                self.trim,
+                observer,
+            ))
+        } else {
+            HashMapGx::default()
+        };
+
+        let (switches_label, variables_label) = get_system_dev_labels(self.engine_type);
+        let obj: Value = parse_rpgm_file(self.system_file_path.as_ref(), self.engine_type);
+
+        let mut lines_set: IndexSetGx = IndexSet::default();
+
+        for label in [switches_label, variables_label] {
+            let Some(arr) = obj[label].as_array() else {
+                continue;
+            };
+
+            for value in arr {
+                let mut buf: Vec<u8> = Vec::new();
+
+                let str: &str = value.as_str().unwrap_or_else(|| match value.as_object() {
+                    Some(obj) => {
+                        buf = get_object_data(obj);
+                        unsafe { std::str::from_utf8_unchecked(&buf) }
+                    }
+                    None => "",
+                });
+
+                let trimmed: &str = str.trim();
+
+                if trimmed.is_empty() {
+                    continue;
+                }
+
+                let mut string: String = if self.trim { trimmed } else { str }.to_owned();
+
+                if self.romanize {
+                    string = romanize_string(string);
+                }
+
+                lines_set.insert(escape(&string));
+            }
+        }
+
+        let output_content: String = lines_set
+            .into_iter()
+            .map(|line: String| {
+                let translation: &str = translation_map.get(&line).map(String::as_str).unwrap_or_default();
+                format!("{line}{LINES_SEPARATOR}{translation}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        write(
+            txt_output_path,
+            with_header(
+                output_content,
+                Some(&Header {
+                    engine_type: Some(self.engine_type),
+                    romanize: Some(self.romanize),
+                    trim: Some(self.trim),
+                    ..Header::default()
+                }),
+            ),
+        )
+        .unwrap_log();
+    }
+
     /// This method reads the `System` file, extracts translatable text (like game terms,
     /// vocabulary, and other system-level text), and writes it to a structured text file
     /// in the output path. The behavior is controlled by the various settings configured
@@ -1346,20 +2148,31 @@ impl<P: AsRef<Path>> SystemReader<P> {
     /// ```
     #[inline(always)]
     pub fn read(self) {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("System") {
+            return;
+        }
+
+        observer.files_found(1);
+
+        if self.dev_names {
+            self.read_dev_names(&*observer);
+        }
+
         let txt_output_path: &Path = &self.output_path.as_ref().join("system.txt");
 
         if self.processing_mode.is_default() && txt_output_path.exists() {
-            println!("system.txt {FILE_ALREADY_EXISTS_MSG}");
+            observer.file_skipped("system.txt", SkipReason::AlreadyExists);
             return;
         }
 
         let mut ignore_map: IgnoreMap = IndexMap::default();
 
-        let mut lines_set: IndexSetGx = IndexSet::default();
-        let lines_mut_ref: &mut IndexSetGx = unsafe { &mut *(&mut lines_set as *mut IndexSetGx) };
-        let lines_ref: &IndexSetGx = unsafe { &*(&lines_set as *const IndexSetGx) };
-
-        let mut translation_map: IndexMapGx = IndexMap::default();
+        // Existing translation, grouped by section comments. Lines of files without sections are in `""` section.
+        let mut translation_sections: IndexMap<String, IndexMapGx, GxBuildHasher> = IndexMap::default();
+        // State and review comments of existing lines, carried over when appending.
+        let mut annotations: Attached = Attached::default();
 
         if self.processing_mode.is_append() {
             if txt_output_path.exists() {
@@ -1368,16 +2181,37 @@ impl<P: AsRef<Path>> SystemReader<P> {
                 }
 
                 let translation: String = read_to_string(txt_output_path).unwrap_log();
-                translation_map.extend(parse_translation(&translation, "system.txt", false, self.trim));
+                annotations = detach_comments(&translation, is_annotation_comment).1;
+
+                let mut section: String = String::new();
+
+                for (original, translation) in
+                    parse_translation(&translation, "system.txt", false, self.trim, &*observer)
+                        .filter(|(original, _)| !is_meta_comment(original))
+                {
+                    if original.starts_with("<!--") {
+                        section = original;
+                        translation_sections.entry(section.clone()).or_default();
+                    } else {
+                        translation_sections
+                            .entry(section.clone())
+                            .or_default()
+                            .insert(original, translation);
+                    }
+                }
             } else {
-                println!("{FILES_ARE_NOT_PARSED_MSG}");
+                observer.file_skipped("system.txt", SkipReason::NotParsed);
                 return;
             }
         }
 
         let ignore_entry: Option<&IgnoreEntry> = ignore_map.get("<!-- File: system -->");
+        observer.file_started(&format!("System{}", determine_extension(self.engine_type)));
+
+        // Lines of the `System` file, grouped by section comments and deduplicated within sections.
+        let mut sections: IndexMap<String, IndexSetGx, GxBuildHasher> = IndexMap::default();
 
-        let mut parse_str = |value: &Value| {
+        let mut parse_str = |section: &str, value: &Value| {
             let mut string: String = {
                 let mut buf: Vec<u8> = Vec::new();
 
@@ -1402,27 +2236,18 @@ impl<P: AsRef<Path>> SystemReader<P> {
                 string = romanize_string(string)
             }
 
+            let string: String = escape(&string);
+
             if let Some(entry) = ignore_entry {
                 if entry.contains(&string) {
                     return;
                 }
             }
 
-            lines_mut_ref.insert(string);
-            let string_ref: &str = unsafe { lines_ref.last().unwrap_unchecked() }.as_str();
-
-            if self.processing_mode.is_append() {
-                if translation_map.contains_key(string_ref) {
-                    if self.trim {
-                        translation_map.swap_indices(
-                            translation_map.get_index_of(string_ref).unwrap(),
-                            lines_mut_ref.len() - 1,
-                        );
-                    }
-                } else {
-                    translation_map.shift_insert(lines_ref.len() - 1, string_ref.to_owned(), String::new());
-                };
-            }
+            sections
+                .entry(system_section_comment(section))
+                .or_default()
+                .insert(string);
         };
 
         let (armor_types_label, elements_label, skill_types_label, terms_label, weapon_types_label, game_title_label) =
@@ -1431,16 +2256,16 @@ impl<P: AsRef<Path>> SystemReader<P> {
         let obj: Value = parse_rpgm_file(self.system_file_path.as_ref(), self.engine_type);
 
         // Armor types and elements - mostly system strings, but may be required for some purposes
-        for label in [
-            armor_types_label,
-            elements_label,
-            skill_types_label,
-            weapon_types_label,
-            "equipTypes",
+        for (label, section) in [
+            (armor_types_label, "Armor Types"),
+            (elements_label, "Elements"),
+            (skill_types_label, "Skill Types"),
+            (weapon_types_label, "Weapon Types"),
+            ("equipTypes", "Equip Types"),
         ] {
             if let Some(arr) = obj[label].as_array() {
                 for value in arr {
-                    parse_str(value)
+                    parse_str(section, value)
                 }
             }
         }
@@ -1451,31 +2276,33 @@ impl<P: AsRef<Path>> SystemReader<P> {
                 continue;
             }
 
+            let section: String = format!("Terms: {}", key.trim_start_matches("__symbol__"));
+
             if key != "messages" {
                 if let Some(arr) = value.as_array() {
                     for value in arr {
-                        parse_str(value);
+                        parse_str(&section, value);
                     }
                 } else if (value.is_object() && value["__type"].as_str() == Some("bytes")) || value.is_str() {
-                    parse_str(value)
+                    parse_str(&section, value)
                 }
             } else {
                 if !value.is_object() {
                     continue;
                 }
 
-                for (_, value) in value.as_object().unwrap_log().iter() {
-                    parse_str(value);
+                for (message_key, value) in value.as_object().unwrap_log().iter() {
+                    parse_str(&format!("{section}.{message_key}"), value);
                 }
             }
         }
 
         if !self.engine_type.is_new() {
-            parse_str(&obj["__symbol__currency_unit"]);
+            parse_str("Currency Unit", &obj["__symbol__currency_unit"]);
         }
 
         // Game title - Translators may add something like "ELFISH TRANSLATION v1.0.0" to the title
-        {
+        let game_title_string: String = {
             let mut game_title_string: String = {
                 let mut buf: Vec<u8> = Vec::new();
 
@@ -1503,39 +2330,123 @@ impl<P: AsRef<Path>> SystemReader<P> {
                 game_title_string = romanize_string(game_title_string)
             }
 
-            lines_mut_ref.insert(game_title_string);
-            let string_ref: &str = unsafe { lines_ref.last().unwrap_unchecked() }.as_str();
+            escape(&game_title_string)
+        };
 
-            if self.processing_mode.is_append() {
-                if translation_map.contains_key(string_ref) {
-                    if self.sort {
-                        translation_map.swap_indices(
-                            translation_map.get_index_of(string_ref).unwrap(),
-                            lines_mut_ref.len() - 1,
-                        );
+        let game_title_comment: String = system_section_comment("Game Title");
+        let legacy_section: IndexMapGx = translation_sections.shift_remove("").unwrap_or_default();
+
+        let translation_of = |section: &str, original: &str| -> String {
+            translation_sections
+                .get(section)
+                .and_then(|lines| lines.get(original))
+                .or_else(|| legacy_section.get(original))
+                .cloned()
+                .unwrap_or_default()
+        };
+
+        let mut output_content: String = String::new();
+
+        // In `Append` mode, existing lines keep their order, and new lines are inserted at their positions, unless
+        // sorting is enabled. Trimmed translation is reordered as well, as it was before sections were introduced.
+        let keep_order: bool = self.processing_mode.is_append() && !self.sort && !self.trim;
+
+        for (section, lines) in &sections {
+            let _ = writeln!(output_content, "{section}{LINES_SEPARATOR}");
+
+            if let Some(existing_lines) = translation_sections.get(section).filter(|_| keep_order) {
+                let mut section_lines: IndexMapGx = existing_lines.clone();
+
+                for (index, line) in lines.iter().enumerate() {
+                    if !section_lines.contains_key(line) {
+                        section_lines.shift_insert(index.min(section_lines.len()), line.to_owned(), String::new());
                     }
-                } else {
-                    translation_map.shift_insert(lines_ref.len() - 1, string_ref.to_owned(), String::new());
+                }
+
+                for (original, translation) in section_lines {
+                    let translation: String = if translation.is_empty() {
+                        translation_of(section, &original)
+                    } else {
+                        translation
+                    };
+
+                    let _ = writeln!(output_content, "{original}{LINES_SEPARATOR}{translation}");
+                }
+
+                continue;
+            }
+
+            for line in lines {
+                let _ = writeln!(
+                    output_content,
+                    "{line}{LINES_SEPARATOR}{}",
+                    translation_of(section, line)
+                );
+            }
+
+            // Lines, which are no longer present in the `System` file, are left to the purger
+            if let Some(existing_lines) = translation_sections.get(section) {
+                for (original, translation) in existing_lines.iter().filter(|(original, _)| !lines.contains(*original))
+                {
+                    let _ = writeln!(output_content, "{original}{LINES_SEPARATOR}{translation}");
                 }
             }
         }
 
-        let mut output_content: String = match self.processing_mode {
-            ProcessingMode::Append => String::from_iter(
-                translation_map
-                    .into_iter()
-                    .map(|(original, translated)| format!("{original}{LINES_SEPARATOR}{translated}\n")),
-            ),
-            _ => String::from_iter(lines_set.into_iter().map(|line: String| line + LINES_SEPARATOR + "\n")),
-        };
+        for (section, existing_lines) in translation_sections
+            .iter()
+            .filter(|(section, _)| !sections.contains_key(*section) && **section != game_title_comment)
+        {
+            let _ = writeln!(output_content, "{section}{LINES_SEPARATOR}");
 
-        output_content.pop();
+            for (original, translation) in existing_lines {
+                let _ = writeln!(output_content, "{original}{LINES_SEPARATOR}{translation}");
+            }
+        }
 
-        write(txt_output_path, output_content).unwrap_log();
+        for (original, translation) in legacy_section.iter().filter(|(original, _)| {
+            **original != game_title_string && !sections.values().any(|lines| lines.contains(*original))
+        }) {
+            let _ = writeln!(output_content, "{original}{LINES_SEPARATOR}{translation}");
+        }
+
+        // Game title is always the last line
+        let _ = write!(
+            output_content,
+            "{game_title_comment}{LINES_SEPARATOR}\n{game_title_string}{LINES_SEPARATOR}{}",
+            translation_of(&game_title_comment, &game_title_string)
+        );
+
+        if let Some(pack) = &self.pack {
+            output_content = pack.apply(output_content);
+        }
+
+        output_content = attach_comments(output_content, &mut annotations);
 
-        if self.logging {
-            println!("{PARSED_FILE_MSG} System{}", determine_extension(self.engine_type))
+        if let Some(memory) = self.memory.as_ref().filter(|_| !self.processing_mode.is_default()) {
+            let mut review_map: ReviewMap = parse_review(&self.output_path);
+            output_content = memory.apply(output_content, "system", &mut review_map);
+            write_review(&review_map, &self.output_path);
         }
+
+        write(
+            txt_output_path,
+            with_header(
+                output_content,
+                Some(&Header {
+                    engine_type: Some(self.engine_type),
+                    romanize: Some(self.romanize),
+                    trim: Some(self.trim),
+                    ..Header::default()
+                }),
+            ),
+        )
+        .unwrap_log();
+
+        observer.file_finished(
+            &format!("System{}", determine_extension(self.engine_type)),
+            FileAction::Parsed,
+        );
     }
 }
 
@@ -1554,6 +2465,9 @@ impl<P: AsRef<Path>> SystemReader<P> {
 /// - `processing_mode` - Controls how files are processed
 /// - `ignore` - Whether to ignore entries specified in `.rvpacker-ignore`
 /// - `sort` - Whether to sort entries on `Append` processing mode
+/// - `memory` - Optional translation memory to fill untranslated entries from
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct ScriptReader<P: AsRef<Path>> {
     scripts_file_path: P,
     output_path: P,
@@ -1562,6 +2476,9 @@ pub struct ScriptReader<P: AsRef<Path>> {
     processing_mode: ProcessingMode,
     ignore: bool,
     sort: bool,
+    memory: Option<Arc<TranslationMemory>>,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> ScriptReader<P> {
@@ -1584,6 +2501,9 @@ impl<P: AsRef<Path>> ScriptReader<P> {
     /// - `processing_mode`: `ProcessingMode::Default`
     /// - `ignore`: `false`
     /// - `sort`: `false`
+    /// - `memory`: `None`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1599,6 +2519,9 @@ impl<P: AsRef<Path>> ScriptReader<P> {
             processing_mode: ProcessingMode::Default,
             ignore: false,
             sort: false,
+            memory: None,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1649,6 +2572,32 @@ impl<P: AsRef<Path>> ScriptReader<P> {
         self
     }
 
+    /// Sets the translation memory.
+    ///
+    /// When set and `processing_mode` is `Append` or `Force`, untranslated entries are filled
+    /// with exact matches from the memory, and fuzzy matches are suggested. Both are listed
+    /// in the `.rvpacker-review` file in the output path.
+    pub fn memory(mut self, memory: Option<Arc<TranslationMemory>>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// This method reads the `Scripts` file, extracts translatable text from the Ruby code,
     /// and writes it to a structured text file in the output path. The behavior is controlled
     /// by the various settings configured on the ScriptReader instance.
@@ -1668,10 +2617,18 @@ impl<P: AsRef<Path>> ScriptReader<P> {
     /// ```
     #[inline(always)]
     pub fn read(self) {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("Scripts") {
+            return;
+        }
+
+        observer.files_found(1);
+
         let txt_output_path: &Path = &self.output_path.as_ref().join("scripts.txt");
 
         if self.processing_mode.is_default() && txt_output_path.exists() {
-            println!("scripts.txt {FILE_ALREADY_EXISTS_MSG}");
+            observer.file_skipped("scripts.txt", SkipReason::AlreadyExists);
             return;
         }
 
@@ -1679,6 +2636,8 @@ impl<P: AsRef<Path>> ScriptReader<P> {
 
         let mut lines_vec: Vec<String> = Vec::new();
         let mut translation_map: Vec<(String, String)> = Vec::new();
+        // State and review comments of existing lines, carried over when appending.
+        let mut annotations: Attached = Attached::default();
 
         if self.processing_mode.is_append() {
             if txt_output_path.exists() {
@@ -1687,14 +2646,26 @@ impl<P: AsRef<Path>> ScriptReader<P> {
                 }
 
                 let translation: String = read_to_string(txt_output_path).unwrap_log();
-                translation_map.extend(parse_translation(&translation, "scripts.txt", false, false));
+                annotations = detach_comments(&translation, is_annotation_comment).1;
+                translation_map.extend(
+                    parse_translation(&translation, "scripts.txt", false, false, &*observer)
+                        .filter(|(original, _)| !is_meta_comment(original)),
+                );
             } else {
-                println!("{FILES_ARE_NOT_PARSED_MSG}");
+                observer.file_skipped("scripts.txt", SkipReason::NotParsed);
                 return;
             }
         }
 
         let ignore_entry: Option<&IgnoreEntry> = ignore_map.get("<!-- File: Scripts -->");
+        observer.file_started(
+            &self
+                .scripts_file_path
+                .as_ref()
+                .file_name()
+                .unwrap_log()
+                .to_string_lossy(),
+        );
 
         let scripts_entries: Value = load(
             &read(self.scripts_file_path.as_ref()).unwrap_log(),
@@ -1791,15 +2762,33 @@ impl<P: AsRef<Path>> ScriptReader<P> {
         };
 
         output_content.pop();
+        output_content = attach_comments(output_content, &mut annotations);
+
+        if let Some(memory) = self.memory.as_ref().filter(|_| !self.processing_mode.is_default()) {
+            let mut review_map: ReviewMap = parse_review(&self.output_path);
+            output_content = memory.apply(output_content, "scripts", &mut review_map);
+            write_review(&review_map, &self.output_path);
+        }
 
-        write(txt_output_path, output_content).unwrap_log();
+        write(
+            txt_output_path,
+            with_header(
+                output_content,
+                Some(&Header {
+                    romanize: Some(self.romanize),
+                    ..Header::default()
+                }),
+            ),
+        )
+        .unwrap_log();
 
-        if self.logging {
-            println!(
-                "{PARSED_FILE_MSG} Scripts.{}",
+        observer.file_finished(
+            &format!(
+                "Scripts.{}",
                 self.scripts_file_path.as_ref().extension().unwrap().to_str().unwrap()
-            )
-        }
+            ),
+            FileAction::Parsed,
+        );
     }
 }
 
@@ -1818,6 +2807,9 @@ impl<P: AsRef<Path>> ScriptReader<P> {
 /// - `processing_mode` - Controls how files are processed
 /// - `ignore` - Whether to ignore entries specified in `.rvpacker-ignore`
 /// - `sort` - Whether to sort entries on `Append` processing mode
+/// - `memory` - Optional translation memory to fill untranslated entries from
+/// - `file_filter` - Filter of processed files
+/// - `observer` - Observer of progress and events
 pub struct PluginReader<P: AsRef<Path>> {
     plugins_file_path: P,
     output_path: P,
@@ -1826,6 +2818,9 @@ pub struct PluginReader<P: AsRef<Path>> {
     processing_mode: ProcessingMode,
     ignore: bool,
     sort: bool,
+    memory: Option<Arc<TranslationMemory>>,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> PluginReader<P> {
@@ -1847,6 +2842,9 @@ impl<P: AsRef<Path>> PluginReader<P> {
     /// - `processing_mode`: `ProcessingMode::Default`
     /// - `ignore`: `false`
     /// - `sort`: `false`
+    /// - `memory`: `None`
+    /// - `file_filter`: `FileFilter::default()`
+    /// - `observer`: `None`
     ///
     /// # Parameters
     ///
@@ -1861,6 +2859,9 @@ impl<P: AsRef<Path>> PluginReader<P> {
             processing_mode: ProcessingMode::Default,
             ignore: false,
             sort: false,
+            memory: None,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1911,6 +2912,32 @@ impl<P: AsRef<Path>> PluginReader<P> {
         self
     }
 
+    /// Sets the translation memory.
+    ///
+    /// When set and `processing_mode` is `Append` or `Force`, untranslated entries are filled
+    /// with exact matches from the memory, and fuzzy matches are suggested. Both are listed
+    /// in the `.rvpacker-review` file in the output path.
+    pub fn memory(mut self, memory: Option<Arc<TranslationMemory>>) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
     /// This method reads the `plugins.js` file, extracts translatable text from the
     /// JavaScript plugin configurations, and writes it to a structured text file
     /// in the output path. The behavior is controlled by the various settings
@@ -1931,10 +2958,18 @@ impl<P: AsRef<Path>> PluginReader<P> {
     /// ```
     #[inline(always)]
     pub fn read(self) {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("plugins") {
+            return;
+        }
+
+        observer.files_found(1);
+
         let txt_output_path: &Path = &self.output_path.as_ref().join("plugins.txt");
 
         if self.processing_mode.is_default() && txt_output_path.exists() {
-            println!("scripts.txt {FILE_ALREADY_EXISTS_MSG}");
+            observer.file_skipped("plugins.txt", SkipReason::AlreadyExists);
             return;
         }
 
@@ -1942,6 +2977,8 @@ impl<P: AsRef<Path>> PluginReader<P> {
 
         let mut translation_map: IndexMapGx = IndexMap::default();
         let translation: String;
+        // State and review comments of existing lines, carried over when appending.
+        let mut annotations: Attached = Attached::default();
 
         if self.processing_mode.is_append() {
             if self.ignore {
@@ -1950,14 +2987,19 @@ impl<P: AsRef<Path>> PluginReader<P> {
 
             if txt_output_path.exists() {
                 translation = read_to_string(txt_output_path).unwrap_log();
-                translation_map.extend(parse_translation(&translation, "plugins.txt", false, false));
+                annotations = detach_comments(&translation, is_annotation_comment).1;
+                translation_map.extend(
+                    parse_translation(&translation, "plugins.txt", false, false, &*observer)
+                        .filter(|(original, _)| !is_meta_comment(original)),
+                );
             } else {
-                println!("{FILES_ARE_NOT_PARSED_MSG}");
+                observer.file_skipped("plugins.txt", SkipReason::NotParsed);
                 return;
             }
         }
 
         let ignore_entry: Option<&IgnoreEntry> = ignore_map.get("<!-- File: plugins -->");
+        observer.file_started("plugins.js");
 
         let plugins_content: String = read_to_string(self.plugins_file_path.as_ref()).unwrap_log();
 
@@ -1992,11 +3034,26 @@ impl<P: AsRef<Path>> PluginReader<P> {
         };
 
         output_content.pop();
+        output_content = attach_comments(output_content, &mut annotations);
 
-        write(txt_output_path, output_content).unwrap_log();
-
-        if self.logging {
-            println!("{PARSED_FILE_MSG} plugins.js")
+        if let Some(memory) = self.memory.as_ref().filter(|_| !self.processing_mode.is_default()) {
+            let mut review_map: ReviewMap = parse_review(&self.output_path);
+            output_content = memory.apply(output_content, "plugins", &mut review_map);
+            write_review(&review_map, &self.output_path);
         }
+
+        write(
+            txt_output_path,
+            with_header(
+                output_content,
+                Some(&Header {
+                    romanize: Some(self.romanize),
+                    ..Header::default()
+                }),
+            ),
+        )
+        .unwrap_log();
+
+        observer.file_finished("plugins.js", FileAction::Parsed);
     }
 }
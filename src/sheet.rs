@@ -0,0 +1,611 @@
+#[cfg(feature = "log")]
+use crate::println;
+use crate::{
+    comments::{is_meta_comment, ID_COMMENT},
+    functions::{escape, format_version, unescape},
+    header::is_header_line,
+    layout::{read_txt, txt_files, write_txt},
+    note::find_notes,
+    statics::{
+        localization::{EXPORTED_FILE_MSG, IMPORTED_FILE_MSG},
+        LINES_SEPARATOR, NEW_LINE,
+    },
+    types::{MapsLayout, ResultExt, SheetFormat},
+};
+use std::{
+    fmt::Write,
+    fs::{create_dir_all, read_to_string, write},
+    mem::take,
+    path::{Path, PathBuf},
+};
+
+const FILE_COLUMN: &str = "file";
+const CONTEXT_COLUMN: &str = "context";
+const SOURCE_COLUMN: &str = "source";
+const TARGET_COLUMN: &str = "target";
+const ID_COLUMN: &str = "id";
+const NOTES_COLUMN: &str = "notes";
+
+/// A translatable line of a `.txt` file, which is a single row of the sheet.
+struct SheetRow<'a> {
+    /// Index of the line in the `.txt` content.
+    line: usize,
+    context: String,
+    source: String,
+    target: String,
+    id: Option<&'a str>,
+    notes: Vec<String>,
+}
+
+/// Parses translatable lines of the `.txt` content.
+///
+/// Rows are regular lines and in-game displayed names of maps. Context is the map of the line
+/// along with the name of its event, if present, or the name of the event in other files. Source and target are unescaped.
+fn parse_rows(content: &str) -> Vec<SheetRow<'_>> {
+    let version: u8 = format_version(content);
+
+    let mut rows: Vec<SheetRow> = Vec::new();
+    let mut context: String = String::new();
+    let mut map_context: String = String::new();
+    let mut comments: Vec<&str> = Vec::new();
+
+    for (i, line) in content.split('\n').enumerate() {
+        if is_header_line(line) {
+            continue;
+        }
+
+        let Some((original, rest)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        let translation: &str = rest
+            .split(LINES_SEPARATOR)
+            .filter(|x| !x.is_empty())
+            .last()
+            .unwrap_or("");
+
+        if original == "<!-- Map -->" {
+            map_context = format!("Map {rest}");
+            context = map_context.clone();
+        } else if let Some(name) = original
+            .strip_prefix("<!-- Map Name: ")
+            .and_then(|x| x.strip_suffix(" -->"))
+        {
+            let _ = write!(map_context, ": {}", unescape(name, version));
+            context = map_context.clone();
+        } else if let Some(name) = original
+            .strip_prefix("<!-- Event Name: ")
+            .and_then(|x| x.strip_suffix(" -->"))
+        {
+            // Map events are named within the context of their map
+            context = if map_context.is_empty() {
+                unescape(name, version)
+            } else {
+                format!("{map_context} / {}", unescape(name, version))
+            };
+        } else if let Some(name) = original
+            .strip_prefix("<!-- In-game Displayed Name: ")
+            .and_then(|x| x.strip_suffix(" -->"))
+        {
+            rows.push(SheetRow {
+                line: i,
+                context: context.clone(),
+                source: unescape(name, version),
+                target: unescape(translation, version),
+                id: None,
+                notes: Vec::new(),
+            });
+        } else if is_meta_comment(original) {
+            comments.push(line);
+            continue;
+        } else if !original.starts_with("<!--") {
+            rows.push(SheetRow {
+                line: i,
+                context: context.clone(),
+                source: unescape(original, version),
+                target: unescape(translation, version),
+                id: comments
+                    .iter()
+                    .rev()
+                    .find_map(|x| x.strip_prefix(ID_COMMENT)?.strip_prefix(LINES_SEPARATOR)),
+                notes: find_notes(&comments),
+            });
+        }
+
+        comments.clear();
+    }
+
+    rows
+}
+
+/// Quotes the cell, if it contains the delimiter, quotes or new lines.
+///
+/// # Parameters
+///
+/// - `cell` - Content of the cell
+/// - `delimiter` - Delimiter of the cells, see [`SheetFormat::delimiter`]
+///
+/// # Returns
+///
+/// The cell, as it's written to the sheet.
+pub fn quote_cell(cell: &str, delimiter: char) -> String {
+    if cell.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
+
+/// Parses the sheet into rows of cells.
+///
+/// Quoted cells may contain delimiters, escaped quotes and new lines. Blank rows are skipped.
+///
+/// # Parameters
+///
+/// - `content` - Content of the sheet
+/// - `delimiter` - Delimiter of the cells, see [`SheetFormat::delimiter`]
+///
+/// # Returns
+///
+/// Rows of unquoted cells, including the header row.
+pub fn parse_sheet(content: &str, delimiter: char) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut cell: String = String::new();
+    let mut quoted: bool = false;
+
+    let mut chars = content.trim_start_matches('\u{FEFF}').chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if quoted {
+            match char {
+                '"' if chars.peek() == Some(&'"') => {
+                    cell.push('"');
+                    chars.next();
+                }
+                '"' => quoted = false,
+                '\r' if chars.peek() == Some(&'\n') => {}
+                _ => cell.push(char),
+            }
+        } else if char == '"' && cell.is_empty() {
+            quoted = true;
+        } else if char == delimiter {
+            row.push(take(&mut cell));
+        } else if char == '\n' || char == '\r' {
+            if char == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+            }
+
+            row.push(take(&mut cell));
+
+            if row.iter().any(|x| !x.is_empty()) {
+                rows.push(take(&mut row));
+            } else {
+                row.clear();
+            }
+        } else {
+            cell.push(char);
+        }
+    }
+
+    row.push(cell);
+
+    if row.iter().any(|x| !x.is_empty()) {
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// A struct for exporting translation to spreadsheets.
+///
+/// `SheetExporter` produces a sheet per `.txt` file, with `file`, `context`, `source` and `target`
+/// columns, and optionally `id` and `notes` columns. New lines are written as is, in quoted cells.
+/// Per-map files of the maps translation are exported to a single `maps` sheet.
+///
+/// # Fields
+///
+/// - `translation_path` - Path to the directory containing the translation files
+/// - `output_path` - Path to the directory where sheets will be written
+/// - `format` - Format of the sheets
+/// - `maps_layout` - Layout of the maps translation
+/// - `ids` - Whether to include the column with stable IDs of lines
+/// - `notes` - Whether to include the column with translator notes
+/// - `logging` - Whether to log processing information
+pub struct SheetExporter<P: AsRef<Path>> {
+    translation_path: P,
+    output_path: P,
+    format: SheetFormat,
+    maps_layout: MapsLayout,
+    ids: bool,
+    notes: bool,
+    logging: bool,
+}
+
+impl<P: AsRef<Path>> SheetExporter<P> {
+    /// Creates a new `SheetExporter` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `output_path` - Path to the directory where sheets will be written
+    pub fn new(translation_path: P, output_path: P) -> Self {
+        Self::default(translation_path, output_path)
+    }
+
+    /// Creates a new `SheetExporter` with default values.
+    ///
+    /// Default values are:
+    /// - `format`: `SheetFormat::Csv`
+    /// - `maps_layout`: `MapsLayout::Single`
+    /// - `ids`: `false`
+    /// - `notes`: `false`
+    /// - `logging`: `false`
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `output_path` - Path to the directory where sheets will be written
+    pub fn default(translation_path: P, output_path: P) -> Self {
+        Self {
+            translation_path,
+            output_path,
+            format: SheetFormat::Csv,
+            maps_layout: MapsLayout::Single,
+            ids: false,
+            notes: false,
+            logging: false,
+        }
+    }
+
+    /// Sets the format of the sheets.
+    pub fn format(mut self, format: SheetFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets whether to include the column with stable IDs of lines.
+    ///
+    /// IDs are only present, if the translation was read with IDs enabled.
+    pub fn ids(mut self, ids: bool) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets whether to include the column with translator notes.
+    ///
+    /// Multiple notes of a line are separated with new lines.
+    pub fn notes(mut self, notes: bool) -> Self {
+        self.notes = notes;
+        self
+    }
+
+    /// Sets whether to log processing information.
+    pub fn logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// This method writes a sheet for every `.txt` file in the translation directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{sheet::SheetExporter, types::SheetFormat};
+    ///
+    /// SheetExporter::new("translation", "sheets")
+    ///     .format(SheetFormat::Tsv)
+    ///     .notes(true)
+    ///     .export();
+    /// ```
+    pub fn export(self) {
+        let delimiter: char = self.format.delimiter();
+        create_dir_all(self.output_path.as_ref()).unwrap_log();
+
+        for filename in txt_files(self.translation_path.as_ref(), self.maps_layout) {
+            let content: String = read_txt(self.translation_path.as_ref(), &filename, self.maps_layout);
+
+            let mut header: Vec<&str> = vec![FILE_COLUMN, CONTEXT_COLUMN, SOURCE_COLUMN, TARGET_COLUMN];
+
+            if self.ids {
+                header.push(ID_COLUMN);
+            }
+
+            if self.notes {
+                header.push(NOTES_COLUMN);
+            }
+
+            let mut output: String = header.join(&delimiter.to_string());
+            output.push('\n');
+
+            for row in parse_rows(&content) {
+                let mut cells: Vec<String> = vec![
+                    quote_cell(&filename, delimiter),
+                    quote_cell(&row.context, delimiter),
+                    quote_cell(&row.source, delimiter),
+                    quote_cell(&row.target, delimiter),
+                ];
+
+                if self.ids {
+                    cells.push(quote_cell(row.id.unwrap_or_default(), delimiter));
+                }
+
+                if self.notes {
+                    cells.push(quote_cell(&row.notes.join("\n"), delimiter));
+                }
+
+                output.push_str(&cells.join(&delimiter.to_string()));
+                output.push('\n');
+            }
+
+            let sheet_filename: String = format!("{}.{}", filename.trim_end_matches(".txt"), self.format.extension());
+            write(self.output_path.as_ref().join(&sheet_filename), output).unwrap_log();
+
+            if self.logging {
+                println!("{EXPORTED_FILE_MSG} {sheet_filename}");
+            }
+        }
+    }
+}
+
+/// A sheet, which row count doesn't match the count of translatable lines of its `.txt` file.
+///
+/// `expected` is the count of translatable lines, and `actual` is the count of rows, excluding the header.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RowCountMismatch {
+    pub file: String,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+/// A row, which source doesn't match the source of the corresponding line of the `.txt` file.
+///
+/// `row` is the 1-based row number inside the sheet, including the header.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SourceConflict {
+    pub file: String,
+    pub row: usize,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Result of the import.
+///
+/// # Fields
+///
+/// - `imported` - `.txt` files, which translation was merged
+/// - `invalid` - `.txt` files, which sheets have no `source` or `target` column
+/// - `mismatched` - Sheets, skipped because of the row count mismatch
+/// - `conflicts` - Rows, skipped because of the source mismatch
+#[derive(Debug, Default, Clone)]
+pub struct ImportReport {
+    pub imported: Vec<String>,
+    pub invalid: Vec<String>,
+    pub mismatched: Vec<RowCountMismatch>,
+    pub conflicts: Vec<SourceConflict>,
+}
+
+impl ImportReport {
+    /// Returns whether every sheet was imported without issues.
+    pub fn is_clean(&self) -> bool {
+        self.invalid.is_empty() && self.mismatched.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+/// A struct for importing translation from spreadsheets.
+///
+/// `SheetImporter` reads sheets, produced by [`SheetExporter`], and merges their `target` column
+/// into `.txt` files in place. Every other line of `.txt` files, including comments, stays as is.
+/// Sheets are matched with `.txt` files by name, and rows are matched with lines by order,
+/// so rows must not be reordered, added or removed.
+///
+/// # Fields
+///
+/// - `translation_path` - Path to the directory containing the translation files
+/// - `input_path` - Path to the directory containing the sheets
+/// - `format` - Format of the sheets
+/// - `maps_layout` - Layout of the maps translation
+/// - `logging` - Whether to log processing information
+/// - `dry_run` - Whether to only validate sheets, without modifying files
+pub struct SheetImporter<P: AsRef<Path>> {
+    translation_path: P,
+    input_path: P,
+    format: SheetFormat,
+    maps_layout: MapsLayout,
+    logging: bool,
+    dry_run: bool,
+}
+
+impl<P: AsRef<Path>> SheetImporter<P> {
+    /// Creates a new `SheetImporter` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `input_path` - Path to the directory containing the sheets
+    pub fn new(translation_path: P, input_path: P) -> Self {
+        Self::default(translation_path, input_path)
+    }
+
+    /// Creates a new `SheetImporter` with default values.
+    ///
+    /// Default values are:
+    /// - `format`: `SheetFormat::Csv`
+    /// - `maps_layout`: `MapsLayout::Single`
+    /// - `logging`: `false`
+    /// - `dry_run`: `false`
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `input_path` - Path to the directory containing the sheets
+    pub fn default(translation_path: P, input_path: P) -> Self {
+        Self {
+            translation_path,
+            input_path,
+            format: SheetFormat::Csv,
+            maps_layout: MapsLayout::Single,
+            logging: false,
+            dry_run: false,
+        }
+    }
+
+    /// Sets the format of the sheets.
+    pub fn format(mut self, format: SheetFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// Must be the same value, as in previous read. The `maps` sheet is merged into per-map files.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets whether to log processing information.
+    pub fn logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// Sets whether to only validate sheets, without modifying files.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// This method merges translation from the sheets into the `.txt` files.
+    ///
+    /// Sheets with the row count, that doesn't match the count of translatable lines, are skipped
+    /// entirely. Rows with the source, that doesn't match the source of the line, are skipped.
+    ///
+    /// # Returns
+    ///
+    /// An [`ImportReport`] with the imported files and the found issues.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{sheet::SheetImporter, types::SheetFormat};
+    ///
+    /// let report = SheetImporter::new("translation", "sheets")
+    ///     .format(SheetFormat::Tsv)
+    ///     .import();
+    ///
+    /// for mismatch in &report.mismatched {
+    ///     println!("{}: expected {} rows, got {}", mismatch.file, mismatch.expected, mismatch.actual);
+    /// }
+    /// ```
+    pub fn import(self) -> ImportReport {
+        let mut report: ImportReport = ImportReport::default();
+
+        for filename in txt_files(self.translation_path.as_ref(), self.maps_layout) {
+            let sheet_path: PathBuf = self.input_path.as_ref().join(format!(
+                "{}.{}",
+                filename.trim_end_matches(".txt"),
+                self.format.extension()
+            ));
+
+            let Ok(sheet) = read_to_string(&sheet_path) else {
+                continue;
+            };
+
+            let mut sheet_rows = parse_sheet(&sheet, self.format.delimiter()).into_iter();
+            let header: Vec<String> = sheet_rows.next().unwrap_or_default();
+            let sheet_rows: Vec<Vec<String>> = sheet_rows.collect();
+
+            let (Some(source_column), Some(target_column)) = (
+                header.iter().position(|x| x.trim() == SOURCE_COLUMN),
+                header.iter().position(|x| x.trim() == TARGET_COLUMN),
+            ) else {
+                report.invalid.push(filename);
+                continue;
+            };
+
+            let content: String = read_txt(self.translation_path.as_ref(), &filename, self.maps_layout);
+            let version: u8 = format_version(&content);
+            let rows: Vec<SheetRow> = parse_rows(&content);
+
+            if rows.len() != sheet_rows.len() {
+                report.mismatched.push(RowCountMismatch {
+                    file: filename,
+                    expected: rows.len(),
+                    actual: sheet_rows.len(),
+                });
+                continue;
+            }
+
+            let mut lines: Vec<String> = content.split('\n').map(str::to_owned).collect();
+
+            for (i, (row, cells)) in rows.iter().zip(&sheet_rows).enumerate() {
+                let cell = |column: usize| cells.get(column).map_or(String::new(), |x| x.replace("\r\n", "\n"));
+                let source: String = cell(source_column);
+
+                if source != row.source {
+                    report.conflicts.push(SourceConflict {
+                        file: filename.clone(),
+                        row: i + 2,
+                        expected: row.source.clone(),
+                        actual: source,
+                    });
+                    continue;
+                }
+
+                let target: String = cell(target_column);
+
+                if target == row.target {
+                    continue;
+                }
+
+                let target: String = if version < 2 {
+                    target.replace('\n', NEW_LINE)
+                } else {
+                    escape(&target)
+                };
+
+                // Only the last non-empty column is the translation, other columns are kept
+                let mut columns: Vec<String> = lines[row.line].split(LINES_SEPARATOR).map(str::to_owned).collect();
+
+                if target.is_empty() {
+                    columns[1..].iter_mut().for_each(String::clear);
+                } else {
+                    let column: usize = columns
+                        .iter()
+                        .rposition(|x| !x.is_empty())
+                        .filter(|&x| x > 0)
+                        .unwrap_or(1);
+                    columns[column] = target;
+                }
+
+                lines[row.line] = columns.join(LINES_SEPARATOR);
+            }
+
+            if !self.dry_run {
+                write_txt(
+                    self.translation_path.as_ref(),
+                    &filename,
+                    self.maps_layout,
+                    lines.join("\n"),
+                );
+            }
+
+            if self.logging {
+                println!("{IMPORTED_FILE_MSG} {filename}");
+            }
+
+            report.imported.push(filename);
+        }
+
+        report
+    }
+}
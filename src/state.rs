@@ -0,0 +1,116 @@
+use crate::{
+    comments::{map_lines, OVERRIDE_COMMENT_PREFIX, STATE_COMMENT},
+    functions::parse_map_number,
+    statics::LINES_SEPARATOR,
+    types::{LineState, PurgeReason, PurgedLine},
+};
+use std::fmt::Write;
+
+/// Returns the `<!-- State --><#>state` comment line for the state.
+#[inline]
+pub fn state_comment(state: LineState) -> String {
+    format!("{STATE_COMMENT}{LINES_SEPARATOR}{}", state.as_str())
+}
+
+/// Returns the state from the comment lines, attached to a line.
+///
+/// If there're multiple state comments, the last one is used.
+#[inline]
+fn find_state(comments: &[&str]) -> Option<LineState> {
+    comments
+        .iter()
+        .rev()
+        .find_map(|line| line.strip_prefix(STATE_COMMENT)?.strip_prefix(LINES_SEPARATOR))
+        .and_then(LineState::parse)
+}
+
+/// Parses review states from the `.txt` content.
+///
+/// # Parameters
+///
+/// - `translation` - Content of the `.txt` file
+///
+/// # Returns
+///
+/// Original, translation and state of every line, that has a state, in order of appearance.
+pub fn parse_states(translation: &str) -> Vec<(String, String, LineState)> {
+    let mut states: Vec<(String, String, LineState)> = Vec::new();
+
+    map_lines(translation, |comments, line, _| {
+        if let (Some(state), Some((original, translation))) = (find_state(comments), line.split_once(LINES_SEPARATOR)) {
+            states.push((original.to_owned(), translation.to_owned(), state));
+        }
+    });
+
+    states
+}
+
+/// Clears translation of every line with one of the `states`, including its override comments,
+/// so the line is written untranslated.
+///
+/// If `skip_stateless` is `true`, lines without a state are cleared too.
+pub(crate) fn skip_states(content: String, states: &[LineState], skip_stateless: bool) -> String {
+    if states.is_empty() && !skip_stateless {
+        return content;
+    }
+
+    map_lines(&content, |comments, line, output| {
+        let skip: bool = find_state(comments).map_or(skip_stateless, |state| states.contains(&state));
+
+        for comment in comments.iter().chain(std::iter::once(&line)) {
+            match comment.split_once(LINES_SEPARATOR) {
+                Some((original, _))
+                    if skip && (original.starts_with(OVERRIDE_COMMENT_PREFIX) || !original.starts_with("<!--")) =>
+                {
+                    let _ = writeln!(output, "{original}{LINES_SEPARATOR}");
+                }
+                _ => {
+                    output.push_str(comment);
+                    output.push('\n');
+                }
+            }
+        }
+    })
+}
+
+/// Removes every line with one of the `states` along with its attached comments.
+pub(crate) fn purge_states(content: String, states: &[LineState], file: &str, purged: &mut Vec<PurgedLine>) -> String {
+    if states.is_empty() {
+        return content;
+    }
+
+    // Maps of the lines, passed to `map_lines`, in the same order
+    let mut map: Option<u16> = None;
+    let mut line_maps = content.split('\n').filter_map(|line| {
+        let (original, translation) = line.split_once(LINES_SEPARATOR).unwrap_or((line, ""));
+
+        if original == "<!-- Map -->" {
+            map = Some(parse_map_number(translation));
+            None
+        } else {
+            (!original.starts_with("<!--")).then_some(map)
+        }
+    });
+
+    map_lines(&content, |comments, line, output| {
+        let map: Option<u16> = line_maps.next().flatten();
+
+        if find_state(comments).is_some_and(|state| states.contains(&state)) {
+            let (original, translation) = line.split_once(LINES_SEPARATOR).unwrap_or((line, ""));
+
+            purged.push(PurgedLine {
+                file: file.to_owned(),
+                map,
+                original: original.to_owned(),
+                translation: translation.to_owned(),
+                reason: PurgeReason::State,
+            });
+            return;
+        }
+
+        for comment in comments.iter().chain(std::iter::once(&line)) {
+            output.push_str(comment);
+            output.push('\n');
+        }
+    })
+}
@@ -19,6 +19,27 @@ pub mod localization {
         "Couldn't split line to original and translated part.\nThe line won't be written to the output file.";
     pub const AT_POSITION_MSG: &str = "At position:";
     pub const IN_FILE_MSG: &str = "In file:";
+
+    pub const HEADER_MISMATCH_MSG: &str =
+        "Settings don't match the header of the translation file. Skipping processing.\nMismatched settings:";
+    pub const STREAMING_UNSUPPORTED_MSG: &str =
+        "Streaming isn't supported with these settings. Processing maps as usual.\nUnsupported settings:";
+
+    // language messages
+    pub const ORIGINALS_MISMATCH_MSG: &str = "Original lines don't match the ones of language";
+
+    // sheet messages
+    pub const EXPORTED_FILE_MSG: &str = "Exported file";
+    pub const IMPORTED_FILE_MSG: &str = "Imported file";
+
+    // verify messages
+    pub const VERIFIED_FILE_MSG: &str = "Verified file";
+
+    // migrate messages
+    pub const MIGRATED_FILE_MSG: &str = "Migrated file";
+
+    // layout messages
+    pub const CONVERTED_FILE_MSG: &str = "Converted layout of file";
 }
 
 pub mod regexes {
@@ -53,6 +74,20 @@ pub mod regexes {
 pub const NEW_LINE: &str = r"\#";
 pub const LINES_SEPARATOR: &str = "<#>";
 
+/// Current version of the `.txt` translation format.
+///
+/// Version `1` only replaces new lines with `\#`. Version `2` additionally escapes `<#` and `\#`
+/// sequences of the source text, so they can't be confused with the separator and new lines.
+pub const FORMAT_VERSION: u8 = 2;
+/// Comment, which holds the version of the translation format. It's the first line of `.txt` files.
+///
+/// The full comment is `<!-- Format --><#>version`. Files without it are treated as version `1`.
+pub const FORMAT_COMMENT: &str = "<!-- Format -->";
+/// Escaped `<#` sequence of the source text.
+pub const ESCAPED_SEPARATOR: &str = "<#lt#>";
+/// Escaped `\#` sequence of the source text.
+pub const ESCAPED_NEW_LINE: &str = "<#bs#>";
+
 pub const SYMBOLS: phf::Set<char> = phf_set! {
     ',', '.', '(', ')', '+', '-', ':', ';', '[', ']', '^', '~', '%', '&', '!', '№', '$', '@', '`', '*', '/', '→', '×', '？', '?', 'ｘ', '％', '▼', '|', '♥', '♪', '！', '：', '〜', '『', '』', '「', '」', '〽', '。', '…', '‥', '＝', '゠', '、', '，', '【', '】', '［', '］', '｛', '｝', '（', '）', '〔', '〕', '｟', '｠', '〘', '〙', '〈', '〉', '《', '》', '・', '\\', '#', '<', '>', '=', '_', 'ー', '※', '▶', 'Ⅰ', 'ⅰ', 'Ⅱ', 'ⅱ', 'Ⅲ', 'ⅲ', 'Ⅳ', 'ⅳ', 'Ⅴ', 'ⅴ', 'Ⅵ', 'ⅵ', 'Ⅶ', 'ⅶ', 'Ⅷ', 'ⅷ', 'Ⅸ', 'ⅸ', 'Ⅹ', 'ⅹ', 'Ⅺ', 'ⅺ', 'Ⅻ', 'ⅻ', 'Ⅼ', 'ⅼ', 'Ⅽ', 'ⅽ', 'Ⅾ', 'ⅾ', 'Ⅿ', 'ⅿ', ' ', '\t', '\r', '\n'
 };
@@ -0,0 +1,756 @@
+use crate::{
+    comments::STATE_COMMENT,
+    determine_extension,
+    functions::{format_version, parse_map_number, unescape},
+    language::find_languages,
+    layout::{maps_exist, read_txt, txt_files},
+    purge::{MapPurger, OtherPurger, PluginPurger, ScriptPurger, SystemPurger},
+    statics::LINES_SEPARATOR,
+    types::{EngineType, GameType, LineState, MapsLayout, MapsProcessingMode, PurgedLine, ResultExt},
+};
+use gxhash::GxBuildHasher;
+use indexmap::IndexMap;
+use sonic_rs::{from_str, json, prelude::*, to_string_pretty, Value};
+use std::{
+    collections::HashSet,
+    fmt::Write,
+    fs::{read_to_string, write},
+    path::{Path, PathBuf},
+};
+
+/// Name of the file, where the stats of the previous run are stored.
+pub const STATS_FILE_NAME: &str = ".rvpacker-stats.json";
+
+/// Statistics of a single translation file, a single map, or a whole project.
+///
+/// # Fields
+///
+/// - `total` - Total count of translatable lines
+/// - `translated` - Count of lines with non-empty translation
+/// - `unused` - Count of lines, that are not present in the original data anymore
+/// - `duplicates` - Count of lines, which source text was already encountered before
+/// - `source_chars` - Character count of the source text
+/// - `source_words` - Word count of the source text
+/// - `target_chars` - Character count of the translation
+/// - `target_words` - Word count of the translation
+/// - `machine_translated` - Count of lines in `machine` review state
+/// - `fuzzy` - Count of lines in `fuzzy` review state
+/// - `needs_review` - Count of lines in `needs-review` review state
+/// - `approved` - Count of lines in `approved` review state
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct FileStats {
+    pub total: usize,
+    pub translated: usize,
+    pub unused: usize,
+    pub duplicates: usize,
+    pub source_chars: usize,
+    pub source_words: usize,
+    pub target_chars: usize,
+    pub target_words: usize,
+    pub machine_translated: usize,
+    pub fuzzy: usize,
+    pub needs_review: usize,
+    pub approved: usize,
+}
+
+/// Difference between two [`FileStats`].
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct FileStatsDelta {
+    pub total: i64,
+    pub translated: i64,
+    pub unused: i64,
+    pub duplicates: i64,
+    pub source_chars: i64,
+    pub source_words: i64,
+    pub target_chars: i64,
+    pub target_words: i64,
+    pub machine_translated: i64,
+    pub fuzzy: i64,
+    pub needs_review: i64,
+    pub approved: i64,
+}
+
+impl FileStats {
+    /// Returns translation progress in percents.
+    pub fn progress(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.translated as f64 / self.total as f64 * 100.0
+        }
+    }
+
+    /// Returns the difference between these stats and `previous` stats.
+    pub fn delta(&self, previous: &FileStats) -> FileStatsDelta {
+        let diff = |current: usize, previous: usize| current as i64 - previous as i64;
+
+        FileStatsDelta {
+            total: diff(self.total, previous.total),
+            translated: diff(self.translated, previous.translated),
+            unused: diff(self.unused, previous.unused),
+            duplicates: diff(self.duplicates, previous.duplicates),
+            source_chars: diff(self.source_chars, previous.source_chars),
+            source_words: diff(self.source_words, previous.source_words),
+            target_chars: diff(self.target_chars, previous.target_chars),
+            target_words: diff(self.target_words, previous.target_words),
+            machine_translated: diff(self.machine_translated, previous.machine_translated),
+            fuzzy: diff(self.fuzzy, previous.fuzzy),
+            needs_review: diff(self.needs_review, previous.needs_review),
+            approved: diff(self.approved, previous.approved),
+        }
+    }
+
+    fn add(&mut self, other: &FileStats) {
+        self.total += other.total;
+        self.translated += other.translated;
+        self.unused += other.unused;
+        self.duplicates += other.duplicates;
+        self.source_chars += other.source_chars;
+        self.source_words += other.source_words;
+        self.target_chars += other.target_chars;
+        self.target_words += other.target_words;
+        self.machine_translated += other.machine_translated;
+        self.fuzzy += other.fuzzy;
+        self.needs_review += other.needs_review;
+        self.approved += other.approved;
+    }
+
+    fn add_line(&mut self, original: &str, translation: &str) {
+        self.total += 1;
+        self.source_chars += original.chars().count();
+        self.source_words += original.split_whitespace().count();
+
+        if !translation.is_empty() {
+            self.translated += 1;
+            self.target_chars += translation.chars().count();
+            self.target_words += translation.split_whitespace().count();
+        }
+    }
+
+    fn add_state(&mut self, state: LineState) {
+        match state {
+            LineState::MachineTranslated => self.machine_translated += 1,
+            LineState::Fuzzy => self.fuzzy += 1,
+            LineState::NeedsReview => self.needs_review += 1,
+            LineState::Approved => self.approved += 1,
+        }
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "total": self.total,
+            "translated": self.translated,
+            "unused": self.unused,
+            "duplicates": self.duplicates,
+            "sourceChars": self.source_chars,
+            "sourceWords": self.source_words,
+            "targetChars": self.target_chars,
+            "targetWords": self.target_words,
+            "machineTranslated": self.machine_translated,
+            "fuzzy": self.fuzzy,
+            "needsReview": self.needs_review,
+            "approved": self.approved,
+        })
+    }
+
+    fn from_json(value: &Value) -> FileStats {
+        let get = |key: &str| value[key].as_u64().unwrap_or_default() as usize;
+
+        FileStats {
+            total: get("total"),
+            translated: get("translated"),
+            unused: get("unused"),
+            duplicates: get("duplicates"),
+            source_chars: get("sourceChars"),
+            source_words: get("sourceWords"),
+            target_chars: get("targetChars"),
+            target_words: get("targetWords"),
+            machine_translated: get("machineTranslated"),
+            fuzzy: get("fuzzy"),
+            needs_review: get("needsReview"),
+            approved: get("approved"),
+        }
+    }
+}
+
+impl FileStatsDelta {
+    fn to_json(self) -> Value {
+        json!({
+            "total": self.total,
+            "translated": self.translated,
+            "unused": self.unused,
+            "duplicates": self.duplicates,
+            "sourceChars": self.source_chars,
+            "sourceWords": self.source_words,
+            "targetChars": self.target_chars,
+            "targetWords": self.target_words,
+            "machineTranslated": self.machine_translated,
+            "fuzzy": self.fuzzy,
+            "needsReview": self.needs_review,
+            "approved": self.approved,
+        })
+    }
+}
+
+/// Statistics of a translation project.
+///
+/// # Fields
+///
+/// - `total` - Stats of the whole project. Its `duplicates` count lines duplicated across all files
+/// - `files` - Stats of each `.txt` file
+/// - `maps` - Stats of each map block of the `maps.txt` file
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Stats {
+    pub total: FileStats,
+    pub files: IndexMap<String, FileStats, GxBuildHasher>,
+    pub maps: IndexMap<u16, FileStats, GxBuildHasher>,
+}
+
+impl Stats {
+    /// Serializes stats to JSON.
+    ///
+    /// # Parameters
+    ///
+    /// - `previous` - Stats of the previous run. If passed, deltas are included in the output
+    pub fn to_json(&self, previous: Option<&Stats>) -> String {
+        let mut files: Value = json!({});
+        let mut maps: Value = json!({});
+
+        for (file, stats) in &self.files {
+            let mut value: Value = stats.to_json();
+
+            if let Some(previous_stats) = previous.and_then(|x| x.files.get(file)) {
+                value["delta"] = stats.delta(previous_stats).to_json();
+            }
+
+            files[file.as_str()] = value;
+        }
+
+        for (map_number, stats) in &self.maps {
+            let mut value: Value = stats.to_json();
+
+            if let Some(previous_stats) = previous.and_then(|x| x.maps.get(map_number)) {
+                value["delta"] = stats.delta(previous_stats).to_json();
+            }
+
+            maps[map_number.to_string().as_str()] = value;
+        }
+
+        let mut total: Value = self.total.to_json();
+
+        if let Some(previous) = previous {
+            total["delta"] = self.total.delta(&previous.total).to_json();
+        }
+
+        to_string_pretty(&json!({
+            "total": total,
+            "files": files,
+            "maps": maps,
+        }))
+        .unwrap_log()
+    }
+
+    /// Deserializes stats from JSON, produced by [`Stats::to_json`].
+    ///
+    /// Returns `None`, if JSON is malformed.
+    pub fn from_json(json: &str) -> Option<Stats> {
+        let value: Value = from_str(json).ok()?;
+
+        let mut stats: Stats = Stats {
+            total: FileStats::from_json(value.get("total")?),
+            ..Default::default()
+        };
+
+        for (file, file_stats) in value.get("files")?.as_object()?.iter() {
+            stats.files.insert(file.to_owned(), FileStats::from_json(file_stats));
+        }
+
+        for (map_number, map_stats) in value.get("maps")?.as_object()?.iter() {
+            stats
+                .maps
+                .insert(map_number.parse().ok()?, FileStats::from_json(map_stats));
+        }
+
+        Some(stats)
+    }
+
+    /// Returns a human-readable summary of the stats.
+    ///
+    /// # Parameters
+    ///
+    /// - `previous` - Stats of the previous run. If passed, deltas are included in the output
+    pub fn summary(&self, previous: Option<&Stats>) -> String {
+        let mut output: String = String::new();
+
+        let mut write_line = |name: &str, stats: &FileStats, previous_stats: Option<&FileStats>| {
+            let _ = write!(
+                output,
+                "{name}: {}/{} ({:.1}%), source: {} chars/{} words, translation: {} chars/{} words, duplicates: {}, \
+                 unused: {}",
+                stats.translated,
+                stats.total,
+                stats.progress(),
+                stats.source_chars,
+                stats.source_words,
+                stats.target_chars,
+                stats.target_words,
+                stats.duplicates,
+                stats.unused,
+            );
+
+            if stats.machine_translated + stats.fuzzy + stats.needs_review + stats.approved > 0 {
+                let _ = write!(
+                    output,
+                    ", machine: {}, fuzzy: {}, needs review: {}, approved: {}",
+                    stats.machine_translated, stats.fuzzy, stats.needs_review, stats.approved,
+                );
+            }
+
+            if let Some(previous_stats) = previous_stats {
+                let delta: FileStatsDelta = stats.delta(previous_stats);
+                let _ = write!(output, " [{:+} translated, {:+} total]", delta.translated, delta.total);
+            }
+
+            output.push('\n');
+        };
+
+        write_line("Total", &self.total, previous.map(|x| &x.total));
+
+        for (file, stats) in &self.files {
+            write_line(file, stats, previous.and_then(|x| x.files.get(file)));
+        }
+
+        for (map_number, stats) in &self.maps {
+            write_line(
+                &format!("Map {map_number}"),
+                stats,
+                previous.and_then(|x| x.maps.get(map_number)),
+            );
+        }
+
+        output.pop();
+        output
+    }
+}
+
+/// Statistics of every language of a multi-language translation project.
+///
+/// # Fields
+///
+/// - `languages` - Stats of each language
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct LanguageStats {
+    pub languages: IndexMap<String, Stats, GxBuildHasher>,
+}
+
+impl LanguageStats {
+    /// Returns a human-readable comparison of translation coverage across languages.
+    ///
+    /// Every line holds the progress of each language for the whole project or a single file.
+    /// Files, that are missing in a language, are marked with `-`.
+    pub fn summary(&self) -> String {
+        let mut files: Vec<&String> = Vec::new();
+
+        for stats in self.languages.values() {
+            for file in stats.files.keys() {
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+        }
+
+        let mut output: String = String::new();
+
+        let mut write_line = |name: &str, get: &dyn Fn(&Stats) -> Option<&FileStats>| {
+            output.push_str(name);
+            output.push(':');
+
+            for (i, (language, stats)) in self.languages.iter().enumerate() {
+                let separator: &str = if i == 0 { " " } else { ", " };
+
+                let _ = match get(stats) {
+                    Some(file_stats) => write!(
+                        output,
+                        "{separator}{language} {}/{} ({:.1}%)",
+                        file_stats.translated,
+                        file_stats.total,
+                        file_stats.progress()
+                    ),
+                    None => write!(output, "{separator}{language} -"),
+                };
+            }
+
+            output.push('\n');
+        };
+
+        write_line("Total", &|stats| Some(&stats.total));
+
+        for file in files {
+            write_line(file, &|stats| stats.files.get(file));
+        }
+
+        output.pop();
+        output
+    }
+}
+
+/// Reads stats of the previous run from the `.rvpacker-stats.json` file.
+///
+/// # Parameters
+///
+/// - `translation_path` - The directory where the `.rvpacker-stats.json` file is located
+#[inline]
+pub fn read_stats<P: AsRef<Path>>(translation_path: P) -> Option<Stats> {
+    Stats::from_json(&read_to_string(translation_path.as_ref().join(STATS_FILE_NAME)).ok()?)
+}
+
+/// Writes stats to the `.rvpacker-stats.json` file, so they can be used to compute deltas on the next run.
+///
+/// # Parameters
+///
+/// - `stats` - Stats to write
+/// - `translation_path` - The directory where the `.rvpacker-stats.json` file will be written
+#[inline]
+pub fn write_stats<P: AsRef<Path>>(stats: &Stats, translation_path: P) {
+    write(translation_path.as_ref().join(STATS_FILE_NAME), stats.to_json(None)).unwrap_log();
+}
+
+/// A struct for computing translation statistics.
+///
+/// `StatsCollector` computes statistics from the `.txt` files in the translation directory.
+/// If the path to the original data is set, lines that aren't present in the original data
+/// anymore are counted as well.
+///
+/// # Fields
+///
+/// - `translation_path` - Path to the directory containing the translation files
+/// - `original_path` - Optional path to the directory containing the original data files
+/// - `plugins_file_path` - Optional path to the `plugins.js` file
+/// - `maps_processing_mode` - Controls how maps are processed
+/// - `maps_layout` - Layout of the maps translation
+/// - `romanize` - Whether to romanize non-Latin text
+/// - `game_type` - Optional specific game type for specialized processing
+/// - `engine_type` - The RPG Maker engine type
+/// - `trim` - Whether to trim whitespace from strings
+pub struct StatsCollector<P: AsRef<Path>> {
+    translation_path: P,
+    original_path: Option<P>,
+    plugins_file_path: Option<P>,
+    maps_processing_mode: MapsProcessingMode,
+    maps_layout: MapsLayout,
+    romanize: bool,
+    game_type: Option<GameType>,
+    engine_type: EngineType,
+    trim: bool,
+}
+
+impl<P: AsRef<Path>> StatsCollector<P> {
+    /// Creates a new `StatsCollector` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn new(translation_path: P, engine_type: EngineType) -> Self {
+        Self::default(translation_path, engine_type)
+    }
+
+    /// Creates a new `StatsCollector` with default values.
+    ///
+    /// Default values are:
+    /// - `original_path`: `None`
+    /// - `plugins_file_path`: `None`
+    /// - `maps_processing_mode`: `MapsProcessingMode::Default`
+    /// - `maps_layout`: `MapsLayout::Single`
+    /// - `romanize`: `false`
+    /// - `game_type`: `None`
+    /// - `trim`: `false`
+    ///
+    /// # Parameters
+    ///
+    /// - `translation_path` - Path to the directory containing the translation files
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn default(translation_path: P, engine_type: EngineType) -> Self {
+        Self {
+            translation_path,
+            original_path: None,
+            plugins_file_path: None,
+            maps_processing_mode: MapsProcessingMode::Default,
+            maps_layout: MapsLayout::Single,
+            romanize: false,
+            game_type: None,
+            engine_type,
+            trim: false,
+        }
+    }
+
+    /// Sets the path to the directory containing the original data files.
+    ///
+    /// When set, lines that aren't present in the original data anymore are counted as unused.
+    pub fn original_path(mut self, original_path: Option<P>) -> Self {
+        self.original_path = original_path;
+        self
+    }
+
+    /// Sets the path to the `plugins.js` file.
+    ///
+    /// When set along with the original path, unused lines of `plugins.txt` are counted as well.
+    pub fn plugins_file_path(mut self, plugins_file_path: Option<P>) -> Self {
+        self.plugins_file_path = plugins_file_path;
+        self
+    }
+
+    /// Sets the maps processing mode.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn maps_processing_mode(mut self, maps_processing_mode: MapsProcessingMode) -> Self {
+        self.maps_processing_mode = maps_processing_mode;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// Must be the same value, as in previous read. Per-map files are counted as a single `maps.txt` file.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets whether to romanize text.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn romanize(mut self, romanize: bool) -> Self {
+        self.romanize = romanize;
+        self
+    }
+
+    /// Sets the game type for specialized processing.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn game_type(mut self, game_type: Option<GameType>) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
+    /// Sets whether to trim whitespace from strings.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    fn collect_unused(&self, original_path: &Path, translation_path: &Path) -> Vec<PurgedLine> {
+        let extension: &str = determine_extension(self.engine_type);
+        let mut unused: Vec<PurgedLine> = Vec::new();
+
+        if maps_exist(translation_path, self.maps_layout) {
+            unused.extend(
+                MapPurger::new(original_path, translation_path, self.engine_type)
+                    .maps_processing_mode(self.maps_processing_mode)
+                    .maps_layout(self.maps_layout)
+                    .romanize(self.romanize)
+                    .game_type(self.game_type)
+                    .trim(self.trim)
+                    .dry_run(true)
+                    .purge(None, None),
+            );
+        }
+
+        unused.extend(
+            OtherPurger::new(original_path, translation_path, self.engine_type)
+                .romanize(self.romanize)
+                .game_type(self.game_type)
+                .trim(self.trim)
+                .dry_run(true)
+                .purge(None, None),
+        );
+
+        let system_file_path: PathBuf = original_path.join(format!("System{extension}"));
+
+        if system_file_path.exists() && translation_path.join("system.txt").exists() {
+            unused.extend(
+                SystemPurger::new(system_file_path.as_path(), translation_path, self.engine_type)
+                    .romanize(self.romanize)
+                    .trim(self.trim)
+                    .dry_run(true)
+                    .purge(None, None),
+            );
+        }
+
+        let scripts_file_path: PathBuf = original_path.join(format!("Scripts{extension}"));
+
+        if !self.engine_type.is_new() && scripts_file_path.exists() && translation_path.join("scripts.txt").exists() {
+            unused.extend(
+                ScriptPurger::new(scripts_file_path.as_path(), translation_path)
+                    .romanize(self.romanize)
+                    .dry_run(true)
+                    .purge(None, None),
+            );
+        }
+
+        if let Some(plugins_file_path) = &self.plugins_file_path {
+            let plugins_file_path: &Path = plugins_file_path.as_ref();
+
+            if plugins_file_path.exists() && translation_path.join("plugins.txt").exists() {
+                unused.extend(
+                    PluginPurger::new(plugins_file_path, translation_path)
+                        .romanize(self.romanize)
+                        .dry_run(true)
+                        .purge(None, None),
+                );
+            }
+        }
+
+        unused
+    }
+
+    /// This method reads every `.txt` file in the translation directory and computes statistics.
+    ///
+    /// # Returns
+    ///
+    /// A [`Stats`] of the whole project, every file and every map.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{stats::{read_stats, write_stats, StatsCollector}, types::EngineType};
+    ///
+    /// let stats = StatsCollector::new("translation", EngineType::New)
+    ///     .original_path(Some("data"))
+    ///     .collect();
+    /// let previous = read_stats("translation");
+    ///
+    /// println!("{}", stats.summary(previous.as_ref()));
+    /// write_stats(&stats, "translation");
+    /// ```
+    pub fn collect(self) -> Stats {
+        self.collect_path(self.translation_path.as_ref())
+    }
+
+    /// This method computes statistics of every language of a multi-language project.
+    ///
+    /// Translation path is treated as the root translation directory with a subdirectory per language.
+    ///
+    /// # Returns
+    ///
+    /// A [`LanguageStats`] with stats of every language.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{stats::StatsCollector, types::EngineType};
+    ///
+    /// let stats = StatsCollector::new("translation", EngineType::New)
+    ///     .original_path(Some("data"))
+    ///     .collect_languages();
+    ///
+    /// println!("{}", stats.summary());
+    /// ```
+    pub fn collect_languages(self) -> LanguageStats {
+        let mut stats: LanguageStats = LanguageStats::default();
+
+        for language in find_languages(self.translation_path.as_ref()) {
+            let language_stats: Stats = self.collect_path(&self.translation_path.as_ref().join(&language));
+            stats.languages.insert(language, language_stats);
+        }
+
+        stats
+    }
+
+    fn collect_path(&self, translation_path: &Path) -> Stats {
+        let mut stats: Stats = Stats::default();
+
+        let unused: Vec<PurgedLine> = match &self.original_path {
+            Some(original_path) => self.collect_unused(original_path.as_ref(), translation_path),
+            None => Vec::new(),
+        };
+
+        let mut project_originals: HashSet<String, GxBuildHasher> = HashSet::default();
+
+        for filename in txt_files(translation_path, self.maps_layout) {
+            if filename == "stat.txt" {
+                continue;
+            }
+
+            let content: String = read_txt(translation_path, &filename, self.maps_layout);
+            let is_maps: bool = filename == "maps.txt";
+            let version: u8 = format_version(&content);
+
+            let mut file_stats: FileStats = FileStats::default();
+            let mut map_stats: Option<(u16, FileStats)> = None;
+            let mut file_originals: HashSet<&str, GxBuildHasher> = HashSet::default();
+            let mut pending_state: Option<LineState> = None;
+
+            for line in content.split('\n') {
+                let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+                    continue;
+                };
+
+                let translation: &str = translation
+                    .rsplit(LINES_SEPARATOR)
+                    .find(|x| !x.is_empty())
+                    .unwrap_or("");
+
+                if is_maps && original == "<!-- Map -->" {
+                    if let Some((map_number, map_stats)) = map_stats.take() {
+                        stats.maps.entry(map_number).or_default().add(&map_stats);
+                    }
+
+                    map_stats = Some((parse_map_number(translation), FileStats::default()));
+                    continue;
+                }
+
+                if original == STATE_COMMENT {
+                    pending_state = LineState::parse(translation);
+                    continue;
+                }
+
+                if original.starts_with("<!--") {
+                    continue;
+                }
+
+                let is_duplicate: bool = !file_originals.insert(original);
+                let is_project_duplicate: bool = !project_originals.insert(original.to_owned());
+
+                let original: String = unescape(original, version);
+                let translation: String = unescape(translation, version);
+
+                let mut line_stats: FileStats = FileStats::default();
+                line_stats.add_line(&original, &translation);
+
+                if let Some(state) = pending_state.take() {
+                    line_stats.add_state(state);
+                }
+
+                file_stats.add(&line_stats);
+                file_stats.duplicates += is_duplicate as usize;
+
+                if let Some((_, map_stats)) = &mut map_stats {
+                    map_stats.add(&line_stats);
+                    map_stats.duplicates += is_duplicate as usize;
+                }
+
+                stats.total.add(&line_stats);
+                stats.total.duplicates += is_project_duplicate as usize;
+            }
+
+            if let Some((map_number, map_stats)) = map_stats.take() {
+                stats.maps.entry(map_number).or_default().add(&map_stats);
+            }
+
+            file_stats.unused = unused.iter().filter(|x| x.file == filename).count();
+            stats.total.unused += file_stats.unused;
+            stats.files.insert(filename, file_stats);
+        }
+
+        for (map_number, map_stats) in &mut stats.maps {
+            map_stats.unused = unused
+                .iter()
+                .filter(|x| x.file == "maps.txt" && x.map == Some(*map_number))
+                .count();
+        }
+
+        stats
+    }
+}
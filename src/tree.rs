@@ -0,0 +1,226 @@
+//! Tree of maps, as it's shown in the editor.
+//!
+//! `MapInfos` file holds the name, the parent and the order of every map. Maps, which parent is `0`
+//! or isn't present in `MapInfos`, are roots of the tree, and children of every map are sorted by their order.
+use crate::{escape, functions::parse_rpgm_file, statics::LINES_SEPARATOR, types::EngineType};
+use gxhash::GxBuildHasher;
+use indexmap::IndexMap;
+use sonic_rs::{prelude::*, Value};
+use std::{collections::HashSet, path::Path};
+
+/// Prefix of the comment, which holds the path of the map in the tree.
+///
+/// The full comment is `<!-- Map Path: World/Town/Inn --><#>`.
+pub const MAP_PATH_COMMENT_PREFIX: &str = "<!-- Map Path: ";
+
+/// A map entry of the `MapInfos` file.
+///
+/// # Fields
+///
+/// - `id` - Number of the map
+/// - `name` - Name of the map
+/// - `parent_id` - Number of the parent map, `0` for root maps
+/// - `order` - Order of the map among all maps
+/// - `expanded` - Whether the map is expanded in the editor
+#[derive(Debug, PartialEq, Clone)]
+pub struct MapInfo {
+    pub id: u16,
+    pub name: String,
+    pub parent_id: u16,
+    pub order: u64,
+    pub expanded: bool,
+}
+
+/// Tree of maps, parsed from the `MapInfos` file.
+///
+/// Holds every map entry, mapped to its number, sorted by map numbers.
+#[derive(Debug, Default, Clone)]
+pub struct MapTree {
+    pub maps: IndexMap<u16, MapInfo, GxBuildHasher>,
+}
+
+impl MapTree {
+    /// Parses the map tree from the `MapInfos` file.
+    ///
+    /// # Parameters
+    ///
+    /// - `mapinfos_path` - Path to the `MapInfos` file
+    /// - `engine_type` - The RPG Maker engine type
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{tree::MapTree, types::EngineType};
+    ///
+    /// let tree = MapTree::parse("data/MapInfos.json", EngineType::New);
+    ///
+    /// for map_number in tree.traverse() {
+    ///     println!("{}", tree.path(map_number).join("/"));
+    /// }
+    /// ```
+    pub fn parse<P: AsRef<Path>>(mapinfos_path: P, engine_type: EngineType) -> MapTree {
+        MapTree::from_value(&parse_rpgm_file(mapinfos_path.as_ref(), engine_type), engine_type)
+    }
+
+    /// Parses the map tree from the already parsed `MapInfos` file.
+    pub fn from_value(mapinfos: &Value, engine_type: EngineType) -> MapTree {
+        let (name_label, parent_id_label, order_label, expanded_label) = if engine_type.is_new() {
+            ("name", "parentId", "order", "expanded")
+        } else {
+            (
+                "__symbol__name",
+                "__symbol__parent_id",
+                "__symbol__order",
+                "__symbol__expanded",
+            )
+        };
+
+        let entries: Vec<(u16, &Value)> = if engine_type.is_new() {
+            mapinfos
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter(|value| value.is_object())
+                        .filter_map(|value| Some((value["id"].as_u64()? as u16, value)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else {
+            mapinfos
+                .as_object()
+                .map(|obj| {
+                    obj.iter()
+                        .filter_map(|(key, value)| Some((key.strip_prefix("__integer__")?.parse().ok()?, value)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        let mut maps: IndexMap<u16, MapInfo, GxBuildHasher> = entries
+            .into_iter()
+            .map(|(id, value)| {
+                (
+                    id,
+                    MapInfo {
+                        id,
+                        name: value[name_label].as_str().unwrap_or("").to_owned(),
+                        parent_id: value[parent_id_label].as_u64().unwrap_or(0) as u16,
+                        order: value[order_label].as_u64().unwrap_or(0),
+                        expanded: value[expanded_label].as_bool().unwrap_or(false),
+                    },
+                )
+            })
+            .collect();
+
+        maps.sort_unstable_keys();
+        MapTree { maps }
+    }
+
+    /// Returns the map entry by its number.
+    pub fn get(&self, map_number: u16) -> Option<&MapInfo> {
+        self.maps.get(&map_number)
+    }
+
+    /// Returns whether the map is a root of the tree.
+    fn is_root(&self, map: &MapInfo) -> bool {
+        map.parent_id == 0 || map.parent_id == map.id || !self.maps.contains_key(&map.parent_id)
+    }
+
+    /// Returns root maps, sorted by their order.
+    pub fn roots(&self) -> Vec<&MapInfo> {
+        let mut roots: Vec<&MapInfo> = self.maps.values().filter(|map| self.is_root(map)).collect();
+        roots.sort_by_key(|map| (map.order, map.id));
+        roots
+    }
+
+    /// Returns children of the map, sorted by their order.
+    pub fn children(&self, map_number: u16) -> Vec<&MapInfo> {
+        let mut children: Vec<&MapInfo> = self
+            .maps
+            .values()
+            .filter(|map| map.parent_id == map_number && map.id != map_number)
+            .collect();
+        children.sort_by_key(|map| (map.order, map.id));
+        children
+    }
+
+    /// Returns names of maps from the root to the map itself.
+    ///
+    /// Returns an empty vec, if the map isn't present in the tree.
+    pub fn path(&self, map_number: u16) -> Vec<&str> {
+        let mut path: Vec<&str> = Vec::new();
+        let mut current: Option<&MapInfo> = self.get(map_number);
+
+        // Length is limited, so broken `MapInfos` with cyclic parents doesn't hang
+        while let Some(map) = current.filter(|_| path.len() < self.maps.len()) {
+            path.push(&map.name);
+
+            current = if self.is_root(map) {
+                None
+            } else {
+                self.get(map.parent_id)
+            };
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Returns numbers of maps in the depth-first order of the tree, as maps are shown in the editor.
+    ///
+    /// Maps, that can't be reached from roots because of cyclic parents, are appended at the end.
+    pub fn traverse(&self) -> Vec<u16> {
+        let mut order: Vec<u16> = Vec::with_capacity(self.maps.len());
+        let mut visited: HashSet<u16, GxBuildHasher> = HashSet::default();
+        let mut stack: Vec<u16> = self.roots().into_iter().rev().map(|map| map.id).collect();
+
+        while let Some(map_number) = stack.pop() {
+            if !visited.insert(map_number) {
+                continue;
+            }
+
+            order.push(map_number);
+            stack.extend(self.children(map_number).into_iter().rev().map(|map| map.id));
+        }
+
+        order.extend(self.maps.keys().filter(|id| !visited.contains(id)));
+        order
+    }
+}
+
+/// Returns whether the comment is a map path comment.
+#[inline]
+pub(crate) fn is_map_path_comment(original: &str) -> bool {
+    original.starts_with(MAP_PATH_COMMENT_PREFIX)
+}
+
+/// Inserts the map path comment after the `<!-- Map --><#>N` comment of every map, which is present in the tree.
+pub(crate) fn insert_map_paths(content: String, tree: &MapTree) -> String {
+    let mut output: String = String::with_capacity(content.len());
+
+    for line in content.split('\n') {
+        output.push_str(line);
+        output.push('\n');
+
+        let Some(map_number) = line
+            .strip_prefix("<!-- Map -->")
+            .and_then(|line| line.strip_prefix(LINES_SEPARATOR))
+            .and_then(|number| number.trim().parse::<u16>().ok())
+        else {
+            continue;
+        };
+
+        let path: Vec<&str> = tree.path(map_number);
+
+        if !path.is_empty() {
+            output.push_str(MAP_PATH_COMMENT_PREFIX);
+            output.push_str(&escape(&path.join("/")));
+            output.push_str(" -->");
+            output.push_str(LINES_SEPARATOR);
+            output.push('\n');
+        }
+    }
+
+    output.pop();
+    output
+}
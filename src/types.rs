@@ -65,6 +65,27 @@ impl EngineType {
     pub const fn is_xp(self) -> bool {
         matches!(self, EngineType::XP)
     }
+
+    /// Returns the name of the engine type, as it's written in `.txt` headers.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            EngineType::New => "new",
+            EngineType::VXAce => "vxace",
+            EngineType::VX => "vx",
+            EngineType::XP => "xp",
+        }
+    }
+
+    /// Parses the engine type from its name, as it's written in `.txt` headers.
+    pub fn parse(engine_type: &str) -> Option<EngineType> {
+        match engine_type.trim() {
+            "new" => Some(EngineType::New),
+            "vxace" => Some(EngineType::VXAce),
+            "vx" => Some(EngineType::VX),
+            "xp" => Some(EngineType::XP),
+            _ => None,
+        }
+    }
 }
 
 #[derive(PartialEq, Clone, Copy)]
@@ -205,6 +226,353 @@ impl MapsProcessingMode {
     pub const fn is_preserve(self) -> bool {
         matches!(self, MapsProcessingMode::Preserve)
     }
+
+    /// Returns the name of the maps processing mode, as it's written in `.txt` headers.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            MapsProcessingMode::Default => "default",
+            MapsProcessingMode::Separate => "separate",
+            MapsProcessingMode::Preserve => "preserve",
+        }
+    }
+
+    /// Parses the maps processing mode from its name, as it's written in `.txt` headers.
+    pub fn parse(maps_processing_mode: &str) -> Option<MapsProcessingMode> {
+        match maps_processing_mode.trim() {
+            "default" => Some(MapsProcessingMode::Default),
+            "separate" => Some(MapsProcessingMode::Separate),
+            "preserve" => Some(MapsProcessingMode::Preserve),
+            _ => None,
+        }
+    }
+}
+
+/// Defines, how translation of maps is laid out in the translation directory.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum MapsLayout {
+    /// All maps are stored in a single `maps.txt` file.
+    Single,
+    /// Every map is stored in its own file in the `maps` directory, like `maps/map001.txt`.
+    PerMap,
+    /// Same as `PerMap`, but the map name is appended to the file name, like `maps/map001_Town.txt`.
+    PerMapNamed,
+}
+
+impl MapsLayout {
+    pub const fn is_single(self) -> bool {
+        matches!(self, MapsLayout::Single)
+    }
+
+    pub const fn is_per_map(self) -> bool {
+        matches!(self, MapsLayout::PerMap | MapsLayout::PerMapNamed)
+    }
+}
+
+/// Format of spreadsheet files, produced by exporter and consumed by importer.
+#[derive(PartialEq, Clone, Copy)]
+pub enum SheetFormat {
+    /// Comma-separated values.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+}
+
+impl SheetFormat {
+    pub const fn is_csv(self) -> bool {
+        matches!(self, SheetFormat::Csv)
+    }
+
+    pub const fn is_tsv(self) -> bool {
+        matches!(self, SheetFormat::Tsv)
+    }
+
+    /// Returns the character, which separates cells.
+    pub const fn delimiter(self) -> char {
+        match self {
+            SheetFormat::Csv => ',',
+            SheetFormat::Tsv => '\t',
+        }
+    }
+
+    /// Returns the extension of the files, without the leading dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            SheetFormat::Csv => "csv",
+            SheetFormat::Tsv => "tsv",
+        }
+    }
+}
+
+/// Defines, how writers and purgers treat the header of `.txt` files.
+#[derive(PartialEq, Clone, Copy)]
+pub enum HeaderMode {
+    /// Header is ignored.
+    Ignore,
+    /// Settings are compared with the header, and the file is skipped on mismatch.
+    Validate,
+    /// Settings, recorded in the header, override the passed ones.
+    Configure,
+}
+
+impl HeaderMode {
+    pub const fn is_ignore(self) -> bool {
+        matches!(self, HeaderMode::Ignore)
+    }
+
+    pub const fn is_validate(self) -> bool {
+        matches!(self, HeaderMode::Validate)
+    }
+
+    pub const fn is_configure(self) -> bool {
+        matches!(self, HeaderMode::Configure)
+    }
+}
+
+/// A single string replacement, made by a writer.
+///
+/// `location` is the path to the replaced value inside the file, like `events[1].pages[0].list[3].parameters[0]`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Replacement {
+    pub file: String,
+    pub location: String,
+    pub original: String,
+    pub translation: String,
+}
+
+/// A line of a translation `.txt` file, that has no translation.
+///
+/// `line` is the 1-based line number inside the `.txt` file.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UntranslatedLine {
+    pub file: String,
+    pub line: usize,
+    pub original: String,
+}
+
+/// Report, returned by writers when dry run is enabled.
+#[derive(Debug, Default, Clone)]
+pub struct WriteReport {
+    pub replacements: Vec<Replacement>,
+    pub untranslated: Vec<UntranslatedLine>,
+}
+
+impl WriteReport {
+    pub fn is_empty(&self) -> bool {
+        self.replacements.is_empty() && self.untranslated.is_empty()
+    }
+
+    pub fn extend(&mut self, other: WriteReport) {
+        self.replacements.extend(other.replacements);
+        self.untranslated.extend(other.untranslated);
+    }
+}
+
+/// A single pattern of a [`FileFilter`].
+#[derive(Debug, Clone)]
+enum FilePattern {
+    /// Inclusive range of map numbers, like `5` or `10-20`.
+    Maps(u16, u16),
+    /// Glob pattern with `*` and `?` wildcards, like `Map0*`.
+    Glob(regex::Regex),
+    /// Name of the file, like `Items` or `Map003`.
+    Name(String),
+}
+
+impl FilePattern {
+    fn parse(pattern: &str) -> FilePattern {
+        let pattern: &str = file_stem(pattern.trim());
+
+        let range: Option<(u16, u16)> = match pattern.split_once('-') {
+            Some((start, end)) => start.trim().parse().ok().zip(end.trim().parse().ok()),
+            None => pattern.parse().ok().map(|number| (number, number)),
+        };
+
+        if let Some((start, end)) = range {
+            FilePattern::Maps(start.min(end), start.max(end))
+        } else if pattern.contains(['*', '?']) {
+            let regex: String = regex::escape(pattern).replace(r"\*", ".*").replace(r"\?", ".");
+            FilePattern::Glob(regex::Regex::new(&format!("(?i)^{regex}$")).unwrap_log())
+        } else {
+            FilePattern::Name(pattern.to_lowercase())
+        }
+    }
+
+    fn matches(&self, stem: &str) -> bool {
+        match self {
+            FilePattern::Maps(start, end) => stem
+                .strip_prefix("Map")
+                .filter(|number| !number.is_empty() && number.bytes().all(|x| x.is_ascii_digit()))
+                .and_then(|number| number.parse::<u16>().ok())
+                .is_some_and(|number| (*start..=*end).contains(&number)),
+            FilePattern::Glob(regex) => regex.is_match(stem),
+            FilePattern::Name(name) => stem.to_lowercase() == *name,
+        }
+    }
+}
+
+/// Returns the file name without extension.
+fn file_stem(file_name: &str) -> &str {
+    file_name.split_once('.').map_or(file_name, |(stem, _)| stem)
+}
+
+/// Filter of files, that are processed by readers, writers and purgers.
+///
+/// Patterns are matched against file names without extension, case-insensitively:
+/// - `5` or `10-20` - Inclusive range of map numbers, matches `Map005.json` or `Map010.json` to `Map020.json`
+/// - `Map0*` - Glob pattern with `*` and `?` wildcards
+/// - `Items` - Name of the file, like `Items`, `Map003`, `System`, `Scripts` or `plugins`
+///
+/// A file is processed, if it matches any of included patterns, or there are no included patterns,
+/// and doesn't match any of excluded patterns. Default filter matches every file.
+///
+/// # Examples
+///
+/// ```
+/// use rvpacker_txt_rs_lib::types::FileFilter;
+///
+/// let filter = FileFilter::default()
+///     .include(&["1-10", "Items"])
+///     .exclude(&["Map00?"]);
+///
+/// assert!(filter.matches("Map010.json"));
+/// assert!(filter.matches("Items.json"));
+/// assert!(!filter.matches("Map005.json"));
+/// assert!(!filter.matches("System.json"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FileFilter {
+    include: Vec<FilePattern>,
+    exclude: Vec<FilePattern>,
+}
+
+impl FileFilter {
+    /// Adds patterns of files, that are processed.
+    pub fn include<S: AsRef<str>>(mut self, patterns: &[S]) -> Self {
+        self.include
+            .extend(patterns.iter().map(|pattern| FilePattern::parse(pattern.as_ref())));
+        self
+    }
+
+    /// Adds patterns of files, that aren't processed.
+    pub fn exclude<S: AsRef<str>>(mut self, patterns: &[S]) -> Self {
+        self.exclude
+            .extend(patterns.iter().map(|pattern| FilePattern::parse(pattern.as_ref())));
+        self
+    }
+
+    /// Returns whether the filter has any patterns.
+    pub fn is_active(&self) -> bool {
+        !self.include.is_empty() || !self.exclude.is_empty()
+    }
+
+    /// Returns whether the file with the passed name is processed.
+    ///
+    /// The name may be passed with or without extension.
+    pub fn matches(&self, file_name: &str) -> bool {
+        let stem: &str = file_stem(file_name);
+
+        (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(stem)))
+            && !self.exclude.iter().any(|pattern| pattern.matches(stem))
+    }
+
+    /// Returns whether the map with the passed number is processed.
+    pub fn matches_map(&self, map_number: u16) -> bool {
+        self.matches(&format!("Map{map_number:03}"))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PurgeReason {
+    /// Line is not present in the original data anymore.
+    NotInSource,
+    /// Line has no translation.
+    Empty,
+    /// Line is already present in the passed ignore map.
+    Ignored,
+    /// Line has one of the purged review states.
+    State,
+}
+
+impl PurgeReason {
+    pub const fn is_not_in_source(self) -> bool {
+        matches!(self, PurgeReason::NotInSource)
+    }
+
+    pub const fn is_empty(self) -> bool {
+        matches!(self, PurgeReason::Empty)
+    }
+
+    pub const fn is_ignored(self) -> bool {
+        matches!(self, PurgeReason::Ignored)
+    }
+
+    pub const fn is_state(self) -> bool {
+        matches!(self, PurgeReason::State)
+    }
+}
+
+/// Review state of a line, stored in the `<!-- State --><#>state` comment before the line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum LineState {
+    /// Line was translated by a machine, and wasn't checked yet.
+    MachineTranslated,
+    /// Line was filled from a similar, but not identical source.
+    Fuzzy,
+    /// Line needs to be reviewed, for example, because its original was changed.
+    NeedsReview,
+    /// Line was reviewed and approved.
+    Approved,
+}
+
+impl LineState {
+    /// Returns the name of the state, as it's written in `.txt` files.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            LineState::MachineTranslated => "machine",
+            LineState::Fuzzy => "fuzzy",
+            LineState::NeedsReview => "needs-review",
+            LineState::Approved => "approved",
+        }
+    }
+
+    /// Parses the state from its name, as it's written in `.txt` files.
+    pub fn parse(state: &str) -> Option<LineState> {
+        match state.trim() {
+            "machine" => Some(LineState::MachineTranslated),
+            "fuzzy" => Some(LineState::Fuzzy),
+            "needs-review" => Some(LineState::NeedsReview),
+            "approved" => Some(LineState::Approved),
+            _ => None,
+        }
+    }
+
+    pub const fn is_machine_translated(self) -> bool {
+        matches!(self, LineState::MachineTranslated)
+    }
+
+    pub const fn is_fuzzy(self) -> bool {
+        matches!(self, LineState::Fuzzy)
+    }
+
+    pub const fn is_needs_review(self) -> bool {
+        matches!(self, LineState::NeedsReview)
+    }
+
+    pub const fn is_approved(self) -> bool {
+        matches!(self, LineState::Approved)
+    }
+}
+
+/// A line, removed (or, with dry run, to be removed) from a translation `.txt` file by a purger.
+///
+/// `map` is the number of the map, which block the line belongs to, for lines of maps.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PurgedLine {
+    pub file: String,
+    pub map: Option<u16>,
+    pub original: String,
+    pub translation: String,
+    pub reason: PurgeReason,
 }
 
 pub trait TrimReplace {
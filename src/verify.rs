@@ -0,0 +1,672 @@
+#[cfg(feature = "log")]
+use crate::println;
+use crate::{
+    determine_extension,
+    functions::{as_text, join_key, parse_rpgm_file},
+    read::{MapReader, OtherReader, PluginReader, ScriptReader, SystemReader},
+    statics::{localization::VERIFIED_FILE_MSG, ENCODINGS, LINES_SEPARATOR},
+    types::{EngineType, GameType, MapsProcessingMode, OptionExt, ProcessingMode, ResultExt},
+    write::{MapWriter, OtherWriter, PluginWriter, ScriptWriter, SystemWriter},
+};
+use flate2::read::ZlibDecoder;
+use gxhash::GxBuildHasher;
+use indexmap::IndexMap;
+use marshal_rs::{load, StringMode};
+use sonic_rs::{from_str, from_value, prelude::*, to_string, JsonType, Value};
+use std::{
+    fmt,
+    fs::{create_dir_all, read, read_dir, read_to_string, write},
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+/// Kind of a difference between an original file and the file written with identity translation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DifferenceKind {
+    /// Value has a different JSON type.
+    Type,
+    /// Non-string scalar value (number, boolean) differs.
+    Value,
+    /// Key or element is present in the original, but not in the written file.
+    Missing,
+    /// Key or element is present in the written file, but not in the original.
+    Extra,
+    /// Arrays have different lengths.
+    Length,
+    /// Objects have the same keys in a different order.
+    KeyOrder,
+    /// String contents differ. Only reported when `strings` is enabled.
+    String,
+    /// Values are equal, but are represented or serialized differently.
+    Encoding,
+    /// Text surrounding the JSON body of `plugins.js` differs.
+    Header,
+}
+
+impl DifferenceKind {
+    pub const fn is_string(self) -> bool {
+        matches!(self, DifferenceKind::String)
+    }
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            DifferenceKind::Type => "type",
+            DifferenceKind::Value => "value",
+            DifferenceKind::Missing => "missing",
+            DifferenceKind::Extra => "extra",
+            DifferenceKind::Length => "length",
+            DifferenceKind::KeyOrder => "key order",
+            DifferenceKind::String => "string",
+            DifferenceKind::Encoding => "encoding",
+            DifferenceKind::Header => "header",
+        }
+    }
+}
+
+/// A single difference between an original file and the file written with identity translation.
+///
+/// # Fields
+///
+/// - `path` - Location of the value inside the file, like `events[1].pages[0].list[3]`
+/// - `kind` - Kind of the difference
+/// - `original` - Serialized original value, empty if it's missing
+/// - `written` - Serialized written value, empty if it's missing
+#[derive(Debug, PartialEq, Clone)]
+pub struct Difference {
+    pub path: String,
+    pub kind: DifferenceKind,
+    pub original: String,
+    pub written: String,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at `{}`: {} -> {}",
+            self.kind.as_str(),
+            self.path,
+            self.original,
+            self.written
+        )
+    }
+}
+
+/// Result of the round-trip verification.
+///
+/// Holds every written file, mapped to the differences found in it. Files without
+/// differences are present in the map with an empty vector.
+#[derive(Debug, Default, Clone)]
+pub struct VerificationReport {
+    pub files: IndexMap<String, Vec<Difference>, GxBuildHasher>,
+}
+
+impl VerificationReport {
+    /// Returns `true` if no differences were found in any file.
+    pub fn is_lossless(&self) -> bool {
+        self.files.values().all(Vec::is_empty)
+    }
+
+    /// Returns an iterator over files that have at least one difference.
+    pub fn changed_files(&self) -> impl Iterator<Item = (&String, &Vec<Difference>)> {
+        self.files.iter().filter(|(_, differences)| !differences.is_empty())
+    }
+}
+
+#[inline]
+fn serialize(value: &Value) -> String {
+    to_string(value).unwrap_or_default()
+}
+
+fn diff_values(path: &str, original: &Value, written: &Value, strings: bool, differences: &mut Vec<Difference>) {
+    let push = |differences: &mut Vec<Difference>, kind: DifferenceKind| {
+        differences.push(Difference {
+            path: path.to_owned(),
+            kind,
+            original: serialize(original),
+            written: serialize(written),
+        })
+    };
+
+    if let (Some((original_text, original_bytes)), Some((written_text, written_bytes))) =
+        (as_text(original), as_text(written))
+    {
+        if original_text != written_text {
+            if strings {
+                push(differences, DifferenceKind::String);
+            }
+        } else if original_bytes != written_bytes {
+            push(differences, DifferenceKind::Encoding);
+        }
+
+        return;
+    }
+
+    let original_type: JsonType = original.get_type();
+
+    if original_type != written.get_type() {
+        push(differences, DifferenceKind::Type);
+        return;
+    }
+
+    match original_type {
+        JsonType::Object => {
+            let original_obj = original.as_object().unwrap_log();
+            let written_obj = written.as_object().unwrap_log();
+
+            for (key, value) in original_obj.iter() {
+                match written_obj.get(&key) {
+                    Some(written_value) => {
+                        diff_values(&join_key(path, key), value, written_value, strings, differences)
+                    }
+                    None => differences.push(Difference {
+                        path: join_key(path, key),
+                        kind: DifferenceKind::Missing,
+                        original: serialize(value),
+                        written: String::new(),
+                    }),
+                }
+            }
+
+            for (key, value) in written_obj.iter() {
+                if !original_obj.contains_key(&key) {
+                    differences.push(Difference {
+                        path: join_key(path, key),
+                        kind: DifferenceKind::Extra,
+                        original: String::new(),
+                        written: serialize(value),
+                    });
+                }
+            }
+
+            if original_obj.len() == written_obj.len()
+                && original_obj.iter().all(|(key, _)| written_obj.contains_key(&key))
+                && !original_obj
+                    .iter()
+                    .zip(written_obj.iter())
+                    .all(|((original_key, _), (written_key, _))| original_key == written_key)
+            {
+                differences.push(Difference {
+                    path: path.to_owned(),
+                    kind: DifferenceKind::KeyOrder,
+                    original: original_obj.iter().map(|(key, _)| key).collect::<Vec<_>>().join(", "),
+                    written: written_obj.iter().map(|(key, _)| key).collect::<Vec<_>>().join(", "),
+                });
+            }
+        }
+        JsonType::Array => {
+            let original_arr = original.as_array().unwrap_log();
+            let written_arr = written.as_array().unwrap_log();
+
+            if original_arr.len() != written_arr.len() {
+                differences.push(Difference {
+                    path: path.to_owned(),
+                    kind: DifferenceKind::Length,
+                    original: original_arr.len().to_string(),
+                    written: written_arr.len().to_string(),
+                });
+            }
+
+            for (i, (original_value, written_value)) in original_arr.iter().zip(written_arr.iter()).enumerate() {
+                diff_values(
+                    &format!("{path}[{i}]"),
+                    original_value,
+                    written_value,
+                    strings,
+                    differences,
+                );
+            }
+        }
+        JsonType::Number => {
+            if original.as_f64() != written.as_f64() {
+                push(differences, DifferenceKind::Value);
+            } else if original.is_f64() != written.is_f64() {
+                push(differences, DifferenceKind::Encoding);
+            }
+        }
+        _ => {
+            if original != written {
+                push(differences, DifferenceKind::Value);
+            }
+        }
+    }
+}
+
+/// Replaces compressed script code in `Scripts` entries with the inflated code,
+/// so the compression itself doesn't show up as a difference.
+fn inflate_scripts(scripts: &mut Value) {
+    if let Some(entries) = scripts.as_array_mut() {
+        for entry in entries.iter_mut() {
+            let data: Vec<u8> = from_value(&entry[2]["data"]).unwrap_or_default();
+
+            let mut inflated: Vec<u8> = Vec::new();
+            ZlibDecoder::new(&*data).read_to_end(&mut inflated).unwrap_log();
+
+            let mut code: String = String::new();
+
+            for encoding in ENCODINGS {
+                let (cow, _, had_errors) = encoding.decode(&inflated);
+
+                if !had_errors {
+                    code = cow.into_owned();
+                    break;
+                }
+            }
+
+            entry[2] = Value::from(&code);
+        }
+    }
+}
+
+/// Fills every translation line with its original text.
+///
+/// Comment lines keep their values, except for the map display name,
+/// which gets its own name, so the writer leaves it untouched.
+fn fill_identity(txt_file_path: &Path) {
+    let content: String = read_to_string(txt_file_path).unwrap_log();
+
+    let filled: Vec<String> = content
+        .split('\n')
+        .map(|line: &str| {
+            let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+                return line.to_owned();
+            };
+
+            if let Some(display_name) = original
+                .strip_prefix("<!-- In-game Displayed Name: ")
+                .and_then(|x| x.strip_suffix(" -->"))
+            {
+                format!("{original}{LINES_SEPARATOR}{display_name}")
+            } else if original.starts_with("<!--") {
+                format!("{original}{LINES_SEPARATOR}{translation}")
+            } else {
+                format!("{original}{LINES_SEPARATOR}{original}")
+            }
+        })
+        .collect();
+
+    write(txt_file_path, filled.join("\n")).unwrap_log();
+}
+
+/// A struct for verifying that writing an untouched translation is lossless.
+///
+/// Verifier reads the original files, fills every translation line with its original text,
+/// writes the files back and structurally compares written files with the originals. Every
+/// difference beyond translated strings is reported per file.
+///
+/// # Fields
+///
+/// - `original_path` - Path to the directory containing the original data files
+/// - `work_path` - Path to the scratch directory, where translation and output will be written
+/// - `plugins_file_path` - Optional path to the `plugins.js` file
+/// - `maps_processing_mode` - Controls how maps are processed
+/// - `romanize` - Whether to romanize non-Latin text
+/// - `logging` - Whether to log processing information
+/// - `game_type` - Optional specific game type for specialized processing
+/// - `engine_type` - The RPG Maker engine type
+/// - `trim` - Whether to trim whitespace from strings
+/// - `strings` - Whether to report string differences too
+/// - `excluded_files` - Names of data files, that aren't processed
+/// - `included_files` - Names of excluded data files, that are processed anyway
+pub struct Verifier<P: AsRef<Path> + Sync> {
+    original_path: P,
+    work_path: P,
+    plugins_file_path: Option<P>,
+    maps_processing_mode: MapsProcessingMode,
+    romanize: bool,
+    logging: bool,
+    game_type: Option<GameType>,
+    engine_type: EngineType,
+    trim: bool,
+    strings: bool,
+    excluded_files: Option<Vec<String>>,
+    included_files: Vec<String>,
+}
+
+impl<P: AsRef<Path> + Sync> Verifier<P> {
+    /// Creates a new `Verifier` with default values.
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `work_path` - Path to the scratch directory, where translation and output will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn new(original_path: P, work_path: P, engine_type: EngineType) -> Self {
+        Self::default(original_path, work_path, engine_type)
+    }
+
+    /// Creates a new `Verifier` with default values.
+    ///
+    /// Default values are:
+    /// - `plugins_file_path`: `None`
+    /// - `maps_processing_mode`: `MapsProcessingMode::Default`
+    /// - `romanize`: `false`
+    /// - `logging`: `false`
+    /// - `game_type`: `None`
+    /// - `trim`: `false`
+    /// - `strings`: `false`
+    /// - `excluded_files`: `None`
+    /// - `included_files`: empty
+    ///
+    /// # Parameters
+    ///
+    /// - `original_path` - Path to the directory containing the original data files
+    /// - `work_path` - Path to the scratch directory, where translation and output will be written
+    /// - `engine_type` - The RPG Maker engine type
+    pub fn default(original_path: P, work_path: P, engine_type: EngineType) -> Self {
+        Self {
+            original_path,
+            work_path,
+            plugins_file_path: None,
+            maps_processing_mode: MapsProcessingMode::Default,
+            romanize: false,
+            logging: false,
+            game_type: None,
+            engine_type,
+            trim: false,
+            strings: false,
+            excluded_files: None,
+            included_files: Vec::new(),
+        }
+    }
+
+    /// Sets the path to the `plugins.js` file.
+    ///
+    /// When set, `plugins.js` is verified along with the data files.
+    pub fn plugins_file_path(mut self, plugins_file_path: Option<P>) -> Self {
+        self.plugins_file_path = plugins_file_path;
+        self
+    }
+
+    /// Sets the maps processing mode.
+    pub fn maps_processing_mode(mut self, maps_processing_mode: MapsProcessingMode) -> Self {
+        self.maps_processing_mode = maps_processing_mode;
+        self
+    }
+
+    /// Sets whether to romanize text.
+    pub fn romanize(mut self, romanize: bool) -> Self {
+        self.romanize = romanize;
+        self
+    }
+
+    /// Sets whether to log processing information.
+    ///
+    /// When enabled, the verifier will log information about the files being processed.
+    pub fn logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+
+    /// Sets the game type for specialized processing.
+    pub fn game_type(mut self, game_type: Option<GameType>) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
+    /// Sets whether to trim whitespace from strings.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether to report string differences.
+    ///
+    /// Some strings are expected to change even with identity translation (e.g. trimmed or
+    /// romanized strings), so they're not reported by default.
+    pub fn strings(mut self, strings: bool) -> Self {
+        self.strings = strings;
+        self
+    }
+
+    /// Sets the names of data files, that aren't processed, without extension.
+    ///
+    /// If `None`, [`default_excluded_files`](crate::default_excluded_files) are used.
+    pub fn excluded_files(mut self, excluded_files: Option<Vec<String>>) -> Self {
+        self.excluded_files = excluded_files;
+        self
+    }
+
+    /// Sets the names of excluded data files, that are processed anyway, without extension.
+    pub fn included_files(mut self, included_files: Vec<String>) -> Self {
+        self.included_files = included_files;
+        self
+    }
+
+    /// This method reads the original files into `translation` subdirectory of the work path,
+    /// fills every translation with its original text, writes the files into `output`
+    /// subdirectory of the work path and compares every written file with its original.
+    ///
+    /// # Returns
+    ///
+    /// A [`VerificationReport`] with the differences found in every written file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rvpacker_txt_rs_lib::{types::EngineType, verify::Verifier};
+    ///
+    /// let report = Verifier::new("data", "verify", EngineType::New).verify();
+    ///
+    /// for (file, differences) in report.changed_files() {
+    ///     for difference in differences {
+    ///         println!("{file}: {difference}");
+    ///     }
+    /// }
+    /// ```
+    pub fn verify(self) -> VerificationReport {
+        let original_path: &Path = self.original_path.as_ref();
+        let translation_path: PathBuf = self.work_path.as_ref().join("translation");
+        let output_path: PathBuf = self.work_path.as_ref().join("output");
+
+        create_dir_all(&translation_path).unwrap_log();
+        create_dir_all(&output_path).unwrap_log();
+
+        let extension: &str = determine_extension(self.engine_type);
+        let system_file_path: PathBuf = original_path.join(format!("System{extension}"));
+        let scripts_file_path: PathBuf = original_path.join(format!("Scripts{extension}"));
+
+        MapReader::new(original_path, &translation_path, self.engine_type)
+            .maps_processing_mode(self.maps_processing_mode)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .game_type(self.game_type)
+            .processing_mode(ProcessingMode::Force)
+            .trim(self.trim)
+            .read();
+
+        OtherReader::new(original_path, &translation_path, self.engine_type)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .game_type(self.game_type)
+            .processing_mode(ProcessingMode::Force)
+            .trim(self.trim)
+            .excluded_files(self.excluded_files.clone())
+            .included_files(self.included_files.clone())
+            .read();
+
+        SystemReader::new(&system_file_path, &translation_path, self.engine_type)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .processing_mode(ProcessingMode::Force)
+            .trim(self.trim)
+            .read();
+
+        if !self.engine_type.is_new() && scripts_file_path.exists() {
+            ScriptReader::new(&scripts_file_path, &translation_path)
+                .romanize(self.romanize)
+                .logging(self.logging)
+                .processing_mode(ProcessingMode::Force)
+                .read();
+        }
+
+        if let Some(plugins_file_path) = &self.plugins_file_path {
+            PluginReader::new(plugins_file_path.as_ref(), &translation_path)
+                .romanize(self.romanize)
+                .logging(self.logging)
+                .processing_mode(ProcessingMode::Force)
+                .read();
+        }
+
+        for entry in read_dir(&translation_path).unwrap_log().flatten() {
+            let path: PathBuf = entry.path();
+
+            if path.extension().is_some_and(|x| x == "txt") {
+                fill_identity(&path);
+            }
+        }
+
+        MapWriter::new(original_path, &translation_path, &output_path, self.engine_type)
+            .maps_processing_mode(self.maps_processing_mode)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .game_type(self.game_type)
+            .trim(self.trim)
+            .write();
+
+        OtherWriter::new(original_path, &translation_path, &output_path, self.engine_type)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .game_type(self.game_type)
+            .trim(self.trim)
+            .excluded_files(self.excluded_files.clone())
+            .included_files(self.included_files.clone())
+            .write();
+
+        SystemWriter::new(&system_file_path, &translation_path, &output_path, self.engine_type)
+            .romanize(self.romanize)
+            .logging(self.logging)
+            .trim(self.trim)
+            .write();
+
+        if !self.engine_type.is_new() && scripts_file_path.exists() {
+            ScriptWriter::new(&scripts_file_path, &translation_path, &output_path)
+                .romanize(self.romanize)
+                .logging(self.logging)
+                .write();
+        }
+
+        if let Some(plugins_file_path) = &self.plugins_file_path {
+            PluginWriter::new(plugins_file_path.as_ref(), &translation_path, &output_path)
+                .romanize(self.romanize)
+                .logging(self.logging)
+                .write();
+        }
+
+        let mut report: VerificationReport = VerificationReport::default();
+
+        let mut written_files: Vec<(String, PathBuf)> = read_dir(&output_path)
+            .unwrap_log()
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|x| x.is_file()))
+            .map(|entry| (entry.file_name().into_string().unwrap_log(), entry.path()))
+            .collect();
+        written_files.sort_unstable();
+
+        for (filename, written_path) in written_files {
+            let differences: Vec<Difference> = if filename == "plugins.js" {
+                match &self.plugins_file_path {
+                    Some(plugins_file_path) => self.diff_plugins(plugins_file_path.as_ref(), &written_path),
+                    None => continue,
+                }
+            } else {
+                let original_file_path: PathBuf = original_path.join(&filename);
+
+                if !original_file_path.exists() {
+                    continue;
+                }
+
+                self.diff_files(&filename, &original_file_path, &written_path)
+            };
+
+            if self.logging {
+                println!("{VERIFIED_FILE_MSG} {filename}");
+            }
+
+            report.files.insert(filename, differences);
+        }
+
+        report
+    }
+
+    fn diff_files(&self, filename: &str, original_file_path: &Path, written_file_path: &Path) -> Vec<Difference> {
+        let mut differences: Vec<Difference> = Vec::new();
+
+        let (original, written): (Value, Value) = if filename.starts_with("Scripts") {
+            let mut original: Value =
+                load(&read(original_file_path).unwrap_log(), Some(StringMode::Binary), None).unwrap_log();
+            let mut written: Value =
+                load(&read(written_file_path).unwrap_log(), Some(StringMode::Binary), None).unwrap_log();
+
+            inflate_scripts(&mut original);
+            inflate_scripts(&mut written);
+
+            (original, written)
+        } else {
+            (
+                parse_rpgm_file(original_file_path, self.engine_type),
+                parse_rpgm_file(written_file_path, self.engine_type),
+            )
+        };
+
+        diff_values("", &original, &written, self.strings, &mut differences);
+
+        if differences.is_empty() && read(original_file_path).unwrap_log() != read(written_file_path).unwrap_log() {
+            differences.push(Difference {
+                path: String::new(),
+                kind: DifferenceKind::Encoding,
+                original: String::from("serialized file"),
+                written: String::from("differently serialized file"),
+            });
+        }
+
+        differences
+    }
+
+    fn diff_plugins(&self, original_file_path: &Path, written_file_path: &Path) -> Vec<Difference> {
+        let mut differences: Vec<Difference> = Vec::new();
+
+        let original_content: String = read_to_string(original_file_path).unwrap_log();
+        let written_content: String = read_to_string(written_file_path).unwrap_log();
+
+        // Whitespace between `=` and the JSON body belongs to the header, so it's compared exactly too
+        let split = |content: &str| -> (String, String, String) {
+            let (header, body) = content.split_once('=').unwrap_log();
+            let json: &str = body.trim_start();
+            let trimmed: &str = json.trim_end_matches([';', '\n']);
+            let footer: &str = &json[trimmed.len()..];
+            (
+                format!("{header}={}", &body[..body.len() - json.len()]),
+                trimmed.to_owned(),
+                footer.to_owned(),
+            )
+        };
+
+        let (original_header, original_body, original_footer) = split(&original_content);
+        let (written_header, written_body, written_footer) = split(&written_content);
+
+        if original_header != written_header {
+            differences.push(Difference {
+                path: String::from("header"),
+                kind: DifferenceKind::Header,
+                original: original_header,
+                written: written_header,
+            });
+        }
+
+        if original_footer != written_footer {
+            differences.push(Difference {
+                path: String::from("footer"),
+                kind: DifferenceKind::Header,
+                original: original_footer,
+                written: written_footer,
+            });
+        }
+
+        let original_json: Value = from_str(&original_body).unwrap_log();
+        let written_json: Value = from_str(&written_body).unwrap_log();
+
+        diff_values("", &original_json, &written_json, self.strings, &mut differences);
+        differences
+    }
+}
@@ -1,18 +1,23 @@
-#[cfg(feature = "log")]
-use crate::{eprintln, println};
 use crate::{
+    cache::{inputs_hash, WriteCache},
+    comments::is_meta_comment,
+    context::{list_location, object_id, override_map, parse_overrides},
+    determine_extension,
     functions::{
-        extract_strings, filter_maps, filter_other, get_maps_labels, get_object_data, get_other_labels,
-        get_system_labels, is_allowed_code, is_bad_code, parse_map_number, parse_rpgm_file, parse_translation,
-        process_parameter, process_variable, romanize_string, traverse_json,
-    },
-    statics::{
-        localization::{AT_POSITION_MSG, COULD_NOT_SPLIT_LINE_MSG, IN_FILE_MSG, WROTE_FILE_MSG},
-        ENCODINGS, LINES_SEPARATOR, NEW_LINE,
+        collect_replacements, extract_strings, filter_maps, filter_other, format_version, get_maps_labels,
+        get_object_data, get_objects_mut, get_other_labels, get_system_dev_labels, get_system_labels, get_untranslated,
+        is_allowed_code, is_bad_code, parse_map_number, parse_rpgm_file, parse_translation, process_parameter,
+        process_variable, resolve_excluded_files, romanize_string, system_section_comment, traverse_json, unescape,
     },
+    header::{apply_header, is_header_line, Header},
+    layout::{map_blocks, maps_header_path, read_maps},
+    observer::{resolve_observer, FileAction, Observer, SkipReason, Warning},
+    state::skip_states,
+    statics::{ENCODINGS, LINES_SEPARATOR},
     types::{
-        Code, EngineType, GameType, HashMapGx, IndexMapGx, MapsProcessingMode, OptionExt, ProcessingMode, ResultExt,
-        TrimReplace, Variable,
+        Code, EngineType, FileFilter, GameType, HashMapGx, HeaderMode, IndexMapGx, LineState, MapsLayout,
+        MapsProcessingMode, OptionExt, ProcessingMode, Replacement, ResultExt, TrimReplace, UntranslatedLine, Variable,
+        WriteReport,
     },
 };
 use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
@@ -27,7 +32,7 @@ use std::{
     fs::{read, read_dir, read_to_string, write},
     io::{Read, Write},
     mem::{take, transmute},
-    path::Path,
+    path::{Path, PathBuf},
     sync::{Arc, Mutex},
 };
 
@@ -77,6 +82,7 @@ fn write_list(
     (code_label, parameters_label): (&str, &str),
     maps_processing_mode: Option<MapsProcessingMode>,
     trim: bool,
+    overrides: Option<(&HashMapGx, &str)>,
 ) {
     let mut in_sequence: bool = false;
     let mut lines: SmallVec<[String; 4]> = SmallVec::with_capacity(4);
@@ -117,6 +123,16 @@ fn write_list(
                     joined = romanize_string(joined)
                 }
 
+                let override_map: Option<HashMapGx> = override_map(
+                    Code::Dialogue,
+                    &joined,
+                    game_type,
+                    engine_type,
+                    romanize,
+                    overrides,
+                    |prefix| format!("{prefix}/{}", item_indices[0]),
+                );
+
                 let translated: Option<String> = process_parameter(
                     Code::Dialogue,
                     &joined,
@@ -126,7 +142,7 @@ fn write_list(
                     if maps_processing_mode == Some(MapsProcessingMode::Preserve) {
                         None
                     } else {
-                        Some(map)
+                        Some(override_map.as_ref().unwrap_or(map))
                     },
                     deque.clone(),
                     true,
@@ -197,10 +213,20 @@ fn write_list(
                         if trim { trimmed } else { subparameter_string }.to_owned()
                     };
 
+                    let override_map: Option<HashMapGx> = override_map(
+                        code,
+                        &subparameter_string,
+                        game_type,
+                        engine_type,
+                        romanize,
+                        overrides,
+                        |prefix| format!("{prefix}/{it}:{i}"),
+                    );
+
                     process_parameter_write(
                         code,
                         subparameter_string,
-                        Some(map),
+                        Some(override_map.as_ref().unwrap_or(map)),
                         None,
                         game_type,
                         engine_type,
@@ -235,10 +261,20 @@ fn write_list(
                     item_indices.push(it);
                     in_sequence = true;
                 } else {
+                    let override_map: Option<HashMapGx> = override_map(
+                        code,
+                        &parameter_string,
+                        game_type,
+                        engine_type,
+                        romanize,
+                        overrides,
+                        |prefix| format!("{prefix}/{it}"),
+                    );
+
                     process_parameter_write(
                         code,
                         parameter_string,
-                        Some(map),
+                        Some(override_map.as_ref().unwrap_or(map)),
                         None,
                         game_type,
                         engine_type,
@@ -251,6 +287,26 @@ fn write_list(
     }
 }
 
+/// Translation of maps, parsed from the `maps.txt` content.
+struct MapsTranslation<'a> {
+    /// The `maps.txt` content.
+    content: &'a str,
+    /// Translated displayed names of maps.
+    names_map: HashMapGx,
+    /// Translated names of maps, by map number.
+    map_names_map: HashMap<u16, String, GxBuildHasher>,
+    /// Translated names of events, by map number.
+    event_names_map: HashMap<u16, HashMapGx, GxBuildHasher>,
+    /// Translation lines in order, when maps processing mode is PRESERVE.
+    translation_deque: Arc<Mutex<VecDeque<String>>>,
+    /// Translation, by map number when maps processing mode is SEPARATE.
+    translation_maps: HashMap<u16, HashMapGx, GxBuildHasher>,
+    /// Translation lines of every map, when maps processing mode is SEPARATE.
+    map_translations: HashMap<u16, String, GxBuildHasher>,
+    /// Location-specific translations.
+    overrides: HashMapGx,
+}
+
 /// This struct handles the process of reading translation from a `maps.txt` file and applying them
 /// to the original map files, producing new map files with the translated text.
 pub struct MapWriter<P: AsRef<Path> + Sync> {
@@ -263,6 +319,17 @@ pub struct MapWriter<P: AsRef<Path> + Sync> {
     game_type: Option<GameType>,
     engine_type: EngineType,
     trim: bool,
+    dry_run: bool,
+    skip_states: Vec<LineState>,
+    skip_stateless: bool,
+    header_mode: HeaderMode,
+    map_names: bool,
+    event_names: bool,
+    maps_layout: MapsLayout,
+    file_filter: FileFilter,
+    force: bool,
+    streaming: bool,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path> + Sync> MapWriter<P> {
@@ -297,6 +364,17 @@ impl<P: AsRef<Path> + Sync> MapWriter<P> {
             game_type: None,
             engine_type,
             trim: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            map_names: false,
+            event_names: false,
+            maps_layout: MapsLayout::Single,
+            file_filter: FileFilter::default(),
+            force: false,
+            streaming: false,
+            observer: None,
         }
     }
 
@@ -340,9 +418,163 @@ impl<P: AsRef<Path> + Sync> MapWriter<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the writer doesn't write any files, and instead returns a report with
+    /// all the replacements it would make and all untranslated lines.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that won't be written.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are left untranslated,
+    /// for example, to not ship lines, that weren't approved yet.
+    pub fn skip_states(mut self, skip_states: Vec<LineState>) -> Self {
+        self.skip_states = skip_states;
+        self
+    }
+
+    /// Sets whether to treat lines without a review state as unapproved.
+    ///
+    /// When enabled, lines without `<!-- State --><#>state` comment are left untranslated too, so
+    /// together with [`skip_states`](Self::skip_states) only lines of the other states are written.
+    pub fn skip_stateless(mut self, skip_stateless: bool) -> Self {
+        self.skip_stateless = skip_stateless;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets whether to write translated map names.
+    ///
+    /// When enabled, translation of `<!-- Map Name: name --><#>` comments is written to the
+    /// `MapInfos` file in the output directory.
+    pub fn map_names(mut self, map_names: bool) -> Self {
+        self.map_names = map_names;
+        self
+    }
+
+    /// Sets whether to write translated event names.
+    ///
+    /// When enabled, translation of `<!-- Event Name: name --><#>` comments, emitted by
+    /// [`MapReader::event_names`](crate::read::MapReader::event_names), is written to the names
+    /// of events of the map.
+    pub fn event_names(mut self, event_names: bool) -> Self {
+        self.event_names = event_names;
+        self
+    }
+
+    /// Sets the layout of the maps translation.
+    ///
+    /// Must be the same value, as in previous read.
+    ///
+    /// With `MapsLayout::PerMap` and `MapsLayout::PerMapNamed`, every map is stored in its own file in
+    /// the `maps` directory instead of the single `maps.txt` file. Use
+    /// [`convert_maps_layout`](crate::layout::convert_maps_layout) to convert existing translation.
+    pub fn maps_layout(mut self, maps_layout: MapsLayout) -> Self {
+        self.maps_layout = maps_layout;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets whether to write every file, ignoring the cache of written files.
+    ///
+    /// By default, files, which original file, translation and options didn't change since the previous
+    /// write, are skipped, if they're still present in the output directory. See [`CACHE_FILE_NAME`](crate::cache::CACHE_FILE_NAME).
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Sets whether to process maps block by block.
+    ///
+    /// When enabled, the `maps.txt` content is read block by block, and every map is written as soon as its
+    /// block is read, so memory use scales with the largest map rather than with the whole project.
+    ///
+    /// Streaming is only used in `Separate` maps processing mode, as in other modes translation of every map
+    /// depends on the whole content. Otherwise, maps are processed as usual, and
+    /// [`Warning::StreamingUnsupported`] is reported.
+    pub fn streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            maps_processing_mode: Some(self.maps_processing_mode),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &maps_header_path(self.translation_path.as_ref(), self.maps_layout),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.maps_processing_mode = settings.maps_processing_mode.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
+    /// Returns options, that affect the written files, for the cache of written files.
+    fn cache_settings(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{}|{}|{}|{:?}|{}",
+            self.engine_type as u8,
+            self.maps_processing_mode as u8,
+            self.game_type.map_or(0, |game_type| game_type as u8 + 1),
+            self.romanize,
+            self.trim,
+            self.map_names,
+            self.event_names,
+            self.skip_states,
+            self.skip_stateless,
+        )
+    }
+
     /// This method reads the `maps.txt` file, processes the original map files,
     /// applies translation, and writes the resulting files to the output directory.
     ///
+    /// # Returns
+    ///
+    /// A [`WriteReport`], if dry run is enabled. Otherwise, an empty report.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -362,172 +594,505 @@ impl<P: AsRef<Path> + Sync> MapWriter<P> {
     /// writer.write();
     /// ```
     #[inline(always)]
-    pub fn write(self) {
-        let translation: String = read_to_string(self.translation_path.as_ref().join("maps.txt")).unwrap_log();
-
-        let (names_map, translation_deque, translation_maps) = {
-            // Allocated when maps processing mode is PRESERVE.
-            let mut translation_deque: VecDeque<String> = VecDeque::new();
-            // Default map for translation from the `.txt` file.
-            let mut translation_map: HashMapGx = HashMap::default();
-            // A vec that holds translation maps. If maps processing mode is
-            // DEFAULT, only ever holds one hashmap with all the translation lines.
-            // If maps processing mode is SEPARATE, holds multiple hashmap, each
-            // respective to a single map file.
-            let mut translation_maps: HashMap<u16, HashMapGx, GxBuildHasher> = HashMap::default();
-            // Always allocated.
-            let mut names_map: HashMapGx = HashMap::default();
-
-            let mut map_number: u16 = 0;
-
-            for (i, line) in translation.split('\n').enumerate() {
-                let parts: Vec<&str> = line.split(LINES_SEPARATOR).collect();
-
-                if parts.len() >= 2 {
-                    let original: &str = parts.first().unwrap();
-                    let translation: &str = parts.into_iter().skip(1).rfind(|x| !x.is_empty()).unwrap_or("");
-
-                    if original.starts_with("<!-- In-game Displayed Name:") {
-                        let map_display_name: &str = &original[29..original.len() - 4];
-                        names_map.insert(
-                            if self.trim {
-                                map_display_name.trim_replace()
-                            } else {
-                                map_display_name.to_owned()
-                            },
-                            translation.trim_replace(),
-                        );
-                    } else if original == "<!-- Map -->" {
-                        if self.maps_processing_mode == MapsProcessingMode::Separate && i > 0 {
-                            translation_maps.insert(map_number, take(&mut translation_map));
-                        }
+    pub fn write(mut self) -> WriteReport {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
 
-                        map_number = parse_map_number(translation);
-                    } else if !line.starts_with("<!--") {
-                        #[cfg(not(debug_assertions))]
-                        if translation.is_empty() {
-                            continue;
-                        }
+        if !self.apply_header(&*observer) {
+            return WriteReport::default();
+        }
 
-                        if self.maps_processing_mode == MapsProcessingMode::Preserve {
-                            translation_deque.push_back(translation.replace(NEW_LINE, "\n").trim_replace());
-                        } else {
-                            let original_replaced = original.replace(NEW_LINE, "\n");
-                            translation_map.insert(
-                                if self.trim {
-                                    original_replaced.trim_replace()
-                                } else {
-                                    original_replaced
-                                },
-                                translation.replace(NEW_LINE, "\n").trim_replace(),
-                            );
-                        }
-                    }
-                } else {
-                    eprintln!(
-                        "{COULD_NOT_SPLIT_LINE_MSG} ({line})\n{AT_POSITION_MSG} {i}\n{IN_FILE_MSG} maps.txt",
-                        i = i + 1,
+        let replacements: Mutex<Vec<Replacement>> = Mutex::new(Vec::new());
+        let mut untranslated: Vec<UntranslatedLine> = Vec::new();
+        let mut map_names_map: HashMap<u16, String, GxBuildHasher> = HashMap::default();
+
+        // Maps consume translation lines in order when maps processing mode is PRESERVE, so they're never skipped
+        let cache: Option<WriteCache> = (!self.dry_run && self.maps_processing_mode != MapsProcessingMode::Preserve)
+            .then(|| WriteCache::load(self.output_path.as_ref(), self.force));
+        let settings: String = self.cache_settings();
+
+        if self.streaming && self.maps_processing_mode != MapsProcessingMode::Separate {
+            observer.warning(Warning::StreamingUnsupported {
+                file: String::from("maps.txt"),
+                settings: vec![String::from("maps_processing_mode")],
+            });
+        }
+
+        if self.streaming && self.maps_processing_mode == MapsProcessingMode::Separate {
+            let extension: &str = determine_extension(self.engine_type);
+
+            for (map_number, block, offset) in map_blocks(self.translation_path.as_ref(), self.maps_layout) {
+                let block: String = skip_states(block, &self.skip_states, self.skip_stateless);
+
+                if self.dry_run {
+                    untranslated.extend(get_untranslated(&block, "maps.txt").into_iter().map(|mut x| {
+                        x.line += offset;
+                        x
+                    }));
+                }
+
+                let maps_translation: MapsTranslation = self.parse_maps_translation(&block, &*observer);
+                let filename: String = format!("Map{map_number:03}{extension}");
+                let path: PathBuf = self.original_path.as_ref().join(&filename);
+
+                if path.exists() && self.file_filter.matches(&filename) {
+                    observer.files_found(1);
+                    self.write_map(
+                        &filename,
+                        &path,
+                        &maps_translation,
+                        cache.as_ref(),
+                        &settings,
+                        &replacements,
+                        &*observer,
                     );
                 }
+
+                map_names_map.extend(maps_translation.map_names_map);
+            }
+        } else {
+            let translation: String = skip_states(
+                read_maps(self.translation_path.as_ref(), self.maps_layout),
+                &self.skip_states,
+                self.skip_stateless,
+            );
+
+            if self.dry_run {
+                untranslated = get_untranslated(&translation, "maps.txt");
             }
 
-            translation_maps.entry(map_number).or_insert(translation_map);
+            let maps_translation: MapsTranslation = self.parse_maps_translation(&translation, &*observer);
+
+            let maps: Vec<(String, PathBuf)> = read_dir(&self.original_path)
+                .unwrap_log()
+                .filter_map(|entry| filter_maps(entry, self.engine_type))
+                .filter(|(filename, _)| self.file_filter.matches(filename))
+                .collect();
+            observer.files_found(maps.len());
+
+            maps.into_par_iter().for_each(|(filename, path)| {
+                self.write_map(
+                    &filename,
+                    &path,
+                    &maps_translation,
+                    cache.as_ref(),
+                    &settings,
+                    &replacements,
+                    &*observer,
+                );
+            });
 
-            (names_map, translation_deque, translation_maps)
+            map_names_map = maps_translation.map_names_map;
+        }
+
+        let name_label: &str = if self.engine_type.is_new() {
+            "name"
+        } else {
+            "__symbol__name"
         };
 
-        let translation_deque_mutex: Arc<Mutex<VecDeque<String>>> = Arc::new(Mutex::new(translation_deque));
+        let mut replacements: Vec<Replacement> = replacements.into_inner().unwrap_log();
+
+        if self.map_names && !map_names_map.is_empty() && self.file_filter.matches("MapInfos") {
+            observer.files_found(1);
+            self.write_map_names(
+                &map_names_map,
+                name_label,
+                &mut replacements,
+                cache.as_ref(),
+                &settings,
+                &*observer,
+            );
+        }
 
-        let (display_name_label, events_label, pages_label, list_label, code_label, parameters_label) =
-            get_maps_labels(self.engine_type);
+        if let Some(cache) = cache {
+            cache.save();
+        }
 
-        let maps_iter = read_dir(self.original_path)
-            .unwrap_log()
-            .filter_map(|entry| filter_maps(entry, self.engine_type));
+        replacements.sort_by(|a, b| a.file.cmp(&b.file));
+
+        WriteReport {
+            replacements,
+            untranslated,
+        }
+    }
 
-        maps_iter.par_bridge().for_each(|(filename, path)| {
-            let mut obj: Value = parse_rpgm_file(&path, self.engine_type);
+    /// Parses translation of maps from the `maps.txt` content.
+    fn parse_maps_translation<'a>(&self, translation: &'a str, observer: &dyn Observer) -> MapsTranslation<'a> {
+        // Allocated when maps processing mode is PRESERVE.
+        let mut translation_deque: VecDeque<String> = VecDeque::new();
+        // Default map for translation from the `.txt` file.
+        let mut translation_map: HashMapGx = HashMap::default();
+        // A vec that holds translation maps. If maps processing mode is
+        // DEFAULT, only ever holds one hashmap with all the translation lines.
+        // If maps processing mode is SEPARATE, holds multiple hashmap, each
+        // respective to a single map file.
+        let mut translation_maps: HashMap<u16, HashMapGx, GxBuildHasher> = HashMap::default();
+        // Always allocated.
+        let mut names_map: HashMapGx = HashMap::default();
+        // Translated names of maps and map events, by map number.
+        let mut map_names_map: HashMap<u16, String, GxBuildHasher> = HashMap::default();
+        let mut event_names_map: HashMap<u16, HashMapGx, GxBuildHasher> = HashMap::default();
+        // Translation lines of every map, hashed for the cache of written files when maps processing mode
+        // is SEPARATE. Lines of displayed names are stored with the `0` key, as they apply to all maps.
+        let mut map_translations: HashMap<u16, String, GxBuildHasher> = HashMap::default();
+
+        let mut map_number: u16 = 0;
+        let version: u8 = format_version(translation);
+
+        for (i, line) in translation.split('\n').enumerate() {
+            let parts: Vec<&str> = line.split(LINES_SEPARATOR).collect();
+
+            if parts.len() >= 2 {
+                let original: &str = parts.first().unwrap();
+                let translation: &str = parts.into_iter().skip(1).rfind(|x| !x.is_empty()).unwrap_or("");
+
+                if self.maps_processing_mode == MapsProcessingMode::Separate {
+                    let key: u16 = if original.starts_with("<!-- In-game Displayed Name:") {
+                        0
+                    } else if original == "<!-- Map -->" {
+                        parse_map_number(translation)
+                    } else {
+                        map_number
+                    };
 
-            if let Some(mut display_name) = obj[display_name_label].as_str().map(str::to_owned) {
-                if !display_name.is_empty() {
-                    if self.romanize {
-                        display_name = romanize_string(display_name)
+                    let map_translation: &mut String = map_translations.entry(key).or_default();
+                    map_translation.push_str(line);
+                    map_translation.push('\n');
+                }
+
+                if original.starts_with("<!-- In-game Displayed Name:") {
+                    let map_display_name: String = unescape(&original[29..original.len() - 4], version);
+                    names_map.insert(
+                        if self.trim {
+                            map_display_name.trim_replace()
+                        } else {
+                            map_display_name.to_owned()
+                        },
+                        translation.trim_replace(),
+                    );
+                } else if original.starts_with("<!-- Map Name: ") {
+                    if !translation.is_empty() {
+                        map_names_map.insert(map_number, unescape(translation, version).trim_replace());
+                    }
+                } else if let Some(event_name) = original
+                    .strip_prefix("<!-- Event Name: ")
+                    .and_then(|x| x.strip_suffix(" -->"))
+                {
+                    if !translation.is_empty() {
+                        event_names_map.entry(map_number).or_default().insert(
+                            unescape(event_name, version),
+                            unescape(translation, version).trim_replace(),
+                        );
+                    }
+                } else if original == "<!-- Map -->" {
+                    if self.maps_processing_mode == MapsProcessingMode::Separate && i > 0 {
+                        translation_maps.insert(map_number, take(&mut translation_map));
                     }
 
-                    if let Some(location_name) = names_map.get(&display_name) {
-                        obj[display_name_label] = Value::from(location_name);
+                    map_number = parse_map_number(translation);
+                } else if !line.starts_with("<!--") {
+                    #[cfg(not(debug_assertions))]
+                    if translation.is_empty() {
+                        continue;
+                    }
+
+                    if self.maps_processing_mode == MapsProcessingMode::Preserve {
+                        translation_deque.push_back(unescape(translation, version).trim_replace());
+                    } else {
+                        let original_replaced = unescape(original, version);
+                        translation_map.insert(
+                            if self.trim {
+                                original_replaced.trim_replace()
+                            } else {
+                                original_replaced
+                            },
+                            unescape(translation, version).trim_replace(),
+                        );
                     }
                 }
+            } else {
+                observer.warning(Warning::UnsplittableLine {
+                    file: String::from("maps.txt"),
+                    line: i + 1,
+                    content: line.to_owned(),
+                });
             }
+        }
 
-            let reserve_map: HashMapGx = HashMapGx::default();
+        translation_maps.entry(map_number).or_insert(translation_map);
 
-            let hashmap: &HashMapGx = if self.maps_processing_mode == MapsProcessingMode::Preserve {
-                &reserve_map
-            } else {
-                let hashmap: &HashMapGx = if self.maps_processing_mode == MapsProcessingMode::Separate {
-                    unsafe {
-                        let filename: &str = filename.split_once('.').unwrap_unchecked().0;
-                        let map_number: u16 = parse_map_number(filename);
-                        translation_maps.get(&map_number).unwrap_log()
-                    }
+        // Location-specific translations, read in keyed mode.
+        let overrides: HashMapGx = if self.maps_processing_mode == MapsProcessingMode::Preserve {
+            HashMapGx::default()
+        } else {
+            parse_overrides(translation, true)
+        };
+
+        MapsTranslation {
+            content: translation,
+            names_map,
+            map_names_map,
+            event_names_map,
+            translation_deque: Arc::new(Mutex::new(translation_deque)),
+            translation_maps,
+            map_translations,
+            overrides,
+        }
+    }
+
+    /// Applies translation to a single map file, and writes it to the output directory.
+    ///
+    /// In dry run, collects replacements instead.
+    #[allow(clippy::too_many_arguments)]
+    fn write_map(
+        &self,
+        filename: &str,
+        path: &Path,
+        maps_translation: &MapsTranslation,
+        cache: Option<&WriteCache>,
+        settings: &str,
+        replacements: &Mutex<Vec<Replacement>>,
+        observer: &dyn Observer,
+    ) {
+        let (display_name_label, events_label, pages_label, list_label, code_label, parameters_label) =
+            get_maps_labels(self.engine_type);
+        let name_label: &str = if self.engine_type.is_new() {
+            "name"
+        } else {
+            "__symbol__name"
+        };
+
+        let map_number: u16 = parse_map_number(filename);
+
+        let hash: Option<u64> = cache.map(|_| {
+            let (map_translation, names_translation): (&str, &str) =
+                if self.maps_processing_mode == MapsProcessingMode::Separate {
+                    (
+                        maps_translation
+                            .map_translations
+                            .get(&map_number)
+                            .map_or("", String::as_str),
+                        maps_translation.map_translations.get(&0).map_or("", String::as_str),
+                    )
                 } else {
-                    // translation_maps always have only one entry in this case
-                    unsafe { translation_maps.values().next().unwrap_unchecked() }
+                    (maps_translation.content, "")
                 };
 
-                if hashmap.is_empty() {
-                    return;
+            inputs_hash(&[
+                &read(path).unwrap_log(),
+                map_translation.as_bytes(),
+                names_translation.as_bytes(),
+                settings.as_bytes(),
+            ])
+        });
+
+        if let Some((cache, hash)) = cache.zip(hash) {
+            if cache.is_fresh(filename, hash) {
+                observer.file_skipped(filename, SkipReason::Unchanged);
+                return;
+            }
+        }
+
+        observer.file_started(filename);
+
+        let mut obj: Value = parse_rpgm_file(path, self.engine_type);
+        let original_obj: Option<Value> = self.dry_run.then(|| obj.clone());
+
+        if let Some(mut display_name) = obj[display_name_label].as_str().map(str::to_owned) {
+            if !display_name.is_empty() {
+                if self.romanize {
+                    display_name = romanize_string(display_name)
                 }
 
-                hashmap
-            };
+                if let Some(location_name) = maps_translation.names_map.get(&display_name) {
+                    obj[display_name_label] = Value::from(location_name);
+                }
+            }
+        }
+
+        let event_names: Option<&HashMapGx> = if self.event_names {
+            maps_translation.event_names_map.get(&map_number)
+        } else {
+            None
+        };
+
+        let reserve_map: HashMapGx = HashMapGx::default();
 
-            let events_arr: Box<dyn Iterator<Item = &mut Value> + Send> = if self.engine_type.is_new() {
-                // Skipping first element in array as it is null
-                Box::new(obj[events_label].as_array_mut().unwrap_log().iter_mut().skip(1))
+        let hashmap: &HashMapGx = if self.maps_processing_mode == MapsProcessingMode::Preserve {
+            &reserve_map
+        } else {
+            let hashmap: &HashMapGx = if self.maps_processing_mode == MapsProcessingMode::Separate {
+                unsafe {
+                    let filename: &str = filename.split_once('.').unwrap_unchecked().0;
+                    let map_number: u16 = parse_map_number(filename);
+                    maps_translation.translation_maps.get(&map_number).unwrap_log()
+                }
             } else {
-                Box::new(obj[events_label].as_object_mut().unwrap_log().iter_mut().map(|x| x.1))
+                // translation_maps always have only one entry in this case
+                unsafe { maps_translation.translation_maps.values().next().unwrap_unchecked() }
             };
 
-            let lines_deque_mutex: Arc<Mutex<VecDeque<String>>> = translation_deque_mutex.clone();
-            events_arr.par_bridge().for_each(|event: &mut Value| {
-                if event.is_null() {
-                    return;
+            if hashmap.is_empty() && event_names.is_none() {
+                return;
+            }
+
+            hashmap
+        };
+
+        let events_arr: Box<dyn Iterator<Item = &mut Value> + Send> = if self.engine_type.is_new() {
+            // Skipping first element in array as it is null
+            Box::new(obj[events_label].as_array_mut().unwrap_log().iter_mut().skip(1))
+        } else {
+            Box::new(obj[events_label].as_object_mut().unwrap_log().iter_mut().map(|x| x.1))
+        };
+
+        let map_file: String = format!("map{map_number}");
+
+        let lines_deque_mutex: Arc<Mutex<VecDeque<String>>> = maps_translation.translation_deque.clone();
+        events_arr.par_bridge().for_each(|event: &mut Value| {
+            if event.is_null() {
+                return;
+            }
+
+            let event_id: u64 = object_id(event, self.engine_type);
+
+            if let Some(event_names) = event_names {
+                if let Some(mut event_name) = event[name_label].as_str().map(str::to_owned) {
+                    if self.romanize {
+                        event_name = romanize_string(event_name);
+                    }
+
+                    if let Some(translated) = event_names.get(&event_name) {
+                        event[name_label] = Value::from(translated.as_str());
+                    }
                 }
+            }
 
-                let lines_deque_mutex: Arc<Mutex<VecDeque<String>>> = lines_deque_mutex.clone();
-                event[pages_label]
-                    .as_array_mut()
-                    .unwrap_log()
-                    .par_iter_mut()
-                    .for_each(move |page: &mut Value| {
-                        write_list(
-                            page[list_label].as_array_mut().unwrap_log(),
-                            self.romanize,
-                            self.game_type,
-                            self.engine_type,
-                            hashmap,
-                            Some(lines_deque_mutex.clone()),
-                            (code_label, parameters_label),
-                            Some(self.maps_processing_mode),
-                            self.trim,
-                        );
-                    });
-            });
+            let lines_deque_mutex: Arc<Mutex<VecDeque<String>>> = lines_deque_mutex.clone();
+            let overrides: &HashMapGx = &maps_translation.overrides;
+            let map_file: &str = &map_file;
+            event[pages_label]
+                .as_array_mut()
+                .unwrap_log()
+                .par_iter_mut()
+                .enumerate()
+                .for_each(move |(page_index, page): (usize, &mut Value)| {
+                    let location: String = list_location(map_file, event_id, page_index);
+
+                    write_list(
+                        page[list_label].as_array_mut().unwrap_log(),
+                        self.romanize,
+                        self.game_type,
+                        self.engine_type,
+                        hashmap,
+                        Some(lines_deque_mutex.clone()),
+                        (code_label, parameters_label),
+                        Some(self.maps_processing_mode),
+                        self.trim,
+                        (!overrides.is_empty()).then_some((overrides, location.as_str())),
+                    );
+                });
+        });
 
-            let output_data: Vec<u8> = if self.engine_type.is_new() {
-                unsafe { to_vec(&obj).unwrap_unchecked() }
+        if let Some(original_obj) = original_obj {
+            let mut file_replacements: Vec<Replacement> = Vec::new();
+            collect_replacements(filename, "", &original_obj, &obj, &mut file_replacements);
+            replacements.lock().unwrap_log().extend(file_replacements);
+            return;
+        }
+
+        let output_data: Vec<u8> = if self.engine_type.is_new() {
+            unsafe { to_vec(&obj).unwrap_unchecked() }
+        } else {
+            dump(obj, Some(""))
+        };
+
+        write(self.output_path.as_ref().join(filename), output_data).unwrap_log();
+
+        if let Some((cache, hash)) = cache.zip(hash) {
+            cache.update(filename, hash);
+        }
+
+        observer.file_finished(filename, FileAction::Wrote);
+    }
+
+    /// Writes translated map names to the `MapInfos` file.
+    ///
+    /// In dry run, collects replacements instead.
+    fn write_map_names(
+        &self,
+        map_names: &HashMap<u16, String, GxBuildHasher>,
+        name_label: &str,
+        replacements: &mut Vec<Replacement>,
+        cache: Option<&WriteCache>,
+        settings: &str,
+        observer: &dyn Observer,
+    ) {
+        let filename: String = format!("MapInfos{}", determine_extension(self.engine_type));
+        let mapinfos_path: PathBuf = self.original_path.as_ref().join(&filename);
+
+        let hash: Option<u64> = cache.map(|_| {
+            let mut map_names: Vec<(&u16, &String)> = map_names.iter().collect();
+            map_names.sort_unstable();
+
+            let map_names: String = map_names
+                .into_iter()
+                .map(|(map_number, map_name)| format!("{map_number}{LINES_SEPARATOR}{map_name}\n"))
+                .collect();
+
+            inputs_hash(&[
+                &read(&mapinfos_path).unwrap_log(),
+                map_names.as_bytes(),
+                settings.as_bytes(),
+            ])
+        });
+
+        if let Some((cache, hash)) = cache.zip(hash) {
+            if cache.is_fresh(&filename, hash) {
+                observer.file_skipped(&filename, SkipReason::Unchanged);
+                return;
+            }
+        }
+
+        observer.file_started(&filename);
+
+        let mut mapinfos: Value = parse_rpgm_file(&mapinfos_path, self.engine_type);
+        let original_mapinfos: Option<Value> = self.dry_run.then(|| mapinfos.clone());
+
+        for (&map_number, map_name) in map_names {
+            let mapinfo: Option<&mut Value> = if self.engine_type.is_new() {
+                mapinfos
+                    .as_array_mut()
+                    .and_then(|mapinfos| mapinfos.get_mut(map_number as usize))
             } else {
-                dump(obj, Some(""))
+                mapinfos
+                    .as_object_mut()
+                    .and_then(|mapinfos| mapinfos.get_mut(&format!("__integer__{map_number}")))
             };
 
-            write(self.output_path.as_ref().join(&filename), output_data).unwrap_log();
-
-            if self.logging {
-                println!("{WROTE_FILE_MSG} {filename}");
+            if let Some(mapinfo) = mapinfo.filter(|x| x.is_object()) {
+                mapinfo[name_label] = Value::from(map_name.as_str());
             }
-        });
+        }
+
+        if let Some(original_mapinfos) = original_mapinfos {
+            collect_replacements(&filename, "", &original_mapinfos, &mapinfos, replacements);
+            return;
+        }
+
+        let output_data: Vec<u8> = if self.engine_type.is_new() {
+            unsafe { to_vec(&mapinfos).unwrap_unchecked() }
+        } else {
+            dump(mapinfos, Some(""))
+        };
+
+        write(self.output_path.as_ref().join(&filename), output_data).unwrap_log();
+
+        if let Some((cache, hash)) = cache.zip(hash) {
+            cache.update(&filename, hash);
+        }
+
+        observer.file_finished(&filename, FileAction::Wrote);
     }
 }
 
@@ -543,6 +1108,15 @@ pub struct OtherWriter<P: AsRef<Path> + Sync> {
     game_type: Option<GameType>,
     engine_type: EngineType,
     trim: bool,
+    dry_run: bool,
+    skip_states: Vec<LineState>,
+    skip_stateless: bool,
+    header_mode: HeaderMode,
+    excluded_files: Option<Vec<String>>,
+    included_files: Vec<String>,
+    file_filter: FileFilter,
+    force: bool,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path> + Sync> OtherWriter<P> {
@@ -576,6 +1150,15 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
             game_type: None,
             engine_type,
             trim: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            excluded_files: None,
+            included_files: Vec::new(),
+            file_filter: FileFilter::default(),
+            force: false,
+            observer: None,
         }
     }
 
@@ -595,25 +1178,155 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
         self
     }
 
-    /// Sets the game type.
+    /// Sets the game type.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn game_type(mut self, game_type: Option<GameType>) -> Self {
+        self.game_type = game_type;
+        self
+    }
+
+    /// Sets whether to trim strings.
+    ///
+    /// Must be the same value, as in previous read.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the writer doesn't write any files, and instead returns a report with
+    /// all the replacements it would make and all untranslated lines.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that won't be written.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are left untranslated,
+    /// for example, to not ship lines, that weren't approved yet.
+    pub fn skip_states(mut self, skip_states: Vec<LineState>) -> Self {
+        self.skip_states = skip_states;
+        self
+    }
+
+    /// Sets whether to treat lines without a review state as unapproved.
+    ///
+    /// When enabled, lines without `<!-- State --><#>state` comment are left untranslated too, so
+    /// together with [`skip_states`](Self::skip_states) only lines of the other states are written.
+    pub fn skip_stateless(mut self, skip_stateless: bool) -> Self {
+        self.skip_stateless = skip_stateless;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the names of data files, that aren't processed, without extension.
+    ///
+    /// If `None`, [`default_excluded_files`](crate::default_excluded_files) are used.
+    pub fn excluded_files(mut self, excluded_files: Option<Vec<String>>) -> Self {
+        self.excluded_files = excluded_files;
+        self
+    }
+
+    /// Sets the names of excluded data files, that are processed anyway, without extension.
+    ///
+    /// For example, `vec![String::from("Tilesets")]` enables processing of tileset names.
+    pub fn included_files(mut self, included_files: Vec<String>) -> Self {
+        self.included_files = included_files;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets whether to write every file, ignoring the cache of written files.
+    ///
+    /// By default, files, which original file, translation and options didn't change since the previous
+    /// write, are skipped, if they're still present in the output directory. See [`CACHE_FILE_NAME`](crate::cache::CACHE_FILE_NAME).
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Sets the observer of progress and events.
     ///
-    /// Must be the same value, as in previous read.
-    pub fn game_type(mut self, game_type: Option<GameType>) -> Self {
-        self.game_type = game_type;
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
         self
     }
 
-    /// Sets whether to trim strings.
+    /// Validates or configures the settings according to the header mode.
     ///
-    /// Must be the same value, as in previous read.
-    pub fn trim(mut self, trim: bool) -> Self {
-        self.trim = trim;
-        self
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let excluded_files: Vec<String> =
+            resolve_excluded_files(self.excluded_files.as_deref(), &self.included_files, self.game_type);
+
+        let Some(txt_path) = read_dir(self.original_path.as_ref())
+            .ok()
+            .and_then(|mut entries| entries.find_map(|entry| filter_other(entry, self.engine_type, &excluded_files)))
+            .map(|(filename, _)| {
+                let basename: &str = filename.rsplit_once('.').unwrap_log().0;
+                self.translation_path.as_ref().join(basename.to_lowercase() + ".txt")
+            })
+        else {
+            return true;
+        };
+
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(&txt_path, self.header_mode, &mut settings, observer) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
+    /// Returns options, that affect the written files, for the cache of written files.
+    fn cache_settings(&self) -> String {
+        format!(
+            "{}|{}|{}|{}|{:?}|{}",
+            self.engine_type as u8,
+            self.game_type.map_or(0, |game_type| game_type as u8 + 1),
+            self.romanize,
+            self.trim,
+            self.skip_states,
+            self.skip_stateless,
+        )
     }
 
     /// This method reads the translation `.txt` files, processes the original data files,
     /// applies translation, and writes the resulting files to the output directory.
     ///
+    /// # Returns
+    ///
+    /// A [`WriteReport`], if dry run is enabled. Otherwise, an empty report.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -633,7 +1346,13 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
     /// writer.write();
     /// ```
     #[inline(always)]
-    pub fn write(self) {
+    pub fn write(mut self) -> WriteReport {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.apply_header(&*observer) {
+            return WriteReport::default();
+        }
+
         let (
             name_label,
             nickname_label,
@@ -660,35 +1379,76 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
             (note_label, Variable::Note),
         ]);
 
-        let other_iter = read_dir(self.original_path)
+        let excluded_files: Vec<String> =
+            resolve_excluded_files(self.excluded_files.as_deref(), &self.included_files, self.game_type);
+
+        let cache: Option<WriteCache> =
+            (!self.dry_run).then(|| WriteCache::load(self.output_path.as_ref(), self.force));
+        let settings: String = self.cache_settings();
+
+        let files: Vec<(String, PathBuf)> = read_dir(self.original_path)
             .unwrap_log()
-            .filter_map(|entry| filter_other(entry, self.engine_type, self.game_type));
+            .filter_map(|entry| filter_other(entry, self.engine_type, &excluded_files))
+            .filter(|(filename, _)| self.file_filter.matches(filename))
+            .collect();
+        observer.files_found(files.len());
+
+        let report: Mutex<WriteReport> = Mutex::new(WriteReport::default());
 
-        other_iter.par_bridge().for_each(|(filename, path)| {
+        files.into_par_iter().for_each(|(filename, path)| {
             let txt_filename: &str =
                 &(unsafe { filename.rsplit_once('.').unwrap_unchecked() }.0.to_owned() + ".txt").to_lowercase();
 
+            let translation: String = skip_states(
+                read_to_string(self.translation_path.as_ref().join(txt_filename)).unwrap_log(),
+                &self.skip_states,
+                self.skip_stateless,
+            );
+
+            if self.dry_run {
+                report
+                    .lock()
+                    .unwrap_log()
+                    .untranslated
+                    .extend(get_untranslated(&translation, txt_filename));
+            }
+
             let translation_map: HashMapGx = HashMap::from_iter(parse_translation(
-                &read_to_string(self.translation_path.as_ref().join(txt_filename)).unwrap_log(),
+                &translation,
                 txt_filename,
                 true,
                 self.trim,
+                &*observer,
             ));
+            // Location-specific translations, read in keyed mode.
+            let overrides: HashMapGx = parse_overrides(&translation, true);
 
             if translation_map.is_empty() {
                 return;
             }
 
+            let hash: Option<u64> = cache
+                .as_ref()
+                .map(|_| inputs_hash(&[&read(&path).unwrap_log(), translation.as_bytes(), settings.as_bytes()]));
+
+            if let Some((cache, hash)) = cache.as_ref().zip(hash) {
+                if cache.is_fresh(&filename, hash) {
+                    observer.file_skipped(&filename, SkipReason::Unchanged);
+                    return;
+                }
+            }
+
+            observer.file_started(&filename);
+
             let mut obj_arr: Value = parse_rpgm_file(&path, self.engine_type);
+            let original_obj_arr: Option<Value> = self.dry_run.then(|| obj_arr.clone());
 
             // Other files except CommonEvents and Troops have the structure that consists
             // of name, nickname, description and note
             if !filename.starts_with("Co") && !filename.starts_with("Tr") {
-                obj_arr
-                    .as_array_mut()
+                get_objects_mut(&mut obj_arr)
                     .unwrap_log()
-                    .par_iter_mut()
-                    .skip(1) // Skipping first element in array as it is null
+                    .into_par_iter()
                     .for_each(|obj: &mut Value| {
                         for (variable_label, variable_type) in variable_tuples.into_iter() {
                             let value: Option<&Value> = obj.get(variable_label);
@@ -767,6 +1527,8 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
                             1
                         };
 
+                        let object_id: u64 = object_id(obj, self.engine_type);
+
                         for i in 0..pages_length {
                             // If element has pages, then we'll iterate over them
                             // Otherwise we'll just iterate over the list
@@ -777,6 +1539,9 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
                             };
 
                             if let Some(list) = list.as_array_mut() {
+                                let location: String =
+                                    list_location(txt_filename.trim_end_matches(".txt"), object_id, i);
+
                                 write_list(
                                     list,
                                     self.romanize,
@@ -787,12 +1552,20 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
                                     (code_label, parameters_label),
                                     None,
                                     self.trim,
+                                    (!overrides.is_empty()).then_some((&overrides, location.as_str())),
                                 );
                             }
                         }
                     });
             }
 
+            if let Some(original_obj_arr) = original_obj_arr {
+                let mut file_replacements: Vec<Replacement> = Vec::new();
+                collect_replacements(&filename, "", &original_obj_arr, &obj_arr, &mut file_replacements);
+                report.lock().unwrap_log().replacements.extend(file_replacements);
+                return;
+            }
+
             let output_data: Vec<u8> = if self.engine_type.is_new() {
                 unsafe { to_vec(&obj_arr).unwrap_unchecked() }
             } else {
@@ -801,11 +1574,76 @@ impl<P: AsRef<Path> + Sync> OtherWriter<P> {
 
             write(self.output_path.as_ref().join(&filename), output_data).unwrap_log();
 
-            if self.logging {
-                println!("{WROTE_FILE_MSG} {filename}");
+            if let Some((cache, hash)) = cache.as_ref().zip(hash) {
+                cache.update(&filename, hash);
             }
+
+            observer.file_finished(&filename, FileAction::Wrote);
         });
+
+        if let Some(cache) = cache {
+            cache.save();
+        }
+
+        let mut report: WriteReport = report.into_inner().unwrap_log();
+        report.replacements.sort_by(|a, b| a.file.cmp(&b.file));
+        report.untranslated.sort_by(|a, b| a.file.cmp(&b.file));
+        report
+    }
+}
+
+/// Translation of `System` sections, by section comments.
+type SectionMaps = HashMap<String, HashMapGx, GxBuildHasher>;
+
+/// Parses translation of every section of the `system.txt` content.
+///
+/// Sections are started by comments like `<!-- Elements -->`, and are used to tell identical
+/// strings of different sections apart.
+fn parse_system_sections(translation: &str, trim: bool) -> SectionMaps {
+    let version: u8 = format_version(translation);
+
+    let mut section_maps: SectionMaps = HashMap::default();
+    let mut section: &str = "";
+
+    for line in translation.split('\n') {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if original.starts_with("<!--") {
+            if !is_header_line(line) && !is_meta_comment(original) {
+                section = original;
+            }
+
+            continue;
+        }
+
+        let translation: &str = translation
+            .split(LINES_SEPARATOR)
+            .filter(|x| !x.is_empty())
+            .last()
+            .unwrap_or("");
+
+        #[cfg(not(debug_assertions))]
+        if translation.is_empty() {
+            continue;
+        }
+
+        let (mut original, mut translation): (String, String) =
+            (unescape(original, version), unescape(translation, version));
+
+        if trim {
+            original = original.trim_replace();
+            translation = translation.trim_replace();
+        }
+
+        section_maps
+            .entry(section.to_owned())
+            .or_default()
+            .insert(original, translation);
     }
+
+    section_maps
 }
 
 /// This struct handles the process of reading translation from the `system.txt` file and applying them
@@ -818,6 +1656,13 @@ pub struct SystemWriter<P: AsRef<Path>> {
     logging: bool,
     engine_type: EngineType,
     trim: bool,
+    dry_run: bool,
+    skip_states: Vec<LineState>,
+    skip_stateless: bool,
+    header_mode: HeaderMode,
+    dev_names: bool,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> SystemWriter<P> {
@@ -850,6 +1695,13 @@ impl<P: AsRef<Path>> SystemWriter<P> {
             logging: false,
             engine_type,
             trim: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            dev_names: false,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -877,9 +1729,102 @@ impl<P: AsRef<Path>> SystemWriter<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the writer doesn't write any files, and instead returns a report with
+    /// all the replacements it would make and all untranslated lines.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that won't be written.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are left untranslated,
+    /// for example, to not ship lines, that weren't approved yet.
+    pub fn skip_states(mut self, skip_states: Vec<LineState>) -> Self {
+        self.skip_states = skip_states;
+        self
+    }
+
+    /// Sets whether to treat lines without a review state as unapproved.
+    ///
+    /// When enabled, lines without `<!-- State --><#>state` comment are left untranslated too, so
+    /// together with [`skip_states`](Self::skip_states) only lines of the other states are written.
+    pub fn skip_stateless(mut self, skip_stateless: bool) -> Self {
+        self.skip_stateless = skip_stateless;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets whether to write names of switches and variables.
+    ///
+    /// When enabled, translation from the `system_dev.txt` file, produced by
+    /// [`SystemReader::dev_names`](crate::read::SystemReader::dev_names), is written to names of
+    /// switches and variables. Does nothing, if the file doesn't exist.
+    pub fn dev_names(mut self, dev_names: bool) -> Self {
+        self.dev_names = dev_names;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            engine_type: Some(self.engine_type),
+            romanize: Some(self.romanize),
+            trim: Some(self.trim),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("system.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.engine_type = settings.engine_type.unwrap_log();
+        self.romanize = settings.romanize.unwrap_log();
+        self.trim = settings.trim.unwrap_log();
+
+        true
+    }
+
     /// This method reads the `system.txt` file, processes the original `System` file,
     /// applies translation, and writes the resulting file to the output directory.
     ///
+    /// # Returns
+    ///
+    /// A [`WriteReport`], if dry run is enabled. Otherwise, an empty report.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -898,21 +1843,83 @@ impl<P: AsRef<Path>> SystemWriter<P> {
     /// writer.write();
     /// ```
     #[inline(always)]
-    pub fn write(self) {
-        let (translation_map, game_title): (HashMapGx, String) = {
-            let translation: String = read_to_string(self.translation_path.as_ref().join("system.txt")).unwrap_log();
-            let game_title: String = translation[translation.rfind(LINES_SEPARATOR).unwrap_log() + 3..].to_owned();
+    pub fn write(mut self) -> WriteReport {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("System") || !self.apply_header(&*observer) {
+            return WriteReport::default();
+        }
+
+        observer.files_found(1);
+        observer.file_started(
+            &self
+                .system_file_path
+                .as_ref()
+                .file_name()
+                .unwrap_log()
+                .to_string_lossy(),
+        );
+
+        let mut report: WriteReport = WriteReport::default();
+
+        let (translation_map, section_maps, game_title): (HashMapGx, SectionMaps, String) = {
+            let translation: String = skip_states(
+                read_to_string(self.translation_path.as_ref().join("system.txt")).unwrap_log(),
+                &self.skip_states,
+                self.skip_stateless,
+            );
+            let game_title: String = unescape(
+                &translation[translation.rfind(LINES_SEPARATOR).unwrap_log() + 3..],
+                format_version(&translation),
+            );
+
+            if self.dry_run {
+                report.untranslated = get_untranslated(&translation, "system.txt");
+            }
+
             (
-                HashMap::from_iter(parse_translation(&translation, "system.txt", true, self.trim)),
+                HashMap::from_iter(parse_translation(
+                    &translation,
+                    "system.txt",
+                    true,
+                    self.trim,
+                    &*observer,
+                )),
+                parse_system_sections(&translation, self.trim),
                 game_title,
             )
         };
 
-        if translation_map.is_empty() {
-            return;
+        let dev_txt_path: PathBuf = self.translation_path.as_ref().join("system_dev.txt");
+        let dev_translation_map: HashMapGx = if self.dev_names && dev_txt_path.exists() {
+            let translation: String = skip_states(
+                read_to_string(&dev_txt_path).unwrap_log(),
+                &self.skip_states,
+                self.skip_stateless,
+            );
+
+            if self.dry_run {
+                report
+                    .untranslated
+                    .extend(get_untranslated(&translation, "system_dev.txt"));
+            }
+
+            HashMap::from_iter(parse_translation(
+                &translation,
+                "system_dev.txt",
+                true,
+                self.trim,
+                &*observer,
+            ))
+        } else {
+            HashMap::default()
+        };
+
+        if translation_map.is_empty() && dev_translation_map.is_empty() {
+            return report;
         }
 
-        let replace_value = |value: &mut Value| {
+        let replace_value_from = |translation_map: &HashMapGx, section: Option<&HashMapGx>, value: &mut Value| {
             let mut buf: Vec<u8> = Vec::new();
             let str: &str = value.as_str().unwrap_or_else(|| {
                 if let Some(obj) = value.as_object() {
@@ -932,8 +1939,11 @@ impl<P: AsRef<Path>> SystemWriter<P> {
                     string = romanize_string(string);
                 }
 
-
-                if let Some(translated) = translation_map.get(&string) {
+                // Translation of the section takes precedence over the translation of identical strings
+                if let Some(translated) = section
+                    .and_then(|section| section.get(&string))
+                    .or_else(|| translation_map.get(&string))
+                {
                     *value = if self.engine_type.is_new() {
                         Value::from(translated)
                     } else {
@@ -942,21 +1952,29 @@ impl<P: AsRef<Path>> SystemWriter<P> {
                 }
             }
         };
+        let replace_value = |section: &str, value: &mut Value| {
+            replace_value_from(
+                &translation_map,
+                section_maps.get(&system_section_comment(section)),
+                value,
+            )
+        };
 
         let (armor_types_label, elements_label, skill_types_label, terms_label, weapon_types_label, game_title_label) =
             get_system_labels(self.engine_type);
 
         let mut obj: Value = parse_rpgm_file(self.system_file_path.as_ref(), self.engine_type);
-
-        for label in [
-            armor_types_label,
-            elements_label,
-            skill_types_label,
-            weapon_types_label,
-            "equipTypes",
+        let original_obj: Option<Value> = self.dry_run.then(|| obj.clone());
+
+        for (label, section) in [
+            (armor_types_label, "Armor Types"),
+            (elements_label, "Elements"),
+            (skill_types_label, "Skill Types"),
+            (weapon_types_label, "Weapon Types"),
+            ("equipTypes", "Equip Types"),
         ] {
             if let Some(arr) = obj[label].as_array_mut() {
-                arr.iter_mut().for_each(replace_value);
+                arr.iter_mut().for_each(|value| replace_value(section, value));
             }
         }
 
@@ -969,11 +1987,13 @@ impl<P: AsRef<Path>> SystemWriter<P> {
                     return;
                 }
 
+                let section: String = format!("Terms: {}", key.trim_start_matches("__symbol__"));
+
                 if key != "messages" {
                     if let Some(arr) = value.as_array_mut() {
-                        arr.par_iter_mut().for_each(replace_value);
+                        arr.par_iter_mut().for_each(|value| replace_value(&section, value));
                     } else if (value.is_object() && value["__type"].as_str() == Some("bytes")) || value.is_str() {
-                        replace_value(value)
+                        replace_value(&section, value)
                     }
                 } else {
                     if !value.is_object() {
@@ -984,31 +2004,53 @@ impl<P: AsRef<Path>> SystemWriter<P> {
                         .as_object_mut()
                         .unwrap_log()
                         .iter_mut()
-                        .for_each(|(_, value)| replace_value(value));
+                        .for_each(|(message_key, value)| replace_value(&format!("{section}.{message_key}"), value));
                 }
             });
 
         if !self.engine_type.is_new() {
-            replace_value(&mut obj["__symbol__currency_unit"]);
+            replace_value("Currency Unit", &mut obj["__symbol__currency_unit"]);
         }
 
         if !game_title.is_empty() {
             obj[game_title_label] = Value::from(&game_title);
         }
 
+        if !dev_translation_map.is_empty() {
+            let (switches_label, variables_label) = get_system_dev_labels(self.engine_type);
+
+            for label in [switches_label, variables_label] {
+                if let Some(arr) = obj[label].as_array_mut() {
+                    arr.iter_mut()
+                        .for_each(|value| replace_value_from(&dev_translation_map, None, value));
+                }
+            }
+        }
+
+        let filename: &OsStr = unsafe { self.system_file_path.as_ref().file_name().unwrap_unchecked() };
+
+        if let Some(original_obj) = original_obj {
+            collect_replacements(
+                &filename.to_string_lossy(),
+                "",
+                &original_obj,
+                &obj,
+                &mut report.replacements,
+            );
+            return report;
+        }
+
         let output_data: Vec<u8> = if self.engine_type.is_new() {
             unsafe { to_vec(&obj).unwrap_unchecked() }
         } else {
             dump(obj, Some(""))
         };
 
-        let filename: &OsStr = unsafe { self.system_file_path.as_ref().file_name().unwrap_unchecked() };
-
         write(self.output_path.as_ref().join(filename), output_data).unwrap_log();
 
-        if self.logging {
-            println!("{WROTE_FILE_MSG} {filename:?}");
-        }
+        observer.file_finished(&filename.to_string_lossy(), FileAction::Wrote);
+
+        report
     }
 }
 
@@ -1020,6 +2062,12 @@ pub struct PluginWriter<P: AsRef<Path>> {
     output_path: P,
     logging: bool,
     romanize: bool,
+    dry_run: bool,
+    skip_states: Vec<LineState>,
+    skip_stateless: bool,
+    header_mode: HeaderMode,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> PluginWriter<P> {
@@ -1048,6 +2096,12 @@ impl<P: AsRef<Path>> PluginWriter<P> {
             output_path,
             logging: false,
             romanize: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1067,9 +2121,88 @@ impl<P: AsRef<Path>> PluginWriter<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the writer doesn't write any files, and instead returns a report with
+    /// all the replacements it would make and all untranslated lines.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that won't be written.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are left untranslated,
+    /// for example, to not ship lines, that weren't approved yet.
+    pub fn skip_states(mut self, skip_states: Vec<LineState>) -> Self {
+        self.skip_states = skip_states;
+        self
+    }
+
+    /// Sets whether to treat lines without a review state as unapproved.
+    ///
+    /// When enabled, lines without `<!-- State --><#>state` comment are left untranslated too, so
+    /// together with [`skip_states`](Self::skip_states) only lines of the other states are written.
+    pub fn skip_stateless(mut self, skip_stateless: bool) -> Self {
+        self.skip_stateless = skip_stateless;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            romanize: Some(self.romanize),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("plugins.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.romanize = settings.romanize.unwrap_log();
+
+        true
+    }
+
     /// This method reads the `plugins.txt` file, processes the original `plugins.js` file,
     /// applies translation, and writes the resulting file to the output directory.
     ///
+    /// # Returns
+    ///
+    /// A [`WriteReport`], if dry run is enabled. Otherwise, an empty report.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1086,13 +2219,30 @@ impl<P: AsRef<Path>> PluginWriter<P> {
     /// writer.write();
     /// ```
     #[inline(always)]
-    pub fn write(self) {
-        let mut translation_map: IndexMapGx = IndexMapGx::from_iter(parse_translation(
-            &read_to_string(self.translation_path.as_ref().join("plugins.txt")).unwrap_log(),
-            "plugins.txt",
-            true,
-            false,
-        ));
+    pub fn write(mut self) -> WriteReport {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("plugins") || !self.apply_header(&*observer) {
+            return WriteReport::default();
+        }
+
+        observer.files_found(1);
+        observer.file_started("plugins.js");
+
+        let mut report: WriteReport = WriteReport::default();
+
+        let translation: String = skip_states(
+            read_to_string(self.translation_path.as_ref().join("plugins.txt")).unwrap_log(),
+            &self.skip_states,
+            self.skip_stateless,
+        );
+
+        if self.dry_run {
+            report.untranslated = get_untranslated(&translation, "plugins.txt");
+        }
+
+        let mut translation_map: IndexMapGx =
+            IndexMapGx::from_iter(parse_translation(&translation, "plugins.txt", true, false, &*observer));
 
         let translation_set: HashSet<String, GxBuildHasher> =
             HashSet::from_iter(translation_map.iter().map(|(k, _)| k.to_owned()));
@@ -1106,6 +2256,7 @@ impl<P: AsRef<Path>> PluginWriter<P> {
             .trim_end_matches([';', '\n']);
 
         let mut plugins_json: Value = from_str(plugins_object).unwrap_log();
+        let original_plugins_json: Option<Value> = self.dry_run.then(|| plugins_json.clone());
 
         traverse_json(
             None,
@@ -1119,15 +2270,26 @@ impl<P: AsRef<Path>> PluginWriter<P> {
             None,
         );
 
+        if let Some(original_plugins_json) = original_plugins_json {
+            collect_replacements(
+                "plugins.js",
+                "",
+                &original_plugins_json,
+                &plugins_json,
+                &mut report.replacements,
+            );
+            return report;
+        }
+
         write(
             self.output_path.as_ref().join("plugins.js"),
             String::from("var $plugins =\n") + unsafe { &to_string(&plugins_json).unwrap_unchecked() },
         )
         .unwrap_log();
 
-        if self.logging {
-            println!("{WROTE_FILE_MSG} plugins.js");
-        }
+        observer.file_finished("plugins.js", FileAction::Wrote);
+
+        report
     }
 }
 
@@ -1139,6 +2301,12 @@ pub struct ScriptWriter<P: AsRef<Path>> {
     output_path: P,
     romanize: bool,
     logging: bool,
+    dry_run: bool,
+    skip_states: Vec<LineState>,
+    skip_stateless: bool,
+    header_mode: HeaderMode,
+    file_filter: FileFilter,
+    observer: Option<Arc<dyn Observer>>,
 }
 
 impl<P: AsRef<Path>> ScriptWriter<P> {
@@ -1169,6 +2337,12 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
             output_path,
             romanize: false,
             logging: false,
+            dry_run: false,
+            skip_states: Vec::new(),
+            skip_stateless: false,
+            header_mode: HeaderMode::Validate,
+            file_filter: FileFilter::default(),
+            observer: None,
         }
     }
 
@@ -1188,9 +2362,88 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
         self
     }
 
+    /// Sets whether to perform a dry run.
+    ///
+    /// When enabled, the writer doesn't write any files, and instead returns a report with
+    /// all the replacements it would make and all untranslated lines.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets the review states of lines, that won't be written.
+    ///
+    /// Lines with `<!-- State --><#>state` comment of one of these states are left untranslated,
+    /// for example, to not ship lines, that weren't approved yet.
+    pub fn skip_states(mut self, skip_states: Vec<LineState>) -> Self {
+        self.skip_states = skip_states;
+        self
+    }
+
+    /// Sets whether to treat lines without a review state as unapproved.
+    ///
+    /// When enabled, lines without `<!-- State --><#>state` comment are left untranslated too, so
+    /// together with [`skip_states`](Self::skip_states) only lines of the other states are written.
+    pub fn skip_stateless(mut self, skip_stateless: bool) -> Self {
+        self.skip_stateless = skip_stateless;
+        self
+    }
+
+    /// Sets how to treat the header of `.txt` files.
+    ///
+    /// With `HeaderMode::Validate`, files are skipped if the settings don't match their header.
+    /// With `HeaderMode::Configure`, the settings, recorded in the header, are used instead of the passed ones.
+    pub fn header_mode(mut self, header_mode: HeaderMode) -> Self {
+        self.header_mode = header_mode;
+        self
+    }
+
+    /// Sets the filter of processed files.
+    ///
+    /// Only files, that match the filter, are processed. See [`FileFilter`] for the pattern syntax.
+    pub fn file_filter(mut self, file_filter: FileFilter) -> Self {
+        self.file_filter = file_filter;
+        self
+    }
+
+    /// Sets the observer of progress and events.
+    ///
+    /// By default, events are logged with [`LogObserver`](crate::observer::LogObserver).
+    pub fn observer(mut self, observer: Arc<dyn Observer>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Validates or configures the settings according to the header mode.
+    ///
+    /// Returns `false` if the files should be skipped.
+    fn apply_header(&mut self, observer: &dyn Observer) -> bool {
+        let mut settings: Header = Header {
+            romanize: Some(self.romanize),
+            ..Header::default()
+        };
+
+        if !apply_header(
+            &self.translation_path.as_ref().join("scripts.txt"),
+            self.header_mode,
+            &mut settings,
+            observer,
+        ) {
+            return false;
+        }
+
+        self.romanize = settings.romanize.unwrap_log();
+
+        true
+    }
+
     /// This method reads the `scripts.txt` file, processes the original `Scripts` file,
     /// applies translation, and writes the resulting file to the output directory.
     ///
+    /// # Returns
+    ///
+    /// A [`WriteReport`], if dry run is enabled. Otherwise, an empty report.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1208,18 +2461,45 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
     /// writer.write();
     /// ```
     #[inline(always)]
-    pub fn write(self) {
-        let translation_map: HashMapGx = HashMapGx::from_iter(parse_translation(
-            &read_to_string(self.translation_path.as_ref().join("scripts.txt")).unwrap_log(),
-            "scripts.txt",
-            true,
-            false,
-        ));
+    pub fn write(mut self) -> WriteReport {
+        let observer: Arc<dyn Observer> = resolve_observer(&self.observer, self.logging);
+
+        if !self.file_filter.matches("Scripts") || !self.apply_header(&*observer) {
+            return WriteReport::default();
+        }
+
+        observer.files_found(1);
+        observer.file_started(
+            &self
+                .scripts_file_path
+                .as_ref()
+                .file_name()
+                .unwrap_log()
+                .to_string_lossy(),
+        );
+
+        let mut report: WriteReport = WriteReport::default();
+
+        let translation: String = skip_states(
+            read_to_string(self.translation_path.as_ref().join("scripts.txt")).unwrap_log(),
+            &self.skip_states,
+            self.skip_stateless,
+        );
+
+        if self.dry_run {
+            report.untranslated = get_untranslated(&translation, "scripts.txt");
+        }
+
+        let translation_map: HashMapGx =
+            HashMapGx::from_iter(parse_translation(&translation, "scripts.txt", true, false, &*observer));
 
         if translation_map.is_empty() {
-            return;
+            return report;
         }
 
+        let extension = self.scripts_file_path.as_ref().extension().unwrap().to_str().unwrap();
+        let filename: String = String::from("Scripts.") + extension;
+
         let mut script_entries: Value = load(
             &read(&self.scripts_file_path).unwrap_log(),
             Some(StringMode::Binary),
@@ -1231,7 +2511,8 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
             .as_array_mut()
             .unwrap_log()
             .iter_mut()
-            .for_each(|script: &mut Value| {
+            .enumerate()
+            .for_each(|(i, script): (usize, &mut Value)| {
                 let data: Vec<u8> = from_value(&script.as_array().unwrap_log()[2]["data"]).unwrap_log();
 
                 let mut inflated: Vec<u8> = Vec::new();
@@ -1249,6 +2530,7 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
                 }
 
                 let (extracted_strings, ranges) = extract_strings(&code, true);
+                let mut script_replacements: Vec<Replacement> = Vec::new();
 
                 for (mut extracted, range) in extracted_strings.into_iter().zip(ranges).rev() {
                     if extracted.trim().is_empty() {
@@ -1260,10 +2542,27 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
                     }
 
                     if let Some(translated) = translation_map.get(&extracted) {
+                        if self.dry_run {
+                            script_replacements.push(Replacement {
+                                file: filename.clone(),
+                                location: format!("[{i}][2]:{}..{}", range.start, range.end),
+                                original: extracted,
+                                translation: translated.to_owned(),
+                            });
+                            continue;
+                        }
+
                         code.replace_range(range, translated);
                     }
                 }
 
+                if self.dry_run {
+                    // Strings are processed in reverse, so ranges stay valid after replacement
+                    script_replacements.reverse();
+                    report.replacements.extend(script_replacements);
+                    return;
+                }
+
                 let mut buf: Vec<u8> = Vec::new();
 
                 ZlibEncoder::new(&mut buf, Compression::new(6))
@@ -1275,16 +2574,14 @@ impl<P: AsRef<Path>> ScriptWriter<P> {
                 };
             });
 
-        let extension = self.scripts_file_path.as_ref().extension().unwrap().to_str().unwrap();
+        if self.dry_run {
+            return report;
+        }
 
-        write(
-            self.output_path.as_ref().join(String::from("Scripts.") + extension),
-            dump(script_entries, None),
-        )
-        .unwrap_log();
+        write(self.output_path.as_ref().join(&filename), dump(script_entries, None)).unwrap_log();
 
-        if self.logging {
-            println!("{WROTE_FILE_MSG} Scripts.{}", extension);
-        }
+        observer.file_finished(&filename, FileAction::Wrote);
+
+        report
     }
 }
@@ -0,0 +1,414 @@
+use rvpacker_txt_rs_lib::{
+    read::MapReader,
+    types::{EngineType, MapsProcessingMode, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{copy, create_dir_all, read_dir, read_to_string, remove_dir_all},
+    path::{Path, PathBuf},
+};
+
+#[test]
+fn blocks_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let map_data_path = game_path.join("data_blocks");
+    let translation_path = game_path.join("translation_blocks");
+    let engine_type = EngineType::New;
+
+    let mut map_numbers: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .map(|name| name[3..].split_once('.').unwrap().0.trim_start_matches('0').to_owned())
+        .collect();
+    map_numbers.sort_by_key(|number| number.parse::<u16>().unwrap());
+
+    for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+        // Lines of every map, read from the data directory, without format and header comments
+        let read_blocks = |data_path: &Path| -> Vec<(String, Vec<String>)> {
+            if translation_path.exists() {
+                remove_dir_all(&translation_path).unwrap();
+            }
+
+            create_dir_all(&translation_path).unwrap();
+
+            MapReader::new(data_path, &translation_path, engine_type)
+                .processing_mode(ProcessingMode::Force)
+                .maps_processing_mode(maps_processing_mode)
+                .read();
+
+            let mut blocks: Vec<(String, Vec<String>)> = Vec::new();
+
+            for line in read_to_string(translation_path.join("maps.txt")).unwrap().split('\n') {
+                if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+                    blocks.push((number.to_owned(), Vec::new()));
+                } else if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line.to_owned());
+                }
+            }
+
+            blocks
+        };
+
+        let blocks = read_blocks(&data_path);
+        assert_eq!(
+            blocks.iter().map(|(number, _)| number.clone()).collect::<Vec<_>>(),
+            map_numbers
+        );
+
+        // Every map block holds lines of its own map, read from the data with only that map
+        for (number, lines) in blocks {
+            if map_data_path.exists() {
+                remove_dir_all(&map_data_path).unwrap();
+            }
+
+            create_dir_all(&map_data_path).unwrap();
+
+            for entry in read_dir(&data_path).unwrap().flatten() {
+                let name: String = entry.file_name().into_string().unwrap();
+
+                if name.starts_with("MapInfos.") || name.starts_with(&format!("Map{number:0>3}.")) {
+                    copy(entry.path(), map_data_path.join(name)).unwrap();
+                }
+            }
+
+            let alone = read_blocks(&map_data_path);
+            assert_eq!(alone.len(), 1);
+            assert_eq!(alone[0].0, number);
+
+            if maps_processing_mode == MapsProcessingMode::Separate {
+                assert_eq!(alone[0].1, lines);
+            } else {
+                // Lines, that are present in previous maps, aren't repeated
+                assert!(lines.iter().all(|line| alone[0].1.contains(line)));
+            }
+        }
+    }
+
+    remove_dir_all(&map_data_path).unwrap();
+}
+
+#[test]
+fn blocks_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let map_data_path = game_path.join("data_blocks");
+    let translation_path = game_path.join("translation_blocks");
+    let engine_type = EngineType::New;
+
+    let mut map_numbers: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .map(|name| name[3..].split_once('.').unwrap().0.trim_start_matches('0').to_owned())
+        .collect();
+    map_numbers.sort_by_key(|number| number.parse::<u16>().unwrap());
+
+    for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+        // Lines of every map, read from the data directory, without format and header comments
+        let read_blocks = |data_path: &Path| -> Vec<(String, Vec<String>)> {
+            if translation_path.exists() {
+                remove_dir_all(&translation_path).unwrap();
+            }
+
+            create_dir_all(&translation_path).unwrap();
+
+            MapReader::new(data_path, &translation_path, engine_type)
+                .processing_mode(ProcessingMode::Force)
+                .maps_processing_mode(maps_processing_mode)
+                .read();
+
+            let mut blocks: Vec<(String, Vec<String>)> = Vec::new();
+
+            for line in read_to_string(translation_path.join("maps.txt")).unwrap().split('\n') {
+                if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+                    blocks.push((number.to_owned(), Vec::new()));
+                } else if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line.to_owned());
+                }
+            }
+
+            blocks
+        };
+
+        let blocks = read_blocks(&data_path);
+        assert_eq!(
+            blocks.iter().map(|(number, _)| number.clone()).collect::<Vec<_>>(),
+            map_numbers
+        );
+
+        // Every map block holds lines of its own map, read from the data with only that map
+        for (number, lines) in blocks {
+            if map_data_path.exists() {
+                remove_dir_all(&map_data_path).unwrap();
+            }
+
+            create_dir_all(&map_data_path).unwrap();
+
+            for entry in read_dir(&data_path).unwrap().flatten() {
+                let name: String = entry.file_name().into_string().unwrap();
+
+                if name.starts_with("MapInfos.") || name.starts_with(&format!("Map{number:0>3}.")) {
+                    copy(entry.path(), map_data_path.join(name)).unwrap();
+                }
+            }
+
+            let alone = read_blocks(&map_data_path);
+            assert_eq!(alone.len(), 1);
+            assert_eq!(alone[0].0, number);
+
+            if maps_processing_mode == MapsProcessingMode::Separate {
+                assert_eq!(alone[0].1, lines);
+            } else {
+                // Lines, that are present in previous maps, aren't repeated
+                assert!(lines.iter().all(|line| alone[0].1.contains(line)));
+            }
+        }
+    }
+
+    remove_dir_all(&map_data_path).unwrap();
+}
+
+#[test]
+fn blocks_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let map_data_path = game_path.join("data_blocks");
+    let translation_path = game_path.join("translation_blocks");
+    let engine_type = EngineType::VXAce;
+
+    let mut map_numbers: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .map(|name| name[3..].split_once('.').unwrap().0.trim_start_matches('0').to_owned())
+        .collect();
+    map_numbers.sort_by_key(|number| number.parse::<u16>().unwrap());
+
+    for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+        // Lines of every map, read from the data directory, without format and header comments
+        let read_blocks = |data_path: &Path| -> Vec<(String, Vec<String>)> {
+            if translation_path.exists() {
+                remove_dir_all(&translation_path).unwrap();
+            }
+
+            create_dir_all(&translation_path).unwrap();
+
+            MapReader::new(data_path, &translation_path, engine_type)
+                .processing_mode(ProcessingMode::Force)
+                .maps_processing_mode(maps_processing_mode)
+                .read();
+
+            let mut blocks: Vec<(String, Vec<String>)> = Vec::new();
+
+            for line in read_to_string(translation_path.join("maps.txt")).unwrap().split('\n') {
+                if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+                    blocks.push((number.to_owned(), Vec::new()));
+                } else if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line.to_owned());
+                }
+            }
+
+            blocks
+        };
+
+        let blocks = read_blocks(&data_path);
+        assert_eq!(
+            blocks.iter().map(|(number, _)| number.clone()).collect::<Vec<_>>(),
+            map_numbers
+        );
+
+        // Every map block holds lines of its own map, read from the data with only that map
+        for (number, lines) in blocks {
+            if map_data_path.exists() {
+                remove_dir_all(&map_data_path).unwrap();
+            }
+
+            create_dir_all(&map_data_path).unwrap();
+
+            for entry in read_dir(&data_path).unwrap().flatten() {
+                let name: String = entry.file_name().into_string().unwrap();
+
+                if name.starts_with("MapInfos.") || name.starts_with(&format!("Map{number:0>3}.")) {
+                    copy(entry.path(), map_data_path.join(name)).unwrap();
+                }
+            }
+
+            let alone = read_blocks(&map_data_path);
+            assert_eq!(alone.len(), 1);
+            assert_eq!(alone[0].0, number);
+
+            if maps_processing_mode == MapsProcessingMode::Separate {
+                assert_eq!(alone[0].1, lines);
+            } else {
+                // Lines, that are present in previous maps, aren't repeated
+                assert!(lines.iter().all(|line| alone[0].1.contains(line)));
+            }
+        }
+    }
+
+    remove_dir_all(&map_data_path).unwrap();
+}
+
+#[test]
+fn blocks_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let map_data_path = game_path.join("data_blocks");
+    let translation_path = game_path.join("translation_blocks");
+    let engine_type = EngineType::VX;
+
+    let mut map_numbers: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .map(|name| name[3..].split_once('.').unwrap().0.trim_start_matches('0').to_owned())
+        .collect();
+    map_numbers.sort_by_key(|number| number.parse::<u16>().unwrap());
+
+    for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+        // Lines of every map, read from the data directory, without format and header comments
+        let read_blocks = |data_path: &Path| -> Vec<(String, Vec<String>)> {
+            if translation_path.exists() {
+                remove_dir_all(&translation_path).unwrap();
+            }
+
+            create_dir_all(&translation_path).unwrap();
+
+            MapReader::new(data_path, &translation_path, engine_type)
+                .processing_mode(ProcessingMode::Force)
+                .maps_processing_mode(maps_processing_mode)
+                .read();
+
+            let mut blocks: Vec<(String, Vec<String>)> = Vec::new();
+
+            for line in read_to_string(translation_path.join("maps.txt")).unwrap().split('\n') {
+                if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+                    blocks.push((number.to_owned(), Vec::new()));
+                } else if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line.to_owned());
+                }
+            }
+
+            blocks
+        };
+
+        let blocks = read_blocks(&data_path);
+        assert_eq!(
+            blocks.iter().map(|(number, _)| number.clone()).collect::<Vec<_>>(),
+            map_numbers
+        );
+
+        // Every map block holds lines of its own map, read from the data with only that map
+        for (number, lines) in blocks {
+            if map_data_path.exists() {
+                remove_dir_all(&map_data_path).unwrap();
+            }
+
+            create_dir_all(&map_data_path).unwrap();
+
+            for entry in read_dir(&data_path).unwrap().flatten() {
+                let name: String = entry.file_name().into_string().unwrap();
+
+                if name.starts_with("MapInfos.") || name.starts_with(&format!("Map{number:0>3}.")) {
+                    copy(entry.path(), map_data_path.join(name)).unwrap();
+                }
+            }
+
+            let alone = read_blocks(&map_data_path);
+            assert_eq!(alone.len(), 1);
+            assert_eq!(alone[0].0, number);
+
+            if maps_processing_mode == MapsProcessingMode::Separate {
+                assert_eq!(alone[0].1, lines);
+            } else {
+                // Lines, that are present in previous maps, aren't repeated
+                assert!(lines.iter().all(|line| alone[0].1.contains(line)));
+            }
+        }
+    }
+
+    remove_dir_all(&map_data_path).unwrap();
+}
+
+#[test]
+fn blocks_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let map_data_path = game_path.join("data_blocks");
+    let translation_path = game_path.join("translation_blocks");
+    let engine_type = EngineType::XP;
+
+    let mut map_numbers: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .map(|name| name[3..].split_once('.').unwrap().0.trim_start_matches('0').to_owned())
+        .collect();
+    map_numbers.sort_by_key(|number| number.parse::<u16>().unwrap());
+
+    for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+        // Lines of every map, read from the data directory, without format and header comments
+        let read_blocks = |data_path: &Path| -> Vec<(String, Vec<String>)> {
+            if translation_path.exists() {
+                remove_dir_all(&translation_path).unwrap();
+            }
+
+            create_dir_all(&translation_path).unwrap();
+
+            MapReader::new(data_path, &translation_path, engine_type)
+                .processing_mode(ProcessingMode::Force)
+                .maps_processing_mode(maps_processing_mode)
+                .read();
+
+            let mut blocks: Vec<(String, Vec<String>)> = Vec::new();
+
+            for line in read_to_string(translation_path.join("maps.txt")).unwrap().split('\n') {
+                if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+                    blocks.push((number.to_owned(), Vec::new()));
+                } else if let Some((_, lines)) = blocks.last_mut() {
+                    lines.push(line.to_owned());
+                }
+            }
+
+            blocks
+        };
+
+        let blocks = read_blocks(&data_path);
+        assert_eq!(
+            blocks.iter().map(|(number, _)| number.clone()).collect::<Vec<_>>(),
+            map_numbers
+        );
+
+        // Every map block holds lines of its own map, read from the data with only that map
+        for (number, lines) in blocks {
+            if map_data_path.exists() {
+                remove_dir_all(&map_data_path).unwrap();
+            }
+
+            create_dir_all(&map_data_path).unwrap();
+
+            for entry in read_dir(&data_path).unwrap().flatten() {
+                let name: String = entry.file_name().into_string().unwrap();
+
+                if name.starts_with("MapInfos.") || name.starts_with(&format!("Map{number:0>3}.")) {
+                    copy(entry.path(), map_data_path.join(name)).unwrap();
+                }
+            }
+
+            let alone = read_blocks(&map_data_path);
+            assert_eq!(alone.len(), 1);
+            assert_eq!(alone[0].0, number);
+
+            if maps_processing_mode == MapsProcessingMode::Separate {
+                assert_eq!(alone[0].1, lines);
+            } else {
+                // Lines, that are present in previous maps, aren't repeated
+                assert!(lines.iter().all(|line| alone[0].1.contains(line)));
+            }
+        }
+    }
+
+    remove_dir_all(&map_data_path).unwrap();
+}
@@ -0,0 +1,426 @@
+use rvpacker_txt_rs_lib::{
+    cache::CACHE_FILE_NAME,
+    read::{MapReader, OtherReader},
+    types::{EngineType, FileFilter, ProcessingMode},
+    write::{MapWriter, OtherWriter},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, remove_dir_all, remove_file, write},
+    path::PathBuf,
+};
+
+#[test]
+fn cache_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_cache");
+    let output_path = game_path.join("output_cache");
+    let engine_type = EngineType::New;
+    let map_path = output_path.join("Map001.json");
+    let items_path = output_path.join("Items.json");
+
+    for path in [&translation_path, &output_path] {
+        if path.exists() {
+            remove_dir_all(path).unwrap();
+        }
+
+        create_dir_all(path).unwrap();
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let fill = |file_name: &str, translation: &str| {
+        let path = translation_path.join(file_name);
+        let content: String = read_to_string(&path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, _)) if !line.starts_with("<!--") => format!("{original}<#>{translation}"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write(path, content).unwrap();
+    };
+
+    fill("maps.txt", "Cached");
+    fill("items.txt", "Cached");
+
+    let write_files = |force: bool| {
+        MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .force(force)
+            .write();
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .file_filter(FileFilter::default().include(&["Items"]))
+            .force(force)
+            .write();
+    };
+
+    write_files(false);
+    assert!(output_path.join(CACHE_FILE_NAME).exists());
+
+    // Files with unchanged inputs are skipped
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    write_files(false);
+    assert_eq!(read_to_string(&map_path).unwrap(), "stale");
+    assert_eq!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Force bypasses the cache
+    write_files(true);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Changed translation invalidates the cache
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    fill("maps.txt", "Changed");
+    fill("items.txt", "Changed");
+    write_files(false);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Removed output files are written again
+    remove_file(&map_path).unwrap();
+    write_files(false);
+    assert!(map_path.exists());
+}
+
+#[test]
+fn cache_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_cache");
+    let output_path = game_path.join("output_cache");
+    let engine_type = EngineType::New;
+    let map_path = output_path.join("Map001.json");
+    let items_path = output_path.join("Items.json");
+
+    for path in [&translation_path, &output_path] {
+        if path.exists() {
+            remove_dir_all(path).unwrap();
+        }
+
+        create_dir_all(path).unwrap();
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let fill = |file_name: &str, translation: &str| {
+        let path = translation_path.join(file_name);
+        let content: String = read_to_string(&path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, _)) if !line.starts_with("<!--") => format!("{original}<#>{translation}"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write(path, content).unwrap();
+    };
+
+    fill("maps.txt", "Cached");
+    fill("items.txt", "Cached");
+
+    let write_files = |force: bool| {
+        MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .force(force)
+            .write();
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .file_filter(FileFilter::default().include(&["Items"]))
+            .force(force)
+            .write();
+    };
+
+    write_files(false);
+    assert!(output_path.join(CACHE_FILE_NAME).exists());
+
+    // Files with unchanged inputs are skipped
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    write_files(false);
+    assert_eq!(read_to_string(&map_path).unwrap(), "stale");
+    assert_eq!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Force bypasses the cache
+    write_files(true);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Changed translation invalidates the cache
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    fill("maps.txt", "Changed");
+    fill("items.txt", "Changed");
+    write_files(false);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Removed output files are written again
+    remove_file(&map_path).unwrap();
+    write_files(false);
+    assert!(map_path.exists());
+}
+
+#[test]
+fn cache_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_cache");
+    let output_path = game_path.join("output_cache");
+    let engine_type = EngineType::VXAce;
+    let map_path = output_path.join("Map001.rvdata2");
+    let items_path = output_path.join("Items.rvdata2");
+
+    for path in [&translation_path, &output_path] {
+        if path.exists() {
+            remove_dir_all(path).unwrap();
+        }
+
+        create_dir_all(path).unwrap();
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let fill = |file_name: &str, translation: &str| {
+        let path = translation_path.join(file_name);
+        let content: String = read_to_string(&path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, _)) if !line.starts_with("<!--") => format!("{original}<#>{translation}"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write(path, content).unwrap();
+    };
+
+    fill("maps.txt", "Cached");
+    fill("items.txt", "Cached");
+
+    let write_files = |force: bool| {
+        MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .force(force)
+            .write();
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .file_filter(FileFilter::default().include(&["Items"]))
+            .force(force)
+            .write();
+    };
+
+    write_files(false);
+    assert!(output_path.join(CACHE_FILE_NAME).exists());
+
+    // Files with unchanged inputs are skipped
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    write_files(false);
+    assert_eq!(read_to_string(&map_path).unwrap(), "stale");
+    assert_eq!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Force bypasses the cache
+    write_files(true);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Changed translation invalidates the cache
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    fill("maps.txt", "Changed");
+    fill("items.txt", "Changed");
+    write_files(false);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Removed output files are written again
+    remove_file(&map_path).unwrap();
+    write_files(false);
+    assert!(map_path.exists());
+}
+
+#[test]
+fn cache_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_cache");
+    let output_path = game_path.join("output_cache");
+    let engine_type = EngineType::VX;
+    let map_path = output_path.join("Map001.rvdata");
+    let items_path = output_path.join("Items.rvdata");
+
+    for path in [&translation_path, &output_path] {
+        if path.exists() {
+            remove_dir_all(path).unwrap();
+        }
+
+        create_dir_all(path).unwrap();
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let fill = |file_name: &str, translation: &str| {
+        let path = translation_path.join(file_name);
+        let content: String = read_to_string(&path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, _)) if !line.starts_with("<!--") => format!("{original}<#>{translation}"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write(path, content).unwrap();
+    };
+
+    fill("maps.txt", "Cached");
+    fill("items.txt", "Cached");
+
+    let write_files = |force: bool| {
+        MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .force(force)
+            .write();
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .file_filter(FileFilter::default().include(&["Items"]))
+            .force(force)
+            .write();
+    };
+
+    write_files(false);
+    assert!(output_path.join(CACHE_FILE_NAME).exists());
+
+    // Files with unchanged inputs are skipped
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    write_files(false);
+    assert_eq!(read_to_string(&map_path).unwrap(), "stale");
+    assert_eq!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Force bypasses the cache
+    write_files(true);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Changed translation invalidates the cache
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    fill("maps.txt", "Changed");
+    fill("items.txt", "Changed");
+    write_files(false);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Removed output files are written again
+    remove_file(&map_path).unwrap();
+    write_files(false);
+    assert!(map_path.exists());
+}
+
+#[test]
+fn cache_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_cache");
+    let output_path = game_path.join("output_cache");
+    let engine_type = EngineType::XP;
+    let map_path = output_path.join("Map001.rxdata");
+    let items_path = output_path.join("Items.rxdata");
+
+    for path in [&translation_path, &output_path] {
+        if path.exists() {
+            remove_dir_all(path).unwrap();
+        }
+
+        create_dir_all(path).unwrap();
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let fill = |file_name: &str, translation: &str| {
+        let path = translation_path.join(file_name);
+        let content: String = read_to_string(&path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, _)) if !line.starts_with("<!--") => format!("{original}<#>{translation}"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write(path, content).unwrap();
+    };
+
+    fill("maps.txt", "Cached");
+    fill("items.txt", "Cached");
+
+    let write_files = |force: bool| {
+        MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .force(force)
+            .write();
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .file_filter(FileFilter::default().include(&["Items"]))
+            .force(force)
+            .write();
+    };
+
+    write_files(false);
+    assert!(output_path.join(CACHE_FILE_NAME).exists());
+
+    // Files with unchanged inputs are skipped
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    write_files(false);
+    assert_eq!(read_to_string(&map_path).unwrap(), "stale");
+    assert_eq!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Force bypasses the cache
+    write_files(true);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Changed translation invalidates the cache
+    write(&map_path, "stale").unwrap();
+    write(&items_path, "stale").unwrap();
+    fill("maps.txt", "Changed");
+    fill("items.txt", "Changed");
+    write_files(false);
+    assert_ne!(read_to_string(&map_path).unwrap(), "stale");
+    assert_ne!(read_to_string(&items_path).unwrap(), "stale");
+
+    // Removed output files are written again
+    remove_file(&map_path).unwrap();
+    write_files(false);
+    assert!(map_path.exists());
+}
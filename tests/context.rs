@@ -0,0 +1,782 @@
+use marshal_rs::{dump, load, StringMode};
+use rvpacker_txt_rs_lib::{
+    context::parse_ids,
+    read::{MapReader, OtherReader},
+    types::{EngineType, FileFilter, ProcessingMode},
+    write::{MapWriter, OtherWriter},
+};
+use sonic_rs::{from_str, to_string, Value};
+use std::{
+    env::var,
+    fs::{copy, create_dir_all, read, read_dir, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn keyed_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Overrides survive appending
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .keyed(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+    OtherWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+}
+
+#[test]
+fn keyed_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Overrides survive appending
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .keyed(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+    OtherWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+}
+
+#[test]
+fn keyed_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Overrides survive appending
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .keyed(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+    OtherWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+}
+
+#[test]
+fn keyed_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Overrides survive appending
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .keyed(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+    OtherWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+}
+
+#[test]
+fn keyed_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .keyed(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Overrides survive appending
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .keyed(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+    OtherWriter::new(&data_path, &translation_path, &output_path, engine_type).write();
+}
+
+#[test]
+fn ids_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Merging by IDs doesn't change freshly read files
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+    assert_eq!(parse_ids(&forced).len(), parse_ids(&appended).len());
+}
+
+#[test]
+fn ids_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Merging by IDs doesn't change freshly read files
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+    assert_eq!(parse_ids(&forced).len(), parse_ids(&appended).len());
+}
+
+#[test]
+fn ids_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Merging by IDs doesn't change freshly read files
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+    assert_eq!(parse_ids(&forced).len(), parse_ids(&appended).len());
+}
+
+#[test]
+fn ids_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Merging by IDs doesn't change freshly read files
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+    assert_eq!(parse_ids(&forced).len(), parse_ids(&appended).len());
+}
+
+#[test]
+fn ids_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+    let forced = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    // Merging by IDs doesn't change freshly read files
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+    assert_eq!(parse_ids(&forced).len(), parse_ids(&appended).len());
+}
+
+#[test]
+fn ids_changed_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let ids_data_path = game_path.join("ids_data");
+    let translation_path = game_path.join("translation_ids");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&ids_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), ids_data_path.join(entry.file_name())).unwrap();
+    }
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+
+    // Every line is translated
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &translated).unwrap();
+
+    // The name of the second item changes, while its ID stays the same
+    let items_path = ids_data_path.join("Items.json");
+    let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+    items[2]["name"] = "Elixir".into();
+    write(&items_path, to_string(&items).unwrap()).unwrap();
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended: String = read_to_string(&items_txt_path).unwrap();
+
+    // Translation is carried over by ID and marked for review, previous line isn't kept
+    assert!(appended.contains("<!-- Needs Review --><#>エーテル\n<!-- State --><#>needs-review\nElixir<#>TR エーテル"));
+    assert!(!appended.contains("エーテル<#>TR エーテル"));
+    assert!(appended.contains("MPを回復<#>TR MPを回復"));
+    assert_eq!(parse_ids(&appended).len(), parse_ids(&translated).len());
+
+    // Review comments survive the next append
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), appended);
+}
+
+#[test]
+fn ids_changed_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let ids_data_path = game_path.join("ids_data");
+    let translation_path = game_path.join("translation_ids");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&ids_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), ids_data_path.join(entry.file_name())).unwrap();
+    }
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+
+    // Every line is translated
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &translated).unwrap();
+
+    // The name of the second item changes, while its ID stays the same
+    let items_path = ids_data_path.join("Items.json");
+    let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+    items[2]["name"] = "Elixir".into();
+    write(&items_path, to_string(&items).unwrap()).unwrap();
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended: String = read_to_string(&items_txt_path).unwrap();
+
+    // Translation is carried over by ID and marked for review, previous line isn't kept
+    assert!(appended.contains("<!-- Needs Review --><#>エーテル\n<!-- State --><#>needs-review\nElixir<#>TR エーテル"));
+    assert!(!appended.contains("エーテル<#>TR エーテル"));
+    assert!(appended.contains("MPを回復<#>TR MPを回復"));
+    assert_eq!(parse_ids(&appended).len(), parse_ids(&translated).len());
+
+    // Review comments survive the next append
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), appended);
+}
+
+#[test]
+fn ids_changed_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let ids_data_path = game_path.join("ids_data");
+    let translation_path = game_path.join("translation_ids");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&ids_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), ids_data_path.join(entry.file_name())).unwrap();
+    }
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+
+    // Every line is translated
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &translated).unwrap();
+
+    // The name of the second item changes, while its ID stays the same
+    let items_path = ids_data_path.join("Items.rvdata2");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[2]["__symbol__name"] = "Elixir".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended: String = read_to_string(&items_txt_path).unwrap();
+
+    // Translation is carried over by ID and marked for review, previous line isn't kept
+    assert!(appended.contains("<!-- Needs Review --><#>エーテル\n<!-- State --><#>needs-review\nElixir<#>TR エーテル"));
+    assert!(!appended.contains("エーテル<#>TR エーテル"));
+    assert!(appended.contains("MPを回復<#>TR MPを回復"));
+    assert_eq!(parse_ids(&appended).len(), parse_ids(&translated).len());
+
+    // Review comments survive the next append
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), appended);
+}
+
+#[test]
+fn ids_changed_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let ids_data_path = game_path.join("ids_data");
+    let translation_path = game_path.join("translation_ids");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&ids_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), ids_data_path.join(entry.file_name())).unwrap();
+    }
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+
+    // Every line is translated
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &translated).unwrap();
+
+    // The name of the second item changes, while its ID stays the same
+    let items_path = ids_data_path.join("Items.rvdata");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[2]["__symbol__name"] = "Elixir".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended: String = read_to_string(&items_txt_path).unwrap();
+
+    // Translation is carried over by ID and marked for review, previous line isn't kept
+    assert!(appended.contains("<!-- Needs Review --><#>エーテル\n<!-- State --><#>needs-review\nElixir<#>TR エーテル"));
+    assert!(!appended.contains("エーテル<#>TR エーテル"));
+    assert!(appended.contains("MPを回復<#>TR MPを回復"));
+    assert_eq!(parse_ids(&appended).len(), parse_ids(&translated).len());
+
+    // Review comments survive the next append
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), appended);
+}
+
+#[test]
+fn ids_changed_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let ids_data_path = game_path.join("ids_data");
+    let translation_path = game_path.join("translation_ids");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&ids_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), ids_data_path.join(entry.file_name())).unwrap();
+    }
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .ids(true)
+        .read();
+
+    // Every line is translated
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &translated).unwrap();
+
+    // The name of the second item changes, while its ID stays the same
+    let items_path = ids_data_path.join("Items.rxdata");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[2]["__symbol__name"] = "Elixir".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    let appended: String = read_to_string(&items_txt_path).unwrap();
+
+    // Translation is carried over by ID and marked for review, previous line isn't kept
+    assert!(appended.contains("<!-- Needs Review --><#>エーテル\n<!-- State --><#>needs-review\nElixir<#>TR エーテル"));
+    assert!(!appended.contains("エーテル<#>TR エーテル"));
+    assert!(appended.contains("MPを回復<#>TR MPを回復"));
+    assert_eq!(parse_ids(&appended).len(), parse_ids(&translated).len());
+
+    // Review comments survive the next append
+    OtherReader::new(&ids_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .ids(true)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), appended);
+}
+
+#[test]
+fn keyed_romanize_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let keyed_data_path = game_path.join("keyed_data");
+    let translation_path = game_path.join("translation_keyed");
+    let output_path = game_path.join("output_keyed");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&keyed_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), keyed_data_path.join(entry.file_name())).unwrap();
+    }
+
+    // Two events of the first map show the same choice, which is changed by romanization
+    let map_path = keyed_data_path.join("Map001.json");
+    let mut map: Value = from_str(&read_to_string(&map_path).unwrap()).unwrap();
+    let event = |id: u64| -> Value {
+        from_str(&format!(
+            r#"{{"id":{id},"name":"EV","note":"","pages":[{{"list":[{{"code":102,"indent":0,"parameters":[["「はい」","いいえ"],1,0,2,0]}},{{"code":0,"indent":0,"parameters":[]}}]}}],"x":0,"y":0}}"#
+        ))
+        .unwrap()
+    };
+    map["events"] = Value::from(vec![Value::new_null(), event(1), event(2)]);
+    write(&map_path, to_string(&map).unwrap()).unwrap();
+
+    MapReader::new(&keyed_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["1"]))
+        .keyed(true)
+        .romanize(true)
+        .read();
+
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .replace(
+            "<!-- Override: map1/2/0/0:0 --><#>",
+            "<!-- Override: map1/2/0/0:0 --><#>Second",
+        )
+        .replace("'はい'<#>", "'はい'<#>First");
+    assert!(translated.contains("Second") && translated.contains("First"));
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Override is applied to the romanized line of its location
+    let report = MapWriter::new(&keyed_data_path, &translation_path, &output_path, engine_type)
+        .file_filter(FileFilter::default().include(&["1"]))
+        .romanize(true)
+        .dry_run(true)
+        .write();
+
+    let translation_of = |location: &str| -> Option<String> {
+        report
+            .replacements
+            .iter()
+            .find(|x| x.file == "Map001.json" && x.location == location)
+            .map(|x| x.translation.clone())
+    };
+
+    assert_eq!(
+        translation_of("events[1].pages[0].list[0].parameters[0][0]").as_deref(),
+        Some("First")
+    );
+    assert_eq!(
+        translation_of("events[2].pages[0].list[0].parameters[0][0]").as_deref(),
+        Some("Second")
+    );
+}
+
+#[test]
+fn keyed_romanize_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let keyed_data_path = game_path.join("keyed_data");
+    let translation_path = game_path.join("translation_keyed");
+    let output_path = game_path.join("output_keyed");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&keyed_data_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), keyed_data_path.join(entry.file_name())).unwrap();
+    }
+
+    // Two events of the first map show the same choice, which is changed by romanization
+    let map_path = keyed_data_path.join("Map001.json");
+    let mut map: Value = from_str(&read_to_string(&map_path).unwrap()).unwrap();
+    let event = |id: u64| -> Value {
+        from_str(&format!(
+            r#"{{"id":{id},"name":"EV","note":"","pages":[{{"list":[{{"code":102,"indent":0,"parameters":[["「はい」","いいえ"],1,0,2,0]}},{{"code":0,"indent":0,"parameters":[]}}]}}],"x":0,"y":0}}"#
+        ))
+        .unwrap()
+    };
+    map["events"] = Value::from(vec![Value::new_null(), event(1), event(2)]);
+    write(&map_path, to_string(&map).unwrap()).unwrap();
+
+    MapReader::new(&keyed_data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["1"]))
+        .keyed(true)
+        .romanize(true)
+        .read();
+
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .replace(
+            "<!-- Override: map1/2/0/0:0 --><#>",
+            "<!-- Override: map1/2/0/0:0 --><#>Second",
+        )
+        .replace("'はい'<#>", "'はい'<#>First");
+    assert!(translated.contains("Second") && translated.contains("First"));
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Override is applied to the romanized line of its location
+    let report = MapWriter::new(&keyed_data_path, &translation_path, &output_path, engine_type)
+        .file_filter(FileFilter::default().include(&["1"]))
+        .romanize(true)
+        .dry_run(true)
+        .write();
+
+    let translation_of = |location: &str| -> Option<String> {
+        report
+            .replacements
+            .iter()
+            .find(|x| x.file == "Map001.json" && x.location == location)
+            .map(|x| x.translation.clone())
+    };
+
+    assert_eq!(
+        translation_of("events[1].pages[0].list[0].parameters[0][0]").as_deref(),
+        Some("First")
+    );
+    assert_eq!(
+        translation_of("events[2].pages[0].list[0].parameters[0][0]").as_deref(),
+        Some("Second")
+    );
+}
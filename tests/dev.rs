@@ -0,0 +1,260 @@
+use rvpacker_txt_rs_lib::{
+    read::SystemReader,
+    types::{EngineType, ProcessingMode},
+    write::SystemWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, remove_file, write},
+    path::PathBuf,
+};
+
+#[test]
+fn dev_names_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let system_file_path = data_path.join("System.json");
+    let dev_txt_path = translation_path.join("system_dev.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .dev_names(true)
+        .read();
+
+    let translated: String = read_to_string(&dev_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Dev"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<#>Dev"));
+    assert!(!read_to_string(translation_path.join("system.txt"))
+        .unwrap()
+        .contains("<#>Dev"));
+    write(&dev_txt_path, &translated).unwrap();
+
+    // Translation of names survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .dev_names(true)
+        .read();
+    assert_eq!(read_to_string(&dev_txt_path).unwrap(), translated);
+
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dev_names(true)
+        .dry_run(true)
+        .write();
+    assert!(report.replacements.iter().any(|x| x.translation == "Dev"));
+
+    remove_file(&dev_txt_path).unwrap();
+}
+
+#[test]
+fn dev_names_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let system_file_path = data_path.join("System.json");
+    let dev_txt_path = translation_path.join("system_dev.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .dev_names(true)
+        .read();
+
+    let translated: String = read_to_string(&dev_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Dev"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<#>Dev"));
+    assert!(!read_to_string(translation_path.join("system.txt"))
+        .unwrap()
+        .contains("<#>Dev"));
+    write(&dev_txt_path, &translated).unwrap();
+
+    // Translation of names survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .dev_names(true)
+        .read();
+    assert_eq!(read_to_string(&dev_txt_path).unwrap(), translated);
+
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dev_names(true)
+        .dry_run(true)
+        .write();
+    assert!(report.replacements.iter().any(|x| x.translation == "Dev"));
+
+    remove_file(&dev_txt_path).unwrap();
+}
+
+#[test]
+fn dev_names_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rvdata2");
+    let dev_txt_path = translation_path.join("system_dev.txt");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .dev_names(true)
+        .read();
+
+    let translated: String = read_to_string(&dev_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Dev"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<#>Dev"));
+    assert!(!read_to_string(translation_path.join("system.txt"))
+        .unwrap()
+        .contains("<#>Dev"));
+    write(&dev_txt_path, &translated).unwrap();
+
+    // Translation of names survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .dev_names(true)
+        .read();
+    assert_eq!(read_to_string(&dev_txt_path).unwrap(), translated);
+
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dev_names(true)
+        .dry_run(true)
+        .write();
+    assert!(report.replacements.iter().any(|x| x.translation == "Dev"));
+
+    remove_file(&dev_txt_path).unwrap();
+}
+
+#[test]
+fn dev_names_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rvdata");
+    let dev_txt_path = translation_path.join("system_dev.txt");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .dev_names(true)
+        .read();
+
+    let translated: String = read_to_string(&dev_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Dev"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<#>Dev"));
+    assert!(!read_to_string(translation_path.join("system.txt"))
+        .unwrap()
+        .contains("<#>Dev"));
+    write(&dev_txt_path, &translated).unwrap();
+
+    // Translation of names survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .dev_names(true)
+        .read();
+    assert_eq!(read_to_string(&dev_txt_path).unwrap(), translated);
+
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dev_names(true)
+        .dry_run(true)
+        .write();
+    assert!(report.replacements.iter().any(|x| x.translation == "Dev"));
+
+    remove_file(&dev_txt_path).unwrap();
+}
+
+#[test]
+fn dev_names_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rxdata");
+    let dev_txt_path = translation_path.join("system_dev.txt");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .dev_names(true)
+        .read();
+
+    let translated: String = read_to_string(&dev_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Dev"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<#>Dev"));
+    assert!(!read_to_string(translation_path.join("system.txt"))
+        .unwrap()
+        .contains("<#>Dev"));
+    write(&dev_txt_path, &translated).unwrap();
+
+    // Translation of names survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .dev_names(true)
+        .read();
+    assert_eq!(read_to_string(&dev_txt_path).unwrap(), translated);
+
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dev_names(true)
+        .dry_run(true)
+        .write();
+    assert!(report.replacements.iter().any(|x| x.translation == "Dev"));
+
+    remove_file(&dev_txt_path).unwrap();
+}
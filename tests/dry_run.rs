@@ -0,0 +1,541 @@
+use rvpacker_txt_rs_lib::{
+    purge::OtherPurger,
+    read::OtherReader,
+    types::{EngineType, IgnoreMap, ProcessingMode, PurgeReason, PurgedLine, Replacement, UntranslatedLine},
+    write::OtherWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn dry_run_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_dry_run");
+    let output_path = game_path.join("output_dry_run");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::New;
+    let items_filename = "Items.json";
+    let name_key = "name";
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .replace("エーテル<#>", "エーテル<#>Ether");
+    write(&items_txt_path, &content).unwrap();
+
+    let untranslated_line: usize = content.split('\n').position(|line| line == "MPを回復<#>").unwrap() + 1;
+
+    let txt_files = || -> Vec<(PathBuf, String)> {
+        let mut files: Vec<(PathBuf, String)> = read_dir(&translation_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (path.clone(), read_to_string(path).unwrap()))
+            .collect();
+        files.sort();
+        files
+    };
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    // Writer reports replacements and untranslated lines, but doesn't write anything
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    assert!(read_dir(&output_path).unwrap().next().is_none());
+    assert_eq!(txt_files(), files);
+    assert!(report.replacements.contains(&Replacement {
+        file: String::from(items_filename),
+        location: format!("[2].{name_key}"),
+        original: String::from("エーテル"),
+        translation: String::from("Ether"),
+    }));
+    assert!(report.untranslated.contains(&UntranslatedLine {
+        file: String::from("items.txt"),
+        line: untranslated_line,
+        original: String::from("MPを回復"),
+    }));
+    assert!(!report.untranslated.iter().any(|x| x.original == "エーテル"));
+
+    // Purger reports purged lines with their reasons, but doesn't write anything
+    let content: String = format!("{content}\nStale line<#>Stale translation");
+    write(&items_txt_path, &content).unwrap();
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("Stale line"),
+        translation: String::from("Stale translation"),
+        reason: PurgeReason::NotInSource,
+    }));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "MPを回復"));
+
+    let mut ignore_map = IgnoreMap::default();
+    ignore_map
+        .entry(String::from("<!-- File: items -->"))
+        .or_default()
+        .insert(String::from("MPを回復"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_empty(true)
+        .create_ignore(true)
+        .dry_run(true)
+        .purge(Some(&mut ignore_map), None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("MPを回復"),
+        translation: String::new(),
+        reason: PurgeReason::Ignored,
+    }));
+    assert!(purged
+        .iter()
+        .filter(|x| x.file == "items.txt" && x.original != "MPを回復")
+        .all(|x| x.translation.is_empty() && x.reason == PurgeReason::Empty));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "Stale line"));
+}
+
+#[test]
+fn dry_run_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_dry_run");
+    let output_path = game_path.join("output_dry_run");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::New;
+    let items_filename = "Items.json";
+    let name_key = "name";
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .replace("エーテル<#>", "エーテル<#>Ether");
+    write(&items_txt_path, &content).unwrap();
+
+    let untranslated_line: usize = content.split('\n').position(|line| line == "MPを回復<#>").unwrap() + 1;
+
+    let txt_files = || -> Vec<(PathBuf, String)> {
+        let mut files: Vec<(PathBuf, String)> = read_dir(&translation_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (path.clone(), read_to_string(path).unwrap()))
+            .collect();
+        files.sort();
+        files
+    };
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    // Writer reports replacements and untranslated lines, but doesn't write anything
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    assert!(read_dir(&output_path).unwrap().next().is_none());
+    assert_eq!(txt_files(), files);
+    assert!(report.replacements.contains(&Replacement {
+        file: String::from(items_filename),
+        location: format!("[2].{name_key}"),
+        original: String::from("エーテル"),
+        translation: String::from("Ether"),
+    }));
+    assert!(report.untranslated.contains(&UntranslatedLine {
+        file: String::from("items.txt"),
+        line: untranslated_line,
+        original: String::from("MPを回復"),
+    }));
+    assert!(!report.untranslated.iter().any(|x| x.original == "エーテル"));
+
+    // Purger reports purged lines with their reasons, but doesn't write anything
+    let content: String = format!("{content}\nStale line<#>Stale translation");
+    write(&items_txt_path, &content).unwrap();
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("Stale line"),
+        translation: String::from("Stale translation"),
+        reason: PurgeReason::NotInSource,
+    }));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "MPを回復"));
+
+    let mut ignore_map = IgnoreMap::default();
+    ignore_map
+        .entry(String::from("<!-- File: items -->"))
+        .or_default()
+        .insert(String::from("MPを回復"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_empty(true)
+        .create_ignore(true)
+        .dry_run(true)
+        .purge(Some(&mut ignore_map), None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("MPを回復"),
+        translation: String::new(),
+        reason: PurgeReason::Ignored,
+    }));
+    assert!(purged
+        .iter()
+        .filter(|x| x.file == "items.txt" && x.original != "MPを回復")
+        .all(|x| x.translation.is_empty() && x.reason == PurgeReason::Empty));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "Stale line"));
+}
+
+#[test]
+fn dry_run_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_dry_run");
+    let output_path = game_path.join("output_dry_run");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::VXAce;
+    let items_filename = "Items.rvdata2";
+    let name_key = "__symbol__name";
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .replace("エーテル<#>", "エーテル<#>Ether");
+    write(&items_txt_path, &content).unwrap();
+
+    let untranslated_line: usize = content.split('\n').position(|line| line == "MPを回復<#>").unwrap() + 1;
+
+    let txt_files = || -> Vec<(PathBuf, String)> {
+        let mut files: Vec<(PathBuf, String)> = read_dir(&translation_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (path.clone(), read_to_string(path).unwrap()))
+            .collect();
+        files.sort();
+        files
+    };
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    // Writer reports replacements and untranslated lines, but doesn't write anything
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    assert!(read_dir(&output_path).unwrap().next().is_none());
+    assert_eq!(txt_files(), files);
+    assert!(report.replacements.contains(&Replacement {
+        file: String::from(items_filename),
+        location: format!("[2].{name_key}"),
+        original: String::from("エーテル"),
+        translation: String::from("Ether"),
+    }));
+    assert!(report.untranslated.contains(&UntranslatedLine {
+        file: String::from("items.txt"),
+        line: untranslated_line,
+        original: String::from("MPを回復"),
+    }));
+    assert!(!report.untranslated.iter().any(|x| x.original == "エーテル"));
+
+    // Purger reports purged lines with their reasons, but doesn't write anything
+    let content: String = format!("{content}\nStale line<#>Stale translation");
+    write(&items_txt_path, &content).unwrap();
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("Stale line"),
+        translation: String::from("Stale translation"),
+        reason: PurgeReason::NotInSource,
+    }));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "MPを回復"));
+
+    let mut ignore_map = IgnoreMap::default();
+    ignore_map
+        .entry(String::from("<!-- File: items -->"))
+        .or_default()
+        .insert(String::from("MPを回復"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_empty(true)
+        .create_ignore(true)
+        .dry_run(true)
+        .purge(Some(&mut ignore_map), None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("MPを回復"),
+        translation: String::new(),
+        reason: PurgeReason::Ignored,
+    }));
+    assert!(purged
+        .iter()
+        .filter(|x| x.file == "items.txt" && x.original != "MPを回復")
+        .all(|x| x.translation.is_empty() && x.reason == PurgeReason::Empty));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "Stale line"));
+}
+
+#[test]
+fn dry_run_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_dry_run");
+    let output_path = game_path.join("output_dry_run");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::VX;
+    let items_filename = "Items.rvdata";
+    let name_key = "__symbol__name";
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .replace("エーテル<#>", "エーテル<#>Ether");
+    write(&items_txt_path, &content).unwrap();
+
+    let untranslated_line: usize = content.split('\n').position(|line| line == "MPを回復<#>").unwrap() + 1;
+
+    let txt_files = || -> Vec<(PathBuf, String)> {
+        let mut files: Vec<(PathBuf, String)> = read_dir(&translation_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (path.clone(), read_to_string(path).unwrap()))
+            .collect();
+        files.sort();
+        files
+    };
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    // Writer reports replacements and untranslated lines, but doesn't write anything
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    assert!(read_dir(&output_path).unwrap().next().is_none());
+    assert_eq!(txt_files(), files);
+    assert!(report.replacements.contains(&Replacement {
+        file: String::from(items_filename),
+        location: format!("[2].{name_key}"),
+        original: String::from("エーテル"),
+        translation: String::from("Ether"),
+    }));
+    assert!(report.untranslated.contains(&UntranslatedLine {
+        file: String::from("items.txt"),
+        line: untranslated_line,
+        original: String::from("MPを回復"),
+    }));
+    assert!(!report.untranslated.iter().any(|x| x.original == "エーテル"));
+
+    // Purger reports purged lines with their reasons, but doesn't write anything
+    let content: String = format!("{content}\nStale line<#>Stale translation");
+    write(&items_txt_path, &content).unwrap();
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("Stale line"),
+        translation: String::from("Stale translation"),
+        reason: PurgeReason::NotInSource,
+    }));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "MPを回復"));
+
+    let mut ignore_map = IgnoreMap::default();
+    ignore_map
+        .entry(String::from("<!-- File: items -->"))
+        .or_default()
+        .insert(String::from("MPを回復"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_empty(true)
+        .create_ignore(true)
+        .dry_run(true)
+        .purge(Some(&mut ignore_map), None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("MPを回復"),
+        translation: String::new(),
+        reason: PurgeReason::Ignored,
+    }));
+    assert!(purged
+        .iter()
+        .filter(|x| x.file == "items.txt" && x.original != "MPを回復")
+        .all(|x| x.translation.is_empty() && x.reason == PurgeReason::Empty));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "Stale line"));
+}
+
+#[test]
+fn dry_run_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_dry_run");
+    let output_path = game_path.join("output_dry_run");
+    let items_txt_path = translation_path.join("items.txt");
+    let engine_type = EngineType::XP;
+    let items_filename = "Items.rxdata";
+    let name_key = "__symbol__name";
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .replace("エーテル<#>", "エーテル<#>Ether");
+    write(&items_txt_path, &content).unwrap();
+
+    let untranslated_line: usize = content.split('\n').position(|line| line == "MPを回復<#>").unwrap() + 1;
+
+    let txt_files = || -> Vec<(PathBuf, String)> {
+        let mut files: Vec<(PathBuf, String)> = read_dir(&translation_path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .map(|path| (path.clone(), read_to_string(path).unwrap()))
+            .collect();
+        files.sort();
+        files
+    };
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    // Writer reports replacements and untranslated lines, but doesn't write anything
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    assert!(read_dir(&output_path).unwrap().next().is_none());
+    assert_eq!(txt_files(), files);
+    assert!(report.replacements.contains(&Replacement {
+        file: String::from(items_filename),
+        location: format!("[2].{name_key}"),
+        original: String::from("エーテル"),
+        translation: String::from("Ether"),
+    }));
+    assert!(report.untranslated.contains(&UntranslatedLine {
+        file: String::from("items.txt"),
+        line: untranslated_line,
+        original: String::from("MPを回復"),
+    }));
+    assert!(!report.untranslated.iter().any(|x| x.original == "エーテル"));
+
+    // Purger reports purged lines with their reasons, but doesn't write anything
+    let content: String = format!("{content}\nStale line<#>Stale translation");
+    write(&items_txt_path, &content).unwrap();
+    let files: Vec<(PathBuf, String)> = txt_files();
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("Stale line"),
+        translation: String::from("Stale translation"),
+        reason: PurgeReason::NotInSource,
+    }));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "MPを回復"));
+
+    let mut ignore_map = IgnoreMap::default();
+    ignore_map
+        .entry(String::from("<!-- File: items -->"))
+        .or_default()
+        .insert(String::from("MPを回復"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_empty(true)
+        .create_ignore(true)
+        .dry_run(true)
+        .purge(Some(&mut ignore_map), None);
+
+    assert_eq!(txt_files(), files);
+    assert!(purged.contains(&PurgedLine {
+        file: String::from("items.txt"),
+        map: None,
+        original: String::from("MPを回復"),
+        translation: String::new(),
+        reason: PurgeReason::Ignored,
+    }));
+    assert!(purged
+        .iter()
+        .filter(|x| x.file == "items.txt" && x.original != "MPを回復")
+        .all(|x| x.translation.is_empty() && x.reason == PurgeReason::Empty));
+    assert!(!purged
+        .iter()
+        .any(|x| x.original == "エーテル" || x.original == "Stale line"));
+}
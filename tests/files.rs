@@ -0,0 +1,250 @@
+use rvpacker_txt_rs_lib::{
+    purge::OtherPurger,
+    read::OtherReader,
+    types::{EngineType, ProcessingMode},
+    write::OtherWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, remove_dir_all},
+    path::PathBuf,
+};
+
+#[test]
+fn files_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_files");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let included_files: Vec<String> = vec![String::from("Tilesets"), String::from("Animations")];
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .included_files(included_files.clone())
+        .read();
+
+    assert!(translation_path.join("tilesets.txt").exists());
+    assert!(translation_path.join("animations.txt").exists());
+
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .included_files(included_files.clone())
+        .dry_run(true)
+        .write();
+    assert!(report.untranslated.iter().any(|x| x.file == "tilesets.txt"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .included_files(included_files)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+
+    // Configured list replaces the default one
+    remove_dir_all(&translation_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .excluded_files(Some(vec![String::from("Items")]))
+        .read();
+
+    assert!(!translation_path.join("items.txt").exists());
+    assert!(translation_path.join("tilesets.txt").exists());
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn files_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_files");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let included_files: Vec<String> = vec![String::from("Tilesets"), String::from("Animations")];
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .included_files(included_files.clone())
+        .read();
+
+    assert!(translation_path.join("tilesets.txt").exists());
+    assert!(translation_path.join("animations.txt").exists());
+
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .included_files(included_files.clone())
+        .dry_run(true)
+        .write();
+    assert!(report.untranslated.iter().any(|x| x.file == "tilesets.txt"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .included_files(included_files)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+
+    // Configured list replaces the default one
+    remove_dir_all(&translation_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .excluded_files(Some(vec![String::from("Items")]))
+        .read();
+
+    assert!(!translation_path.join("items.txt").exists());
+    assert!(translation_path.join("tilesets.txt").exists());
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn files_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_files");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VXAce;
+    let included_files: Vec<String> = vec![String::from("Tilesets"), String::from("Animations")];
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .included_files(included_files.clone())
+        .read();
+
+    assert!(translation_path.join("tilesets.txt").exists());
+    assert!(translation_path.join("animations.txt").exists());
+
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .included_files(included_files.clone())
+        .dry_run(true)
+        .write();
+    assert!(report.untranslated.iter().any(|x| x.file == "tilesets.txt"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .included_files(included_files)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+
+    // Configured list replaces the default one
+    remove_dir_all(&translation_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .excluded_files(Some(vec![String::from("Items")]))
+        .read();
+
+    assert!(!translation_path.join("items.txt").exists());
+    assert!(translation_path.join("tilesets.txt").exists());
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn files_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_files");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VX;
+    let included_files: Vec<String> = vec![
+        String::from("Tilesets"),
+        String::from("Animations"),
+        String::from("Areas"),
+    ];
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .included_files(included_files.clone())
+        .read();
+
+    assert!(translation_path.join("tilesets.txt").exists());
+    assert!(translation_path.join("animations.txt").exists());
+
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .included_files(included_files.clone())
+        .dry_run(true)
+        .write();
+    assert!(report.untranslated.iter().any(|x| x.file == "tilesets.txt"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .included_files(included_files)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+
+    // Configured list replaces the default one
+    remove_dir_all(&translation_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .excluded_files(Some(vec![String::from("Items")]))
+        .read();
+
+    assert!(!translation_path.join("items.txt").exists());
+    assert!(translation_path.join("tilesets.txt").exists());
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn files_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_files");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::XP;
+    let included_files: Vec<String> = vec![String::from("Tilesets"), String::from("Animations")];
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .included_files(included_files.clone())
+        .read();
+
+    assert!(translation_path.join("tilesets.txt").exists());
+    assert!(translation_path.join("animations.txt").exists());
+
+    let report = OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .included_files(included_files.clone())
+        .dry_run(true)
+        .write();
+    assert!(report.untranslated.iter().any(|x| x.file == "tilesets.txt"));
+
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .included_files(included_files)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+
+    // Configured list replaces the default one
+    remove_dir_all(&translation_path).unwrap();
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .excluded_files(Some(vec![String::from("Items")]))
+        .read();
+
+    assert!(!translation_path.join("items.txt").exists());
+    assert!(translation_path.join("tilesets.txt").exists());
+
+    remove_dir_all(&translation_path).unwrap();
+}
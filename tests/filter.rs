@@ -0,0 +1,528 @@
+use rvpacker_txt_rs_lib::{
+    cache::CACHE_FILE_NAME,
+    read::{MapReader, OtherReader, SystemReader},
+    types::{EngineType, FileFilter, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+};
+
+#[test]
+fn file_patterns() {
+    // Default filter matches every file
+    let filter = FileFilter::default();
+    assert!(!filter.is_active());
+    assert!(filter.matches("Map001.json") && filter.matches("System") && filter.matches_map(999));
+
+    // Map numbers and ranges match only maps
+    let filter = FileFilter::default().include(&["5", "20-10"]);
+    assert!(filter.is_active());
+    assert!(filter.matches("Map005.rvdata2") && filter.matches_map(10) && filter.matches_map(20));
+    assert!(!filter.matches_map(4) && !filter.matches_map(21) && !filter.matches("5"));
+    assert!(!filter.matches("MapInfos.json") && !filter.matches("Items.json"));
+
+    // Globs and names are case-insensitive and ignore the extension
+    let filter = FileFilter::default().include(&["map0?", "items.json", "Common*"]);
+    assert!(filter.matches("Map01.json") && filter.matches("MAP02"));
+    assert!(!filter.matches("Map001.json"));
+    assert!(filter.matches("Items.rxdata") && filter.matches("CommonEvents.json"));
+    assert!(!filter.matches("Items2.json"));
+
+    // Excluded patterns win over included ones
+    let filter = FileFilter::default()
+        .include(&["1-10", "Items"])
+        .exclude(&["Map00?", "items"]);
+    assert!(filter.matches("Map010.json"));
+    assert!(!filter.matches("Map005.json") && !filter.matches("Items.json"));
+
+    let filter = FileFilter::default().exclude(&["System"]);
+    assert!(filter.matches("Actors.json") && !filter.matches("System.json"));
+}
+
+#[test]
+fn filter_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_filter");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let filled: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if !line.starts_with("<!--") && line.ends_with("<#>") {
+                format!("{line}Filtered")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, &filled).unwrap();
+
+    let filter = FileFilter::default().include(&["1"]);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(filter.clone())
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    let mut map_number: u16 = 0;
+
+    // Only the first map is read again, other maps keep their translation
+    for (line, filled_line) in content.split('\n').zip(filled.split('\n')) {
+        if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+            map_number = number.parse().unwrap();
+        }
+
+        if map_number == 1 {
+            assert!(!line.ends_with("<#>Filtered"));
+        } else {
+            assert_eq!(line, filled_line);
+        }
+    }
+
+    assert_eq!(content.split('\n').count(), filled.split('\n').count());
+
+    let other_translation_path = translation_path.join("other");
+    create_dir_all(&other_translation_path).unwrap();
+
+    OtherReader::new(&data_path, &other_translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["Items"]))
+        .read();
+
+    let files: Vec<String> = read_dir(&other_translation_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, ["items.txt"]);
+
+    SystemReader::new(
+        data_path.join("System.json"),
+        other_translation_path.clone(),
+        engine_type,
+    )
+    .processing_mode(ProcessingMode::Force)
+    .file_filter(FileFilter::default().exclude(&["System"]))
+    .read();
+    assert!(!other_translation_path.join("system.txt").exists());
+
+    let output_path = translation_path.join("output");
+    create_dir_all(&output_path).unwrap();
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .file_filter(filter)
+        .write();
+
+    let files: Vec<String> = read_dir(&output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name != CACHE_FILE_NAME)
+        .collect();
+    assert_eq!(files, ["Map001.json"]);
+}
+
+#[test]
+fn filter_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_filter");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let filled: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if !line.starts_with("<!--") && line.ends_with("<#>") {
+                format!("{line}Filtered")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, &filled).unwrap();
+
+    let filter = FileFilter::default().include(&["1"]);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(filter.clone())
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    let mut map_number: u16 = 0;
+
+    // Only the first map is read again, other maps keep their translation
+    for (line, filled_line) in content.split('\n').zip(filled.split('\n')) {
+        if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+            map_number = number.parse().unwrap();
+        }
+
+        if map_number == 1 {
+            assert!(!line.ends_with("<#>Filtered"));
+        } else {
+            assert_eq!(line, filled_line);
+        }
+    }
+
+    assert_eq!(content.split('\n').count(), filled.split('\n').count());
+
+    let other_translation_path = translation_path.join("other");
+    create_dir_all(&other_translation_path).unwrap();
+
+    OtherReader::new(&data_path, &other_translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["Items"]))
+        .read();
+
+    let files: Vec<String> = read_dir(&other_translation_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, ["items.txt"]);
+
+    SystemReader::new(
+        data_path.join("System.json"),
+        other_translation_path.clone(),
+        engine_type,
+    )
+    .processing_mode(ProcessingMode::Force)
+    .file_filter(FileFilter::default().exclude(&["System"]))
+    .read();
+    assert!(!other_translation_path.join("system.txt").exists());
+
+    let output_path = translation_path.join("output");
+    create_dir_all(&output_path).unwrap();
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .file_filter(filter)
+        .write();
+
+    let files: Vec<String> = read_dir(&output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name != CACHE_FILE_NAME)
+        .collect();
+    assert_eq!(files, ["Map001.json"]);
+}
+
+#[test]
+fn filter_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_filter");
+    let engine_type = EngineType::VXAce;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let filled: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if !line.starts_with("<!--") && line.ends_with("<#>") {
+                format!("{line}Filtered")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, &filled).unwrap();
+
+    let filter = FileFilter::default().include(&["1"]);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(filter.clone())
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    let mut map_number: u16 = 0;
+
+    // Only the first map is read again, other maps keep their translation
+    for (line, filled_line) in content.split('\n').zip(filled.split('\n')) {
+        if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+            map_number = number.parse().unwrap();
+        }
+
+        if map_number == 1 {
+            assert!(!line.ends_with("<#>Filtered"));
+        } else {
+            assert_eq!(line, filled_line);
+        }
+    }
+
+    assert_eq!(content.split('\n').count(), filled.split('\n').count());
+
+    let other_translation_path = translation_path.join("other");
+    create_dir_all(&other_translation_path).unwrap();
+
+    OtherReader::new(&data_path, &other_translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["Items"]))
+        .read();
+
+    let files: Vec<String> = read_dir(&other_translation_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, ["items.txt"]);
+
+    SystemReader::new(
+        data_path.join("System.rvdata2"),
+        other_translation_path.clone(),
+        engine_type,
+    )
+    .processing_mode(ProcessingMode::Force)
+    .file_filter(FileFilter::default().exclude(&["System"]))
+    .read();
+    assert!(!other_translation_path.join("system.txt").exists());
+
+    let output_path = translation_path.join("output");
+    create_dir_all(&output_path).unwrap();
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .file_filter(filter)
+        .write();
+
+    let files: Vec<String> = read_dir(&output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name != CACHE_FILE_NAME)
+        .collect();
+    assert_eq!(files, ["Map001.rvdata2"]);
+}
+
+#[test]
+fn filter_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_filter");
+    let engine_type = EngineType::VX;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let filled: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if !line.starts_with("<!--") && line.ends_with("<#>") {
+                format!("{line}Filtered")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, &filled).unwrap();
+
+    let filter = FileFilter::default().include(&["1"]);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(filter.clone())
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    let mut map_number: u16 = 0;
+
+    // Only the first map is read again, other maps keep their translation
+    for (line, filled_line) in content.split('\n').zip(filled.split('\n')) {
+        if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+            map_number = number.parse().unwrap();
+        }
+
+        if map_number == 1 {
+            assert!(!line.ends_with("<#>Filtered"));
+        } else {
+            assert_eq!(line, filled_line);
+        }
+    }
+
+    assert_eq!(content.split('\n').count(), filled.split('\n').count());
+
+    let other_translation_path = translation_path.join("other");
+    create_dir_all(&other_translation_path).unwrap();
+
+    OtherReader::new(&data_path, &other_translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["Items"]))
+        .read();
+
+    let files: Vec<String> = read_dir(&other_translation_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, ["items.txt"]);
+
+    SystemReader::new(
+        data_path.join("System.rvdata"),
+        other_translation_path.clone(),
+        engine_type,
+    )
+    .processing_mode(ProcessingMode::Force)
+    .file_filter(FileFilter::default().exclude(&["System"]))
+    .read();
+    assert!(!other_translation_path.join("system.txt").exists());
+
+    let output_path = translation_path.join("output");
+    create_dir_all(&output_path).unwrap();
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .file_filter(filter)
+        .write();
+
+    let files: Vec<String> = read_dir(&output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name != CACHE_FILE_NAME)
+        .collect();
+    assert_eq!(files, ["Map001.rvdata"]);
+}
+
+#[test]
+fn filter_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_filter");
+    let engine_type = EngineType::XP;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let filled: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if !line.starts_with("<!--") && line.ends_with("<#>") {
+                format!("{line}Filtered")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, &filled).unwrap();
+
+    let filter = FileFilter::default().include(&["1"]);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(filter.clone())
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    let mut map_number: u16 = 0;
+
+    // Only the first map is read again, other maps keep their translation
+    for (line, filled_line) in content.split('\n').zip(filled.split('\n')) {
+        if let Some(number) = line.strip_prefix("<!-- Map --><#>") {
+            map_number = number.parse().unwrap();
+        }
+
+        if map_number == 1 {
+            assert!(!line.ends_with("<#>Filtered"));
+        } else {
+            assert_eq!(line, filled_line);
+        }
+    }
+
+    assert_eq!(content.split('\n').count(), filled.split('\n').count());
+
+    let other_translation_path = translation_path.join("other");
+    create_dir_all(&other_translation_path).unwrap();
+
+    OtherReader::new(&data_path, &other_translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .file_filter(FileFilter::default().include(&["Items"]))
+        .read();
+
+    let files: Vec<String> = read_dir(&other_translation_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    assert_eq!(files, ["items.txt"]);
+
+    SystemReader::new(
+        data_path.join("System.rxdata"),
+        other_translation_path.clone(),
+        engine_type,
+    )
+    .processing_mode(ProcessingMode::Force)
+    .file_filter(FileFilter::default().exclude(&["System"]))
+    .read();
+    assert!(!other_translation_path.join("system.txt").exists());
+
+    let output_path = translation_path.join("output");
+    create_dir_all(&output_path).unwrap();
+
+    MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .file_filter(filter)
+        .write();
+
+    let files: Vec<String> = read_dir(&output_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name != CACHE_FILE_NAME)
+        .collect();
+    assert_eq!(files, ["Map001.rxdata"]);
+}
@@ -0,0 +1,228 @@
+use rvpacker_txt_rs_lib::{
+    escape, format_version,
+    read::MapReader,
+    statics::{FORMAT_COMMENT, FORMAT_VERSION, LINES_SEPARATOR},
+    types::{EngineType, ProcessingMode},
+    unescape,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn escaping() {
+    let cases: [(&str, &str); 6] = [
+        ("plain", "plain"),
+        ("a<#>b", "a<#lt#>>b"),
+        (r"line\#", "line<#bs#>"),
+        ("two\nlines", r"two\#lines"),
+        ("<#lt#>", "<#lt#>lt#>"),
+        ("<#>\n\\#", r"<#lt#>>\#<#bs#>"),
+    ];
+
+    // Escaped text never contains the separator, and is unescaped back in the second version
+    for (text, escaped) in cases {
+        assert_eq!(escape(text), escaped);
+        assert!(!escape(text).contains(LINES_SEPARATOR));
+        assert_eq!(unescape(escaped, 2), text);
+    }
+
+    // The first version only has new lines
+    assert_eq!(unescape(r"two\#lines", 1), "two\nlines");
+    assert_eq!(unescape("a<#lt#>>b", 1), "a<#lt#>>b");
+    assert_eq!(unescape("line<#bs#>", 1), "line<#bs#>");
+
+    assert_eq!(
+        format_version(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}2\nline<#>")),
+        2
+    );
+    assert_eq!(format_version(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR} 3 ")), 3);
+    assert_eq!(format_version(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}two")), 1);
+    assert_eq!(
+        format_version(&format!("line<#>\n{FORMAT_COMMENT}{LINES_SEPARATOR}2")),
+        1
+    );
+    assert_eq!(format_version(""), 1);
+}
+
+#[test]
+fn format_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(format_version(&content), FORMAT_VERSION);
+    assert!(content.starts_with(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n")));
+
+    for line in content.split('\n').filter(|line| !line.starts_with("<!--")) {
+        let (original, _) = line.split_once(LINES_SEPARATOR).unwrap();
+        assert_eq!(escape(&unescape(original, FORMAT_VERSION)), original);
+    }
+
+    // Files without the format comment are read as the first version and upgraded
+    let (_, legacy) = content.split_once('\n').unwrap();
+    write(translation_path.join("maps.txt"), legacy).unwrap();
+    assert_eq!(format_version(legacy), 1);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), content);
+}
+
+#[test]
+fn format_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(format_version(&content), FORMAT_VERSION);
+    assert!(content.starts_with(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n")));
+
+    for line in content.split('\n').filter(|line| !line.starts_with("<!--")) {
+        let (original, _) = line.split_once(LINES_SEPARATOR).unwrap();
+        assert_eq!(escape(&unescape(original, FORMAT_VERSION)), original);
+    }
+
+    // Files without the format comment are read as the first version and upgraded
+    let (_, legacy) = content.split_once('\n').unwrap();
+    write(translation_path.join("maps.txt"), legacy).unwrap();
+    assert_eq!(format_version(legacy), 1);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), content);
+}
+
+#[test]
+fn format_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(format_version(&content), FORMAT_VERSION);
+    assert!(content.starts_with(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n")));
+
+    for line in content.split('\n').filter(|line| !line.starts_with("<!--")) {
+        let (original, _) = line.split_once(LINES_SEPARATOR).unwrap();
+        assert_eq!(escape(&unescape(original, FORMAT_VERSION)), original);
+    }
+
+    // Files without the format comment are read as the first version and upgraded
+    let (_, legacy) = content.split_once('\n').unwrap();
+    write(translation_path.join("maps.txt"), legacy).unwrap();
+    assert_eq!(format_version(legacy), 1);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), content);
+}
+
+#[test]
+fn format_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(format_version(&content), FORMAT_VERSION);
+    assert!(content.starts_with(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n")));
+
+    for line in content.split('\n').filter(|line| !line.starts_with("<!--")) {
+        let (original, _) = line.split_once(LINES_SEPARATOR).unwrap();
+        assert_eq!(escape(&unescape(original, FORMAT_VERSION)), original);
+    }
+
+    // Files without the format comment are read as the first version and upgraded
+    let (_, legacy) = content.split_once('\n').unwrap();
+    write(translation_path.join("maps.txt"), legacy).unwrap();
+    assert_eq!(format_version(legacy), 1);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), content);
+}
+
+#[test]
+fn format_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let content = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(format_version(&content), FORMAT_VERSION);
+    assert!(content.starts_with(&format!("{FORMAT_COMMENT}{LINES_SEPARATOR}{FORMAT_VERSION}\n")));
+
+    for line in content.split('\n').filter(|line| !line.starts_with("<!--")) {
+        let (original, _) = line.split_once(LINES_SEPARATOR).unwrap();
+        assert_eq!(escape(&unescape(original, FORMAT_VERSION)), original);
+    }
+
+    // Files without the format comment are read as the first version and upgraded
+    let (_, legacy) = content.split_once('\n').unwrap();
+    write(translation_path.join("maps.txt"), legacy).unwrap();
+    assert_eq!(format_version(legacy), 1);
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), content);
+}
@@ -0,0 +1,221 @@
+use rvpacker_txt_rs_lib::{
+    header::Header,
+    read::MapReader,
+    types::{EngineType, HeaderMode, MapsProcessingMode, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string},
+    path::PathBuf,
+};
+
+#[test]
+fn header_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .trim(true)
+        .read();
+
+    let header = Header::parse(&read_to_string(translation_path.join("maps.txt")).unwrap()).unwrap();
+
+    assert_eq!(header.version, env!("CARGO_PKG_VERSION"));
+    assert!(header.engine_type == Some(engine_type));
+    assert!(header.maps_processing_mode == Some(MapsProcessingMode::Separate));
+    assert_eq!(header.romanize, Some(false));
+    assert_eq!(header.trim, Some(true));
+
+    // Mismatched settings are rejected, and taken from the header when configured
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+    assert!(report.is_empty());
+
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .header_mode(HeaderMode::Configure)
+        .dry_run(true)
+        .write();
+    assert!(!report.is_empty());
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn header_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .trim(true)
+        .read();
+
+    let header = Header::parse(&read_to_string(translation_path.join("maps.txt")).unwrap()).unwrap();
+
+    assert_eq!(header.version, env!("CARGO_PKG_VERSION"));
+    assert!(header.engine_type == Some(engine_type));
+    assert!(header.maps_processing_mode == Some(MapsProcessingMode::Separate));
+    assert_eq!(header.romanize, Some(false));
+    assert_eq!(header.trim, Some(true));
+
+    // Mismatched settings are rejected, and taken from the header when configured
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+    assert!(report.is_empty());
+
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .header_mode(HeaderMode::Configure)
+        .dry_run(true)
+        .write();
+    assert!(!report.is_empty());
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn header_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .trim(true)
+        .read();
+
+    let header = Header::parse(&read_to_string(translation_path.join("maps.txt")).unwrap()).unwrap();
+
+    assert_eq!(header.version, env!("CARGO_PKG_VERSION"));
+    assert!(header.engine_type == Some(engine_type));
+    assert!(header.maps_processing_mode == Some(MapsProcessingMode::Separate));
+    assert_eq!(header.romanize, Some(false));
+    assert_eq!(header.trim, Some(true));
+
+    // Mismatched settings are rejected, and taken from the header when configured
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+    assert!(report.is_empty());
+
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .header_mode(HeaderMode::Configure)
+        .dry_run(true)
+        .write();
+    assert!(!report.is_empty());
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn header_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .trim(true)
+        .read();
+
+    let header = Header::parse(&read_to_string(translation_path.join("maps.txt")).unwrap()).unwrap();
+
+    assert_eq!(header.version, env!("CARGO_PKG_VERSION"));
+    assert!(header.engine_type == Some(engine_type));
+    assert!(header.maps_processing_mode == Some(MapsProcessingMode::Separate));
+    assert_eq!(header.romanize, Some(false));
+    assert_eq!(header.trim, Some(true));
+
+    // Mismatched settings are rejected, and taken from the header when configured
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+    assert!(report.is_empty());
+
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .header_mode(HeaderMode::Configure)
+        .dry_run(true)
+        .write();
+    assert!(!report.is_empty());
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn header_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .trim(true)
+        .read();
+
+    let header = Header::parse(&read_to_string(translation_path.join("maps.txt")).unwrap()).unwrap();
+
+    assert_eq!(header.version, env!("CARGO_PKG_VERSION"));
+    assert!(header.engine_type == Some(engine_type));
+    assert!(header.maps_processing_mode == Some(MapsProcessingMode::Separate));
+    assert_eq!(header.romanize, Some(false));
+    assert_eq!(header.trim, Some(true));
+
+    // Mismatched settings are rejected, and taken from the header when configured
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+    assert!(report.is_empty());
+
+    let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .header_mode(HeaderMode::Configure)
+        .dry_run(true)
+        .write();
+    assert!(!report.is_empty());
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
@@ -0,0 +1,588 @@
+use rvpacker_txt_rs_lib::{
+    language::{find_languages, LanguageReader, LanguageWriter, ReaderOptions, WriterOptions},
+    observer::{Observer, Warning},
+    statics::LINES_SEPARATOR,
+    stats::StatsCollector,
+    types::{EngineType, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{read_to_string, remove_dir_all, write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+#[test]
+fn languages_mz() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("languages");
+    let output_path = game_path.join("output").join("languages");
+    let engine_type = EngineType::New;
+
+    let originals = |path: &PathBuf| -> Vec<String> {
+        read_to_string(path)
+            .unwrap()
+            .split('\n')
+            .filter_map(|line| line.split_once(LINES_SEPARATOR))
+            .map(|(original, _)| original.to_owned())
+            .collect()
+    };
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Force,
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    // Only English maps are translated
+    let maps_path = translation_path.join("en").join("maps.txt");
+    let filled = read_to_string(&maps_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once(LINES_SEPARATOR) {
+            Some((original, _)) if !original.starts_with("<!--") => format!("{original}{LINES_SEPARATOR}{original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_path, filled).unwrap();
+
+    assert_eq!(find_languages(&translation_path), ["en", "fr"]);
+
+    let reports = LanguageWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .options(WriterOptions {
+            dry_run: true,
+            ..WriterOptions::default()
+        })
+        .write();
+    assert_eq!(reports.len(), 2);
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    let fr = &stats.languages["fr"].files["maps.txt"];
+
+    assert_eq!(en.translated, en.total);
+    assert_eq!(fr.translated, 0);
+    assert!(stats.summary().starts_with("Total: en "));
+
+    // New language is read from scratch in append mode, and gets the same originals
+    let recorder = Arc::new(Recorder::default());
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into(), "de".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Append,
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(find_languages(&translation_path), ["de", "en", "fr"]);
+    assert_eq!(
+        originals(&translation_path.join("de").join("maps.txt")),
+        originals(&maps_path)
+    );
+    assert!(recorder.warnings.lock().unwrap().is_empty());
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    assert_eq!(en.translated, en.total);
+
+    // Files, that differ from the first language, are reported
+    let items_path = translation_path.join("fr").join("items.txt");
+    let stale = read_to_string(&items_path).unwrap() + &format!("\nStale line{LINES_SEPARATOR}");
+    write(&items_path, stale).unwrap();
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [Warning::OriginalsMismatch {
+            file: "items.txt".into(),
+            language: "fr".into(),
+            reference: "en".into(),
+        }]
+    );
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn languages_mv() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("languages");
+    let output_path = game_path.join("output").join("languages");
+    let engine_type = EngineType::New;
+
+    let originals = |path: &PathBuf| -> Vec<String> {
+        read_to_string(path)
+            .unwrap()
+            .split('\n')
+            .filter_map(|line| line.split_once(LINES_SEPARATOR))
+            .map(|(original, _)| original.to_owned())
+            .collect()
+    };
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Force,
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    // Only English maps are translated
+    let maps_path = translation_path.join("en").join("maps.txt");
+    let filled = read_to_string(&maps_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once(LINES_SEPARATOR) {
+            Some((original, _)) if !original.starts_with("<!--") => format!("{original}{LINES_SEPARATOR}{original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_path, filled).unwrap();
+
+    assert_eq!(find_languages(&translation_path), ["en", "fr"]);
+
+    let reports = LanguageWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .options(WriterOptions {
+            dry_run: true,
+            ..WriterOptions::default()
+        })
+        .write();
+    assert_eq!(reports.len(), 2);
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    let fr = &stats.languages["fr"].files["maps.txt"];
+
+    assert_eq!(en.translated, en.total);
+    assert_eq!(fr.translated, 0);
+    assert!(stats.summary().starts_with("Total: en "));
+
+    // New language is read from scratch in append mode, and gets the same originals
+    let recorder = Arc::new(Recorder::default());
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into(), "de".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Append,
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(find_languages(&translation_path), ["de", "en", "fr"]);
+    assert_eq!(
+        originals(&translation_path.join("de").join("maps.txt")),
+        originals(&maps_path)
+    );
+    assert!(recorder.warnings.lock().unwrap().is_empty());
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    assert_eq!(en.translated, en.total);
+
+    // Files, that differ from the first language, are reported
+    let items_path = translation_path.join("fr").join("items.txt");
+    let stale = read_to_string(&items_path).unwrap() + &format!("\nStale line{LINES_SEPARATOR}");
+    write(&items_path, stale).unwrap();
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [Warning::OriginalsMismatch {
+            file: "items.txt".into(),
+            language: "fr".into(),
+            reference: "en".into(),
+        }]
+    );
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn languages_vxace() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("languages");
+    let output_path = game_path.join("output").join("languages");
+    let engine_type = EngineType::VXAce;
+
+    let originals = |path: &PathBuf| -> Vec<String> {
+        read_to_string(path)
+            .unwrap()
+            .split('\n')
+            .filter_map(|line| line.split_once(LINES_SEPARATOR))
+            .map(|(original, _)| original.to_owned())
+            .collect()
+    };
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Force,
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    // Only English maps are translated
+    let maps_path = translation_path.join("en").join("maps.txt");
+    let filled = read_to_string(&maps_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once(LINES_SEPARATOR) {
+            Some((original, _)) if !original.starts_with("<!--") => format!("{original}{LINES_SEPARATOR}{original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_path, filled).unwrap();
+
+    assert_eq!(find_languages(&translation_path), ["en", "fr"]);
+
+    let reports = LanguageWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .options(WriterOptions {
+            dry_run: true,
+            ..WriterOptions::default()
+        })
+        .write();
+    assert_eq!(reports.len(), 2);
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    let fr = &stats.languages["fr"].files["maps.txt"];
+
+    assert_eq!(en.translated, en.total);
+    assert_eq!(fr.translated, 0);
+    assert!(stats.summary().starts_with("Total: en "));
+
+    // New language is read from scratch in append mode, and gets the same originals
+    let recorder = Arc::new(Recorder::default());
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into(), "de".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Append,
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(find_languages(&translation_path), ["de", "en", "fr"]);
+    assert_eq!(
+        originals(&translation_path.join("de").join("maps.txt")),
+        originals(&maps_path)
+    );
+    assert!(recorder.warnings.lock().unwrap().is_empty());
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    assert_eq!(en.translated, en.total);
+
+    // Files, that differ from the first language, are reported
+    let items_path = translation_path.join("fr").join("items.txt");
+    let stale = read_to_string(&items_path).unwrap() + &format!("\nStale line{LINES_SEPARATOR}");
+    write(&items_path, stale).unwrap();
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [Warning::OriginalsMismatch {
+            file: "items.txt".into(),
+            language: "fr".into(),
+            reference: "en".into(),
+        }]
+    );
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn languages_vx() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("languages");
+    let output_path = game_path.join("output").join("languages");
+    let engine_type = EngineType::VX;
+
+    let originals = |path: &PathBuf| -> Vec<String> {
+        read_to_string(path)
+            .unwrap()
+            .split('\n')
+            .filter_map(|line| line.split_once(LINES_SEPARATOR))
+            .map(|(original, _)| original.to_owned())
+            .collect()
+    };
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Force,
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    // Only English maps are translated
+    let maps_path = translation_path.join("en").join("maps.txt");
+    let filled = read_to_string(&maps_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once(LINES_SEPARATOR) {
+            Some((original, _)) if !original.starts_with("<!--") => format!("{original}{LINES_SEPARATOR}{original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_path, filled).unwrap();
+
+    assert_eq!(find_languages(&translation_path), ["en", "fr"]);
+
+    let reports = LanguageWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .options(WriterOptions {
+            dry_run: true,
+            ..WriterOptions::default()
+        })
+        .write();
+    assert_eq!(reports.len(), 2);
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    let fr = &stats.languages["fr"].files["maps.txt"];
+
+    assert_eq!(en.translated, en.total);
+    assert_eq!(fr.translated, 0);
+    assert!(stats.summary().starts_with("Total: en "));
+
+    // New language is read from scratch in append mode, and gets the same originals
+    let recorder = Arc::new(Recorder::default());
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into(), "de".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Append,
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(find_languages(&translation_path), ["de", "en", "fr"]);
+    assert_eq!(
+        originals(&translation_path.join("de").join("maps.txt")),
+        originals(&maps_path)
+    );
+    assert!(recorder.warnings.lock().unwrap().is_empty());
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    assert_eq!(en.translated, en.total);
+
+    // Files, that differ from the first language, are reported
+    let items_path = translation_path.join("fr").join("items.txt");
+    let stale = read_to_string(&items_path).unwrap() + &format!("\nStale line{LINES_SEPARATOR}");
+    write(&items_path, stale).unwrap();
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [Warning::OriginalsMismatch {
+            file: "items.txt".into(),
+            language: "fr".into(),
+            reference: "en".into(),
+        }]
+    );
+
+    remove_dir_all(&translation_path).unwrap();
+}
+
+#[test]
+fn languages_xp() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("languages");
+    let output_path = game_path.join("output").join("languages");
+    let engine_type = EngineType::XP;
+
+    let originals = |path: &PathBuf| -> Vec<String> {
+        read_to_string(path)
+            .unwrap()
+            .split('\n')
+            .filter_map(|line| line.split_once(LINES_SEPARATOR))
+            .map(|(original, _)| original.to_owned())
+            .collect()
+    };
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Force,
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    // Only English maps are translated
+    let maps_path = translation_path.join("en").join("maps.txt");
+    let filled = read_to_string(&maps_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once(LINES_SEPARATOR) {
+            Some((original, _)) if !original.starts_with("<!--") => format!("{original}{LINES_SEPARATOR}{original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_path, filled).unwrap();
+
+    assert_eq!(find_languages(&translation_path), ["en", "fr"]);
+
+    let reports = LanguageWriter::new(&data_path, &translation_path, &output_path, engine_type)
+        .options(WriterOptions {
+            dry_run: true,
+            ..WriterOptions::default()
+        })
+        .write();
+    assert_eq!(reports.len(), 2);
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    let fr = &stats.languages["fr"].files["maps.txt"];
+
+    assert_eq!(en.translated, en.total);
+    assert_eq!(fr.translated, 0);
+    assert!(stats.summary().starts_with("Total: en "));
+
+    // New language is read from scratch in append mode, and gets the same originals
+    let recorder = Arc::new(Recorder::default());
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into(), "de".into()])
+        .options(ReaderOptions {
+            processing_mode: ProcessingMode::Append,
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(find_languages(&translation_path), ["de", "en", "fr"]);
+    assert_eq!(
+        originals(&translation_path.join("de").join("maps.txt")),
+        originals(&maps_path)
+    );
+    assert!(recorder.warnings.lock().unwrap().is_empty());
+
+    let stats = StatsCollector::new(&translation_path, engine_type).collect_languages();
+    let en = &stats.languages["en"].files["maps.txt"];
+    assert_eq!(en.translated, en.total);
+
+    // Files, that differ from the first language, are reported
+    let items_path = translation_path.join("fr").join("items.txt");
+    let stale = read_to_string(&items_path).unwrap() + &format!("\nStale line{LINES_SEPARATOR}");
+    write(&items_path, stale).unwrap();
+
+    LanguageReader::new(&data_path, &translation_path, engine_type)
+        .languages(vec!["en".into(), "fr".into()])
+        .options(ReaderOptions {
+            observer: Some(recorder.clone()),
+            ..ReaderOptions::default()
+        })
+        .read();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [Warning::OriginalsMismatch {
+            file: "items.txt".into(),
+            language: "fr".into(),
+            reference: "en".into(),
+        }]
+    );
+
+    remove_dir_all(&translation_path).unwrap();
+}
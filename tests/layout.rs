@@ -0,0 +1,621 @@
+use rvpacker_txt_rs_lib::{
+    layout::{convert_maps_layout, map_file_name, split_maps},
+    purge::MapPurger,
+    read::MapReader,
+    types::{EngineType, MapsLayout, MapsProcessingMode, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, remove_file, write},
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::PathBuf,
+};
+
+#[test]
+fn map_names() {
+    assert_eq!(map_file_name(1, None), "map001.txt");
+    assert_eq!(map_file_name(12, Some("")), "map012.txt");
+    assert_eq!(map_file_name(1000, Some("Town")), "map1000_Town.txt");
+    assert_eq!(map_file_name(3, Some("A/B: \"C\"?")), "map003_A_B_ _C__.txt");
+    assert_eq!(map_file_name(4, Some(" Inn... ")), "map004_Inn.txt");
+    assert_eq!(map_file_name(5, Some("<>")), "map005___.txt");
+
+    // Header is dropped, lines before the first map belong to it, map names are unescaped
+    let content = [
+        "<!-- Format --><#>2",
+        "stray<#>",
+        "<!-- Map --><#>2",
+        "<!-- Map Name: A<#lt#>>B --><#>",
+        "first<#>1",
+        "<!-- Map --><#>1",
+        "second<#>2",
+    ]
+    .join("\n");
+    let maps: Vec<(u16, Option<String>, String)> = split_maps(&content)
+        .into_iter()
+        .map(|(number, (name, content))| (number, name, content))
+        .collect();
+
+    assert_eq!(
+        maps,
+        [
+            (
+                2,
+                Some(String::from("A<#>B")),
+                String::from("stray<#>\n<!-- Map --><#>2\n<!-- Map Name: A<#lt#>>B --><#>\nfirst<#>1")
+            ),
+            (1, None, String::from("<!-- Map --><#>1\nsecond<#>2")),
+        ]
+    );
+}
+
+#[test]
+fn layout_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_layout");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let fill = |content: &str| -> String {
+        content
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Layout"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let map_files = || -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(&maps_path)
+            .unwrap()
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().into_string().unwrap(),
+                    read_to_string(entry.path()).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .read();
+
+        let single: String = read_to_string(&maps_txt_path).unwrap();
+
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMapNamed, false);
+        assert!(!maps_txt_path.exists());
+
+        for (file_name, content) in map_files() {
+            assert!(file_name.starts_with("map") && file_name.ends_with(".txt"));
+            assert!(content.starts_with("<!-- Format --><#>"));
+            write(maps_path.join(file_name), fill(&content)).unwrap();
+        }
+
+        // Per-map files are kept as is through appending
+        let files: Vec<(String, String)> = map_files();
+        assert!(!files.is_empty());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .read();
+        assert_eq!(map_files(), files);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .write();
+        assert!(report.replacements.iter().any(|x| x.translation == "Layout"));
+
+        let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .purge(None, None);
+        assert!(purged.is_empty());
+
+        // Switching between per-map layouts keeps the content, including files, which names don't change
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::PerMap, false);
+        let unnamed: Vec<(String, String)> = map_files();
+        assert_eq!(unnamed.len(), files.len());
+        assert!(unnamed
+            .iter()
+            .all(|(file_name, _)| file_name.len() == "map000.txt".len()));
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMap, MapsLayout::PerMapNamed, false);
+        assert_eq!(map_files(), files);
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::Single, false);
+        assert!(map_files().is_empty());
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), fill(&single));
+    }
+
+    // The source translation is kept, if writing of the target layout fails
+    remove_dir_all(&maps_path).unwrap();
+    write(&maps_path, "").unwrap();
+    let single: String = read_to_string(&maps_txt_path).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMap, false)
+    }));
+    assert!(result.is_err());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), single);
+
+    remove_file(&maps_path).unwrap();
+}
+
+#[test]
+fn layout_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_layout");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let fill = |content: &str| -> String {
+        content
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Layout"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let map_files = || -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(&maps_path)
+            .unwrap()
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().into_string().unwrap(),
+                    read_to_string(entry.path()).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .read();
+
+        let single: String = read_to_string(&maps_txt_path).unwrap();
+
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMapNamed, false);
+        assert!(!maps_txt_path.exists());
+
+        for (file_name, content) in map_files() {
+            assert!(file_name.starts_with("map") && file_name.ends_with(".txt"));
+            assert!(content.starts_with("<!-- Format --><#>"));
+            write(maps_path.join(file_name), fill(&content)).unwrap();
+        }
+
+        // Per-map files are kept as is through appending
+        let files: Vec<(String, String)> = map_files();
+        assert!(!files.is_empty());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .read();
+        assert_eq!(map_files(), files);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .write();
+        assert!(report.replacements.iter().any(|x| x.translation == "Layout"));
+
+        let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .purge(None, None);
+        assert!(purged.is_empty());
+
+        // Switching between per-map layouts keeps the content, including files, which names don't change
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::PerMap, false);
+        let unnamed: Vec<(String, String)> = map_files();
+        assert_eq!(unnamed.len(), files.len());
+        assert!(unnamed
+            .iter()
+            .all(|(file_name, _)| file_name.len() == "map000.txt".len()));
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMap, MapsLayout::PerMapNamed, false);
+        assert_eq!(map_files(), files);
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::Single, false);
+        assert!(map_files().is_empty());
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), fill(&single));
+    }
+
+    // The source translation is kept, if writing of the target layout fails
+    remove_dir_all(&maps_path).unwrap();
+    write(&maps_path, "").unwrap();
+    let single: String = read_to_string(&maps_txt_path).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMap, false)
+    }));
+    assert!(result.is_err());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), single);
+
+    remove_file(&maps_path).unwrap();
+}
+
+#[test]
+fn layout_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_layout");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VXAce;
+    let maps_txt_path = translation_path.join("maps.txt");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let fill = |content: &str| -> String {
+        content
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Layout"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let map_files = || -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(&maps_path)
+            .unwrap()
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().into_string().unwrap(),
+                    read_to_string(entry.path()).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .read();
+
+        let single: String = read_to_string(&maps_txt_path).unwrap();
+
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMapNamed, false);
+        assert!(!maps_txt_path.exists());
+
+        for (file_name, content) in map_files() {
+            assert!(file_name.starts_with("map") && file_name.ends_with(".txt"));
+            assert!(content.starts_with("<!-- Format --><#>"));
+            write(maps_path.join(file_name), fill(&content)).unwrap();
+        }
+
+        // Per-map files are kept as is through appending
+        let files: Vec<(String, String)> = map_files();
+        assert!(!files.is_empty());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .read();
+        assert_eq!(map_files(), files);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .write();
+        assert!(report.replacements.iter().any(|x| x.translation == "Layout"));
+
+        let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .purge(None, None);
+        assert!(purged.is_empty());
+
+        // Switching between per-map layouts keeps the content, including files, which names don't change
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::PerMap, false);
+        let unnamed: Vec<(String, String)> = map_files();
+        assert_eq!(unnamed.len(), files.len());
+        assert!(unnamed
+            .iter()
+            .all(|(file_name, _)| file_name.len() == "map000.txt".len()));
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMap, MapsLayout::PerMapNamed, false);
+        assert_eq!(map_files(), files);
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::Single, false);
+        assert!(map_files().is_empty());
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), fill(&single));
+    }
+
+    // The source translation is kept, if writing of the target layout fails
+    remove_dir_all(&maps_path).unwrap();
+    write(&maps_path, "").unwrap();
+    let single: String = read_to_string(&maps_txt_path).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMap, false)
+    }));
+    assert!(result.is_err());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), single);
+
+    remove_file(&maps_path).unwrap();
+}
+
+#[test]
+fn layout_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_layout");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VX;
+    let maps_txt_path = translation_path.join("maps.txt");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let fill = |content: &str| -> String {
+        content
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Layout"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let map_files = || -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(&maps_path)
+            .unwrap()
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().into_string().unwrap(),
+                    read_to_string(entry.path()).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .read();
+
+        let single: String = read_to_string(&maps_txt_path).unwrap();
+
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMapNamed, false);
+        assert!(!maps_txt_path.exists());
+
+        for (file_name, content) in map_files() {
+            assert!(file_name.starts_with("map") && file_name.ends_with(".txt"));
+            assert!(content.starts_with("<!-- Format --><#>"));
+            write(maps_path.join(file_name), fill(&content)).unwrap();
+        }
+
+        // Per-map files are kept as is through appending
+        let files: Vec<(String, String)> = map_files();
+        assert!(!files.is_empty());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .read();
+        assert_eq!(map_files(), files);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .write();
+        assert!(report.replacements.iter().any(|x| x.translation == "Layout"));
+
+        let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .purge(None, None);
+        assert!(purged.is_empty());
+
+        // Switching between per-map layouts keeps the content, including files, which names don't change
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::PerMap, false);
+        let unnamed: Vec<(String, String)> = map_files();
+        assert_eq!(unnamed.len(), files.len());
+        assert!(unnamed
+            .iter()
+            .all(|(file_name, _)| file_name.len() == "map000.txt".len()));
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMap, MapsLayout::PerMapNamed, false);
+        assert_eq!(map_files(), files);
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::Single, false);
+        assert!(map_files().is_empty());
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), fill(&single));
+    }
+
+    // The source translation is kept, if writing of the target layout fails
+    remove_dir_all(&maps_path).unwrap();
+    write(&maps_path, "").unwrap();
+    let single: String = read_to_string(&maps_txt_path).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMap, false)
+    }));
+    assert!(result.is_err());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), single);
+
+    remove_file(&maps_path).unwrap();
+}
+
+#[test]
+fn layout_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_layout");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::XP;
+    let maps_txt_path = translation_path.join("maps.txt");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let fill = |content: &str| -> String {
+        content
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>Layout"),
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let map_files = || -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(&maps_path)
+            .unwrap()
+            .flatten()
+            .map(|entry| {
+                (
+                    entry.file_name().into_string().unwrap(),
+                    read_to_string(entry.path()).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .read();
+
+        let single: String = read_to_string(&maps_txt_path).unwrap();
+
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMapNamed, false);
+        assert!(!maps_txt_path.exists());
+
+        for (file_name, content) in map_files() {
+            assert!(file_name.starts_with("map") && file_name.ends_with(".txt"));
+            assert!(content.starts_with("<!-- Format --><#>"));
+            write(maps_path.join(file_name), fill(&content)).unwrap();
+        }
+
+        // Per-map files are kept as is through appending
+        let files: Vec<(String, String)> = map_files();
+        assert!(!files.is_empty());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .read();
+        assert_eq!(map_files(), files);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .write();
+        assert!(report.replacements.iter().any(|x| x.translation == "Layout"));
+
+        let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .maps_layout(MapsLayout::PerMapNamed)
+            .dry_run(true)
+            .purge(None, None);
+        assert!(purged.is_empty());
+
+        // Switching between per-map layouts keeps the content, including files, which names don't change
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::PerMap, false);
+        let unnamed: Vec<(String, String)> = map_files();
+        assert_eq!(unnamed.len(), files.len());
+        assert!(unnamed
+            .iter()
+            .all(|(file_name, _)| file_name.len() == "map000.txt".len()));
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMap, MapsLayout::PerMapNamed, false);
+        assert_eq!(map_files(), files);
+
+        convert_maps_layout(&translation_path, MapsLayout::PerMapNamed, MapsLayout::Single, false);
+        assert!(map_files().is_empty());
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), fill(&single));
+    }
+
+    // The source translation is kept, if writing of the target layout fails
+    remove_dir_all(&maps_path).unwrap();
+    write(&maps_path, "").unwrap();
+    let single: String = read_to_string(&maps_txt_path).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        convert_maps_layout(&translation_path, MapsLayout::Single, MapsLayout::PerMap, false)
+    }));
+    assert!(result.is_err());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), single);
+
+    remove_file(&maps_path).unwrap();
+}
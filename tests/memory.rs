@@ -0,0 +1,241 @@
+use rvpacker_txt_rs_lib::{
+    memory::{parse_review, similarity, TranslationMemory},
+    read::OtherReader,
+    types::{EngineType, MapsLayout, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+#[test]
+fn similarity_scores() {
+    let chars = |text: &str| -> Vec<char> { text.chars().collect() };
+
+    assert_eq!(similarity(&chars(""), &chars("")), 1.0);
+    assert_eq!(similarity(&chars("abc"), &chars("abc")), 1.0);
+    assert_eq!(similarity(&chars("abc"), &chars("")), 0.0);
+    assert_eq!(similarity(&chars("abc"), &chars("xyz")), 0.0);
+    assert_eq!(similarity(&chars("kitten"), &chars("sitting")), 1.0 - 3.0 / 7.0);
+    assert_eq!(
+        similarity(&chars("ポーション"), &chars("ハイポーション")),
+        1.0 - 2.0 / 7.0
+    );
+    assert_eq!(
+        similarity(&chars("kitten"), &chars("sitting")),
+        similarity(&chars("sitting"), &chars("kitten"))
+    );
+}
+
+#[test]
+fn fuzzy_matches() {
+    let mut memory = TranslationMemory::new();
+    memory.insert("ポーション".into(), "Potion".into());
+    memory.insert("ハイポーション".into(), "Hi-Potion".into());
+    memory.insert("abcdx".into(), "First".into());
+    memory.insert("abcdy".into(), "Second".into());
+
+    let exact = memory.fuzzy("ポーション").unwrap();
+    assert_eq!((exact.translation, exact.similarity), ("Potion", 1.0));
+
+    // Edit distance is exactly on the threshold
+    let tie = memory.fuzzy("abcdz").unwrap();
+    assert_eq!((tie.original, tie.translation), ("abcdx", "First"));
+    assert_eq!(tie.similarity, 1.0 - 1.0 / 5.0);
+
+    assert!(memory.fuzzy("zzzzz").is_none());
+    assert!(memory
+        .fuzzy("ハイポーショ")
+        .is_some_and(|x| x.translation == "Hi-Potion"));
+
+    // Index is rebuilt after insertion
+    memory.insert("abcdz".into(), "Third".into());
+    assert_eq!(memory.fuzzy("abcdz").unwrap().translation, "Third");
+
+    let memory = memory.threshold(0.7);
+    let suggestion = memory.fuzzy("ハイポーシ").unwrap();
+    assert_eq!(suggestion.translation, "Hi-Potion");
+    assert_eq!(suggestion.similarity, 1.0 - 2.0 / 7.0);
+
+    let memory = memory.threshold(1.0);
+    assert!(memory.fuzzy("abcdw").is_none());
+}
+
+#[test]
+fn per_map_import() {
+    let translation_path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("memory_per_map");
+    let maps_path = translation_path.join("maps");
+
+    create_dir_all(&maps_path).unwrap();
+    write(translation_path.join("items.txt"), "Potion<#>Зелье").unwrap();
+    write(maps_path.join("map001.txt"), "<!-- Map --><#>1\nHello<#>Привет").unwrap();
+    write(maps_path.join("map002_Town.txt"), "<!-- Map --><#>2\nGoodbye<#>Пока").unwrap();
+
+    // Per-map files are only imported with per-map layout
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+    assert_eq!(memory.len(), 1);
+
+    let mut memory = TranslationMemory::new().maps_layout(MapsLayout::PerMapNamed);
+    memory.import(&translation_path);
+    assert_eq!(memory.len(), 3);
+    assert_eq!(memory.get("Hello"), Some("Привет"));
+    assert_eq!(memory.get("Goodbye"), Some("Пока"));
+}
+
+#[test]
+fn memory_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .memory(Some(Arc::new(memory.clone())))
+        .read();
+
+    for (original, (translation, similarity)) in parse_review(&translation_path).values().flatten() {
+        if *similarity >= 1.0 {
+            assert_eq!(memory.get(original), Some(translation.as_str()));
+        }
+    }
+
+    let content = read_to_string(translation_path.join("items.txt")).unwrap();
+    assert!(!content.is_empty());
+}
+
+#[test]
+fn memory_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .memory(Some(Arc::new(memory.clone())))
+        .read();
+
+    for (original, (translation, similarity)) in parse_review(&translation_path).values().flatten() {
+        if *similarity >= 1.0 {
+            assert_eq!(memory.get(original), Some(translation.as_str()));
+        }
+    }
+
+    let content = read_to_string(translation_path.join("items.txt")).unwrap();
+    assert!(!content.is_empty());
+}
+
+#[test]
+fn memory_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .memory(Some(Arc::new(memory.clone())))
+        .read();
+
+    for (original, (translation, similarity)) in parse_review(&translation_path).values().flatten() {
+        if *similarity >= 1.0 {
+            assert_eq!(memory.get(original), Some(translation.as_str()));
+        }
+    }
+
+    let content = read_to_string(translation_path.join("items.txt")).unwrap();
+    assert!(!content.is_empty());
+}
+
+#[test]
+fn memory_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .memory(Some(Arc::new(memory.clone())))
+        .read();
+
+    for (original, (translation, similarity)) in parse_review(&translation_path).values().flatten() {
+        if *similarity >= 1.0 {
+            assert_eq!(memory.get(original), Some(translation.as_str()));
+        }
+    }
+
+    let content = read_to_string(translation_path.join("items.txt")).unwrap();
+    assert!(!content.is_empty());
+}
+
+#[test]
+fn memory_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let mut memory = TranslationMemory::new();
+    memory.import(&translation_path);
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .memory(Some(Arc::new(memory.clone())))
+        .read();
+
+    for (original, (translation, similarity)) in parse_review(&translation_path).values().flatten() {
+        if *similarity >= 1.0 {
+            assert_eq!(memory.get(original), Some(translation.as_str()));
+        }
+    }
+
+    let content = read_to_string(translation_path.join("items.txt")).unwrap();
+    assert!(!content.is_empty());
+}
@@ -0,0 +1,533 @@
+use marshal_rs::{dump, load, StringMode};
+use rvpacker_txt_rs_lib::{
+    comments::NEEDS_REVIEW_COMMENT,
+    migrate::Migrator,
+    read::{MapReader, OtherReader, SystemReader},
+    types::{EngineType, ProcessingMode},
+};
+use sonic_rs::{from_str, to_string, Value};
+use std::{
+    env::var,
+    fs::{copy, create_dir_all, read, read_dir, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+};
+
+#[test]
+fn migrate_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let old_path = game_path.join("migrate_old");
+    let new_path = game_path.join("migrate_new");
+    let translation_path = game_path.join("translation_migrate");
+    let work_path = game_path.join("migrate");
+    let engine_type = EngineType::New;
+
+    // The update changes the name of the first item, removes the name of the second item and adds its
+    // description
+    for (path, name, removed, added) in [
+        (&old_path, "Blade of the Old Kingdom", "Xyzzy", ""),
+        (
+            &new_path,
+            "Blade of the Old Kingdoms",
+            "",
+            "Completely unrelated newcomer text",
+        ),
+    ] {
+        create_dir_all(path).unwrap();
+
+        for entry in read_dir(&data_path).unwrap().flatten() {
+            copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+
+        let items_path = path.join("Items.json");
+        let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+        items[1]["name"] = name.into();
+        items[1]["description"] = "Kept description line".into();
+        items[2]["name"] = removed.into();
+        items[2]["description"] = added.into();
+        write(&items_path, to_string(&items).unwrap()).unwrap();
+    }
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    SystemReader::new(&old_path.join("System.json"), &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line of items is translated
+    let items_txt_path = translation_path.join("items.txt");
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, translated).unwrap();
+
+    let report = Migrator::new(&old_path, &new_path, &translation_path, &work_path, engine_type).migrate();
+
+    let changed_files: Vec<&String> = report.changed_files().map(|(file, _)| file).collect();
+    assert_eq!(changed_files, ["items.txt"]);
+
+    let migration = &report.files["items.txt"];
+    assert_eq!(migration.added, ["Completely unrelated newcomer text"]);
+    assert_eq!(migration.removed, [(String::from("Xyzzy"), String::from("TR Xyzzy"))]);
+    assert_eq!(migration.changed.len(), 1);
+
+    let changed = &migration.changed[0];
+    assert_eq!(changed.old_original, "Blade of the Old Kingdom");
+    assert_eq!(changed.new_original, "Blade of the Old Kingdoms");
+    assert_eq!(changed.translation, "TR Blade of the Old Kingdom");
+    assert!(changed.by_location);
+    assert!(changed.similarity > 0.9 && changed.similarity < 1.0);
+
+    let items = read_to_string(&items_txt_path).unwrap();
+
+    // The changed line keeps its translation, and is marked for review
+    assert!(items.contains(&format!(
+        "{NEEDS_REVIEW_COMMENT}<#>Blade of the Old Kingdom\n<!-- State --><#>needs-review\nBlade of the Old Kingdoms<#>TR Blade of the Old Kingdom\n"
+    )));
+    // Unchanged lines keep their translations
+    assert!(items.contains("\nKept description line<#>TR Kept description line"));
+    // The added line is untranslated
+    assert!(items.contains(
+        "
+Completely unrelated newcomer text<#>
+"
+    ));
+    // The removed line is kept, so it can be purged later
+    assert!(items.contains(
+        "
+Xyzzy<#>TR Xyzzy"
+    ));
+    assert_eq!(items.matches(NEEDS_REVIEW_COMMENT).count(), 1);
+}
+
+#[test]
+fn migrate_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let old_path = game_path.join("migrate_old");
+    let new_path = game_path.join("migrate_new");
+    let translation_path = game_path.join("translation_migrate");
+    let work_path = game_path.join("migrate");
+    let engine_type = EngineType::New;
+
+    // The update changes the name of the first item, removes the name of the second item and adds its
+    // description
+    for (path, name, removed, added) in [
+        (&old_path, "Blade of the Old Kingdom", "Xyzzy", ""),
+        (
+            &new_path,
+            "Blade of the Old Kingdoms",
+            "",
+            "Completely unrelated newcomer text",
+        ),
+    ] {
+        create_dir_all(path).unwrap();
+
+        for entry in read_dir(&data_path).unwrap().flatten() {
+            copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+
+        let items_path = path.join("Items.json");
+        let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+        items[1]["name"] = name.into();
+        items[1]["description"] = "Kept description line".into();
+        items[2]["name"] = removed.into();
+        items[2]["description"] = added.into();
+        write(&items_path, to_string(&items).unwrap()).unwrap();
+    }
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    SystemReader::new(&old_path.join("System.json"), &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line of items is translated
+    let items_txt_path = translation_path.join("items.txt");
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, translated).unwrap();
+
+    let report = Migrator::new(&old_path, &new_path, &translation_path, &work_path, engine_type).migrate();
+
+    let changed_files: Vec<&String> = report.changed_files().map(|(file, _)| file).collect();
+    assert_eq!(changed_files, ["items.txt"]);
+
+    let migration = &report.files["items.txt"];
+    assert_eq!(migration.added, ["Completely unrelated newcomer text"]);
+    assert_eq!(migration.removed, [(String::from("Xyzzy"), String::from("TR Xyzzy"))]);
+    assert_eq!(migration.changed.len(), 1);
+
+    let changed = &migration.changed[0];
+    assert_eq!(changed.old_original, "Blade of the Old Kingdom");
+    assert_eq!(changed.new_original, "Blade of the Old Kingdoms");
+    assert_eq!(changed.translation, "TR Blade of the Old Kingdom");
+    assert!(changed.by_location);
+    assert!(changed.similarity > 0.9 && changed.similarity < 1.0);
+
+    let items = read_to_string(&items_txt_path).unwrap();
+
+    // The changed line keeps its translation, and is marked for review
+    assert!(items.contains(&format!(
+        "{NEEDS_REVIEW_COMMENT}<#>Blade of the Old Kingdom\n<!-- State --><#>needs-review\nBlade of the Old Kingdoms<#>TR Blade of the Old Kingdom\n"
+    )));
+    // Unchanged lines keep their translations
+    assert!(items.contains("\nKept description line<#>TR Kept description line"));
+    // The added line is untranslated
+    assert!(items.contains(
+        "
+Completely unrelated newcomer text<#>
+"
+    ));
+    // The removed line is kept, so it can be purged later
+    assert!(items.contains(
+        "
+Xyzzy<#>TR Xyzzy"
+    ));
+    assert_eq!(items.matches(NEEDS_REVIEW_COMMENT).count(), 1);
+}
+
+#[test]
+fn migrate_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let old_path = game_path.join("migrate_old");
+    let new_path = game_path.join("migrate_new");
+    let translation_path = game_path.join("translation_migrate");
+    let work_path = game_path.join("migrate");
+    let engine_type = EngineType::VXAce;
+
+    // The update changes the name of the first item, removes the name of the second item and adds its
+    // description
+    for (path, name, removed, added) in [
+        (&old_path, "Blade of the Old Kingdom", "Xyzzy", ""),
+        (
+            &new_path,
+            "Blade of the Old Kingdoms",
+            "",
+            "Completely unrelated newcomer text",
+        ),
+    ] {
+        create_dir_all(path).unwrap();
+
+        for entry in read_dir(&data_path).unwrap().flatten() {
+            copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+
+        let items_path = path.join("Items.rvdata2");
+        let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+        items[1]["__symbol__name"] = name.into();
+        items[1]["__symbol__description"] = "Kept description line".into();
+        items[2]["__symbol__name"] = removed.into();
+        items[2]["__symbol__description"] = added.into();
+        write(&items_path, dump(items, Some(""))).unwrap();
+    }
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    SystemReader::new(&old_path.join("System.rvdata2"), &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line of items is translated
+    let items_txt_path = translation_path.join("items.txt");
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, translated).unwrap();
+
+    let report = Migrator::new(&old_path, &new_path, &translation_path, &work_path, engine_type).migrate();
+
+    let changed_files: Vec<&String> = report.changed_files().map(|(file, _)| file).collect();
+    assert_eq!(changed_files, ["items.txt"]);
+
+    let migration = &report.files["items.txt"];
+    assert_eq!(migration.added, ["Completely unrelated newcomer text"]);
+    assert_eq!(migration.removed, [(String::from("Xyzzy"), String::from("TR Xyzzy"))]);
+    assert_eq!(migration.changed.len(), 1);
+
+    let changed = &migration.changed[0];
+    assert_eq!(changed.old_original, "Blade of the Old Kingdom");
+    assert_eq!(changed.new_original, "Blade of the Old Kingdoms");
+    assert_eq!(changed.translation, "TR Blade of the Old Kingdom");
+    assert!(changed.by_location);
+    assert!(changed.similarity > 0.9 && changed.similarity < 1.0);
+
+    let items = read_to_string(&items_txt_path).unwrap();
+
+    // The changed line keeps its translation, and is marked for review
+    assert!(items.contains(&format!(
+        "{NEEDS_REVIEW_COMMENT}<#>Blade of the Old Kingdom\n<!-- State --><#>needs-review\nBlade of the Old Kingdoms<#>TR Blade of the Old Kingdom\n"
+    )));
+    // Unchanged lines keep their translations
+    assert!(items.contains("\nKept description line<#>TR Kept description line"));
+    // The added line is untranslated
+    assert!(items.contains(
+        "
+Completely unrelated newcomer text<#>
+"
+    ));
+    // The removed line is kept, so it can be purged later
+    assert!(items.contains(
+        "
+Xyzzy<#>TR Xyzzy"
+    ));
+    assert_eq!(items.matches(NEEDS_REVIEW_COMMENT).count(), 1);
+}
+
+#[test]
+fn migrate_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let old_path = game_path.join("migrate_old");
+    let new_path = game_path.join("migrate_new");
+    let translation_path = game_path.join("translation_migrate");
+    let work_path = game_path.join("migrate");
+    let engine_type = EngineType::VX;
+
+    // The update changes the name of the first item, removes the name of the second item and adds its
+    // description
+    for (path, name, removed, added) in [
+        (&old_path, "Blade of the Old Kingdom", "Xyzzy", ""),
+        (
+            &new_path,
+            "Blade of the Old Kingdoms",
+            "",
+            "Completely unrelated newcomer text",
+        ),
+    ] {
+        create_dir_all(path).unwrap();
+
+        for entry in read_dir(&data_path).unwrap().flatten() {
+            copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+
+        let items_path = path.join("Items.rvdata");
+        let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+        items[1]["__symbol__name"] = name.into();
+        items[1]["__symbol__description"] = "Kept description line".into();
+        items[2]["__symbol__name"] = removed.into();
+        items[2]["__symbol__description"] = added.into();
+        write(&items_path, dump(items, Some(""))).unwrap();
+    }
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    SystemReader::new(&old_path.join("System.rvdata"), &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line of items is translated
+    let items_txt_path = translation_path.join("items.txt");
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, translated).unwrap();
+
+    let report = Migrator::new(&old_path, &new_path, &translation_path, &work_path, engine_type).migrate();
+
+    let changed_files: Vec<&String> = report.changed_files().map(|(file, _)| file).collect();
+    assert_eq!(changed_files, ["items.txt"]);
+
+    let migration = &report.files["items.txt"];
+    assert_eq!(migration.added, ["Completely unrelated newcomer text"]);
+    assert_eq!(migration.removed, [(String::from("Xyzzy"), String::from("TR Xyzzy"))]);
+    assert_eq!(migration.changed.len(), 1);
+
+    let changed = &migration.changed[0];
+    assert_eq!(changed.old_original, "Blade of the Old Kingdom");
+    assert_eq!(changed.new_original, "Blade of the Old Kingdoms");
+    assert_eq!(changed.translation, "TR Blade of the Old Kingdom");
+    assert!(changed.by_location);
+    assert!(changed.similarity > 0.9 && changed.similarity < 1.0);
+
+    let items = read_to_string(&items_txt_path).unwrap();
+
+    // The changed line keeps its translation, and is marked for review
+    assert!(items.contains(&format!(
+        "{NEEDS_REVIEW_COMMENT}<#>Blade of the Old Kingdom\n<!-- State --><#>needs-review\nBlade of the Old Kingdoms<#>TR Blade of the Old Kingdom\n"
+    )));
+    // Unchanged lines keep their translations
+    assert!(items.contains("\nKept description line<#>TR Kept description line"));
+    // The added line is untranslated
+    assert!(items.contains(
+        "
+Completely unrelated newcomer text<#>
+"
+    ));
+    // The removed line is kept, so it can be purged later
+    assert!(items.contains(
+        "
+Xyzzy<#>TR Xyzzy"
+    ));
+    assert_eq!(items.matches(NEEDS_REVIEW_COMMENT).count(), 1);
+}
+
+#[test]
+fn migrate_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let old_path = game_path.join("migrate_old");
+    let new_path = game_path.join("migrate_new");
+    let translation_path = game_path.join("translation_migrate");
+    let work_path = game_path.join("migrate");
+    let engine_type = EngineType::XP;
+
+    // The update changes the name of the first item, removes the name of the second item and adds its
+    // description
+    for (path, name, removed, added) in [
+        (&old_path, "Blade of the Old Kingdom", "Xyzzy", ""),
+        (
+            &new_path,
+            "Blade of the Old Kingdoms",
+            "",
+            "Completely unrelated newcomer text",
+        ),
+    ] {
+        create_dir_all(path).unwrap();
+
+        for entry in read_dir(&data_path).unwrap().flatten() {
+            copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+
+        let items_path = path.join("Items.rxdata");
+        let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+        items[1]["__symbol__name"] = name.into();
+        items[1]["__symbol__description"] = "Kept description line".into();
+        items[2]["__symbol__name"] = removed.into();
+        items[2]["__symbol__description"] = added.into();
+        write(&items_path, dump(items, Some(""))).unwrap();
+    }
+
+    if translation_path.exists() {
+        remove_dir_all(&translation_path).unwrap();
+    }
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&old_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    SystemReader::new(&old_path.join("System.rxdata"), &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line of items is translated
+    let items_txt_path = translation_path.join("items.txt");
+    let translated: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!--") => format!("{original}<#>TR {original}"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, translated).unwrap();
+
+    let report = Migrator::new(&old_path, &new_path, &translation_path, &work_path, engine_type).migrate();
+
+    let changed_files: Vec<&String> = report.changed_files().map(|(file, _)| file).collect();
+    assert_eq!(changed_files, ["items.txt"]);
+
+    let migration = &report.files["items.txt"];
+    assert_eq!(migration.added, ["Completely unrelated newcomer text"]);
+    assert_eq!(migration.removed, [(String::from("Xyzzy"), String::from("TR Xyzzy"))]);
+    assert_eq!(migration.changed.len(), 1);
+
+    let changed = &migration.changed[0];
+    assert_eq!(changed.old_original, "Blade of the Old Kingdom");
+    assert_eq!(changed.new_original, "Blade of the Old Kingdoms");
+    assert_eq!(changed.translation, "TR Blade of the Old Kingdom");
+    assert!(changed.by_location);
+    assert!(changed.similarity > 0.9 && changed.similarity < 1.0);
+
+    let items = read_to_string(&items_txt_path).unwrap();
+
+    // The changed line keeps its translation, and is marked for review
+    assert!(items.contains(&format!(
+        "{NEEDS_REVIEW_COMMENT}<#>Blade of the Old Kingdom\n<!-- State --><#>needs-review\nBlade of the Old Kingdoms<#>TR Blade of the Old Kingdom\n"
+    )));
+    // Unchanged lines keep their translations
+    assert!(items.contains("\nKept description line<#>TR Kept description line"));
+    // The added line is untranslated
+    assert!(items.contains(
+        "
+Completely unrelated newcomer text<#>
+"
+    ));
+    // The removed line is kept, so it can be purged later
+    assert!(items.contains(
+        "
+Xyzzy<#>TR Xyzzy"
+    ));
+    assert_eq!(items.matches(NEEDS_REVIEW_COMMENT).count(), 1);
+}
@@ -0,0 +1,365 @@
+use rvpacker_txt_rs_lib::{
+    read::MapReader,
+    types::{EngineType, MapsProcessingMode, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn names_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        let translated: String = read_to_string(&maps_txt_path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, ""))
+                    if original.starts_with("<!-- Map Name: ") || original.starts_with("<!-- Event Name: ") =>
+                {
+                    format!("{original}<#>Translated")
+                }
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(translated.contains("<!-- Event Name: "));
+        write(&maps_txt_path, &translated).unwrap();
+
+        // Translation of names survives appending
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .map_names(true)
+            .event_names(true)
+            .dry_run(true)
+            .write();
+
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("MapInfos") && x.translation == "Translated"));
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("Map0") && x.translation == "Translated"));
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn names_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        let translated: String = read_to_string(&maps_txt_path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, ""))
+                    if original.starts_with("<!-- Map Name: ") || original.starts_with("<!-- Event Name: ") =>
+                {
+                    format!("{original}<#>Translated")
+                }
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(translated.contains("<!-- Event Name: "));
+        write(&maps_txt_path, &translated).unwrap();
+
+        // Translation of names survives appending
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .map_names(true)
+            .event_names(true)
+            .dry_run(true)
+            .write();
+
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("MapInfos") && x.translation == "Translated"));
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("Map0") && x.translation == "Translated"));
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn names_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VXAce;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        let translated: String = read_to_string(&maps_txt_path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, ""))
+                    if original.starts_with("<!-- Map Name: ") || original.starts_with("<!-- Event Name: ") =>
+                {
+                    format!("{original}<#>Translated")
+                }
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(translated.contains("<!-- Event Name: "));
+        write(&maps_txt_path, &translated).unwrap();
+
+        // Translation of names survives appending
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .map_names(true)
+            .event_names(true)
+            .dry_run(true)
+            .write();
+
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("MapInfos") && x.translation == "Translated"));
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("Map0") && x.translation == "Translated"));
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn names_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::VX;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        let translated: String = read_to_string(&maps_txt_path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, ""))
+                    if original.starts_with("<!-- Map Name: ") || original.starts_with("<!-- Event Name: ") =>
+                {
+                    format!("{original}<#>Translated")
+                }
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(translated.contains("<!-- Event Name: "));
+        write(&maps_txt_path, &translated).unwrap();
+
+        // Translation of names survives appending
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .map_names(true)
+            .event_names(true)
+            .dry_run(true)
+            .write();
+
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("MapInfos") && x.translation == "Translated"));
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("Map0") && x.translation == "Translated"));
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn names_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let engine_type = EngineType::XP;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        let translated: String = read_to_string(&maps_txt_path)
+            .unwrap()
+            .split('\n')
+            .map(|line| match line.split_once("<#>") {
+                Some((original, ""))
+                    if original.starts_with("<!-- Map Name: ") || original.starts_with("<!-- Event Name: ") =>
+                {
+                    format!("{original}<#>Translated")
+                }
+                _ => line.to_owned(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(translated.contains("<!-- Event Name: "));
+        write(&maps_txt_path, &translated).unwrap();
+
+        // Translation of names survives appending
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .event_names(true)
+            .read();
+
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+
+        let report = MapWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .maps_processing_mode(maps_processing_mode)
+            .map_names(true)
+            .event_names(true)
+            .dry_run(true)
+            .write();
+
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("MapInfos") && x.translation == "Translated"));
+        assert!(report
+            .replacements
+            .iter()
+            .any(|x| x.file.starts_with("Map0") && x.translation == "Translated"));
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
@@ -0,0 +1,236 @@
+use rvpacker_txt_rs_lib::{
+    note::{note_comment, parse_notes},
+    purge::MapPurger,
+    read::MapReader,
+    types::{EngineType, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn notes_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let noted = read_to_string(translation_path.join("maps.txt"))
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}", note_comment("Keep it short\nNo honorifics"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(translation_path.join("maps.txt"), &noted).unwrap();
+
+    // Notes survive appending and purging
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    MapPurger::new(&data_path, &translation_path, engine_type).purge(None, None);
+    let purged = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(noted, purged);
+    assert!(parse_notes(&purged)
+        .iter()
+        .all(|(_, _, notes)| notes == &["Keep it short\nNo honorifics"]));
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn notes_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let noted = read_to_string(translation_path.join("maps.txt"))
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}", note_comment("Keep it short\nNo honorifics"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(translation_path.join("maps.txt"), &noted).unwrap();
+
+    // Notes survive appending and purging
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    MapPurger::new(&data_path, &translation_path, engine_type).purge(None, None);
+    let purged = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(noted, purged);
+    assert!(parse_notes(&purged)
+        .iter()
+        .all(|(_, _, notes)| notes == &["Keep it short\nNo honorifics"]));
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn notes_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let noted = read_to_string(translation_path.join("maps.txt"))
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}", note_comment("Keep it short\nNo honorifics"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(translation_path.join("maps.txt"), &noted).unwrap();
+
+    // Notes survive appending and purging
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    MapPurger::new(&data_path, &translation_path, engine_type).purge(None, None);
+    let purged = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(noted, purged);
+    assert!(parse_notes(&purged)
+        .iter()
+        .all(|(_, _, notes)| notes == &["Keep it short\nNo honorifics"]));
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn notes_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let noted = read_to_string(translation_path.join("maps.txt"))
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}", note_comment("Keep it short\nNo honorifics"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(translation_path.join("maps.txt"), &noted).unwrap();
+
+    // Notes survive appending and purging
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    MapPurger::new(&data_path, &translation_path, engine_type).purge(None, None);
+    let purged = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(noted, purged);
+    assert!(parse_notes(&purged)
+        .iter()
+        .all(|(_, _, notes)| notes == &["Keep it short\nNo honorifics"]));
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
+
+#[test]
+fn notes_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let noted = read_to_string(translation_path.join("maps.txt"))
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}", note_comment("Keep it short\nNo honorifics"))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(translation_path.join("maps.txt"), &noted).unwrap();
+
+    // Notes survive appending and purging
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    MapPurger::new(&data_path, &translation_path, engine_type).purge(None, None);
+    let purged = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    assert_eq!(noted, purged);
+    assert!(parse_notes(&purged)
+        .iter()
+        .all(|(_, _, notes)| notes == &["Keep it short\nNo honorifics"]));
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+}
@@ -0,0 +1,567 @@
+use rvpacker_txt_rs_lib::{
+    observer::{FileAction, Observer, SkipReason, Warning},
+    read::{MapReader, OtherReader},
+    types::{EngineType, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+#[test]
+fn observer_mz() {
+    #[derive(Default)]
+    struct Recorder {
+        found: Mutex<usize>,
+        started: Mutex<Vec<String>>,
+        finished: Mutex<Vec<(String, FileAction)>>,
+        skipped: Mutex<Vec<(String, SkipReason)>>,
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn files_found(&self, count: usize) {
+            *self.found.lock().unwrap() += count;
+        }
+
+        fn file_started(&self, file: &str) {
+            self.started.lock().unwrap().push(file.to_owned());
+        }
+
+        fn file_finished(&self, file: &str, action: FileAction) {
+            self.finished.lock().unwrap().push((file.to_owned(), action));
+        }
+
+        fn file_skipped(&self, file: &str, reason: SkipReason) {
+            self.skipped.lock().unwrap().push((file.to_owned(), reason));
+        }
+
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_observer");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    let mut maps: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .collect();
+    maps.sort();
+
+    // Every found map is started and parsed
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(*recorder.found.lock().unwrap(), maps.len());
+    assert_eq!(*recorder.started.lock().unwrap(), maps);
+    assert_eq!(
+        *recorder.finished.lock().unwrap(),
+        maps.iter()
+            .map(|name| (name.clone(), FileAction::Parsed))
+            .collect::<Vec<_>>()
+    );
+
+    let recorder = Arc::new(Recorder::default());
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    let finished = recorder.finished.lock().unwrap();
+    assert!(*recorder.found.lock().unwrap() > 0);
+    assert_eq!(finished.len(), *recorder.found.lock().unwrap());
+    assert!(finished.iter().all(|(_, action)| *action == FileAction::Parsed));
+    assert!(translation_path.join("items.txt").exists());
+
+    // Existing translation isn't overwritten in the default mode
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [(String::from("maps.txt"), SkipReason::AlreadyExists)]
+    );
+    assert!(recorder.finished.lock().unwrap().is_empty());
+
+    // Lines without the separator are reported as warnings
+    let maps_path = translation_path.join("maps.txt");
+    let content = read_to_string(&maps_path).unwrap();
+    write(&maps_path, content + "\nBroken line").unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    MapWriter::new(
+        &data_path,
+        &translation_path,
+        &game_path.join("output_observer"),
+        engine_type,
+    )
+    .dry_run(true)
+    .observer(recorder.clone())
+    .write();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        Warning::UnsplittableLine { file, content, .. } if file == "maps.txt" && content == "Broken line"
+    ));
+}
+
+#[test]
+fn observer_mv() {
+    #[derive(Default)]
+    struct Recorder {
+        found: Mutex<usize>,
+        started: Mutex<Vec<String>>,
+        finished: Mutex<Vec<(String, FileAction)>>,
+        skipped: Mutex<Vec<(String, SkipReason)>>,
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn files_found(&self, count: usize) {
+            *self.found.lock().unwrap() += count;
+        }
+
+        fn file_started(&self, file: &str) {
+            self.started.lock().unwrap().push(file.to_owned());
+        }
+
+        fn file_finished(&self, file: &str, action: FileAction) {
+            self.finished.lock().unwrap().push((file.to_owned(), action));
+        }
+
+        fn file_skipped(&self, file: &str, reason: SkipReason) {
+            self.skipped.lock().unwrap().push((file.to_owned(), reason));
+        }
+
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_observer");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    let mut maps: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .collect();
+    maps.sort();
+
+    // Every found map is started and parsed
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(*recorder.found.lock().unwrap(), maps.len());
+    assert_eq!(*recorder.started.lock().unwrap(), maps);
+    assert_eq!(
+        *recorder.finished.lock().unwrap(),
+        maps.iter()
+            .map(|name| (name.clone(), FileAction::Parsed))
+            .collect::<Vec<_>>()
+    );
+
+    let recorder = Arc::new(Recorder::default());
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    let finished = recorder.finished.lock().unwrap();
+    assert!(*recorder.found.lock().unwrap() > 0);
+    assert_eq!(finished.len(), *recorder.found.lock().unwrap());
+    assert!(finished.iter().all(|(_, action)| *action == FileAction::Parsed));
+    assert!(translation_path.join("items.txt").exists());
+
+    // Existing translation isn't overwritten in the default mode
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [(String::from("maps.txt"), SkipReason::AlreadyExists)]
+    );
+    assert!(recorder.finished.lock().unwrap().is_empty());
+
+    // Lines without the separator are reported as warnings
+    let maps_path = translation_path.join("maps.txt");
+    let content = read_to_string(&maps_path).unwrap();
+    write(&maps_path, content + "\nBroken line").unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    MapWriter::new(
+        &data_path,
+        &translation_path,
+        &game_path.join("output_observer"),
+        engine_type,
+    )
+    .dry_run(true)
+    .observer(recorder.clone())
+    .write();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        Warning::UnsplittableLine { file, content, .. } if file == "maps.txt" && content == "Broken line"
+    ));
+}
+
+#[test]
+fn observer_vxace() {
+    #[derive(Default)]
+    struct Recorder {
+        found: Mutex<usize>,
+        started: Mutex<Vec<String>>,
+        finished: Mutex<Vec<(String, FileAction)>>,
+        skipped: Mutex<Vec<(String, SkipReason)>>,
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn files_found(&self, count: usize) {
+            *self.found.lock().unwrap() += count;
+        }
+
+        fn file_started(&self, file: &str) {
+            self.started.lock().unwrap().push(file.to_owned());
+        }
+
+        fn file_finished(&self, file: &str, action: FileAction) {
+            self.finished.lock().unwrap().push((file.to_owned(), action));
+        }
+
+        fn file_skipped(&self, file: &str, reason: SkipReason) {
+            self.skipped.lock().unwrap().push((file.to_owned(), reason));
+        }
+
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+    let translation_path = game_path.join("translation_observer");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    let mut maps: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .collect();
+    maps.sort();
+
+    // Every found map is started and parsed
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(*recorder.found.lock().unwrap(), maps.len());
+    assert_eq!(*recorder.started.lock().unwrap(), maps);
+    assert_eq!(
+        *recorder.finished.lock().unwrap(),
+        maps.iter()
+            .map(|name| (name.clone(), FileAction::Parsed))
+            .collect::<Vec<_>>()
+    );
+
+    let recorder = Arc::new(Recorder::default());
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    let finished = recorder.finished.lock().unwrap();
+    assert!(*recorder.found.lock().unwrap() > 0);
+    assert_eq!(finished.len(), *recorder.found.lock().unwrap());
+    assert!(finished.iter().all(|(_, action)| *action == FileAction::Parsed));
+    assert!(translation_path.join("items.txt").exists());
+
+    // Existing translation isn't overwritten in the default mode
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [(String::from("maps.txt"), SkipReason::AlreadyExists)]
+    );
+    assert!(recorder.finished.lock().unwrap().is_empty());
+
+    // Lines without the separator are reported as warnings
+    let maps_path = translation_path.join("maps.txt");
+    let content = read_to_string(&maps_path).unwrap();
+    write(&maps_path, content + "\nBroken line").unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    MapWriter::new(
+        &data_path,
+        &translation_path,
+        &game_path.join("output_observer"),
+        engine_type,
+    )
+    .dry_run(true)
+    .observer(recorder.clone())
+    .write();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        Warning::UnsplittableLine { file, content, .. } if file == "maps.txt" && content == "Broken line"
+    ));
+}
+
+#[test]
+fn observer_vx() {
+    #[derive(Default)]
+    struct Recorder {
+        found: Mutex<usize>,
+        started: Mutex<Vec<String>>,
+        finished: Mutex<Vec<(String, FileAction)>>,
+        skipped: Mutex<Vec<(String, SkipReason)>>,
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn files_found(&self, count: usize) {
+            *self.found.lock().unwrap() += count;
+        }
+
+        fn file_started(&self, file: &str) {
+            self.started.lock().unwrap().push(file.to_owned());
+        }
+
+        fn file_finished(&self, file: &str, action: FileAction) {
+            self.finished.lock().unwrap().push((file.to_owned(), action));
+        }
+
+        fn file_skipped(&self, file: &str, reason: SkipReason) {
+            self.skipped.lock().unwrap().push((file.to_owned(), reason));
+        }
+
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+    let translation_path = game_path.join("translation_observer");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    let mut maps: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .collect();
+    maps.sort();
+
+    // Every found map is started and parsed
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(*recorder.found.lock().unwrap(), maps.len());
+    assert_eq!(*recorder.started.lock().unwrap(), maps);
+    assert_eq!(
+        *recorder.finished.lock().unwrap(),
+        maps.iter()
+            .map(|name| (name.clone(), FileAction::Parsed))
+            .collect::<Vec<_>>()
+    );
+
+    let recorder = Arc::new(Recorder::default());
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    let finished = recorder.finished.lock().unwrap();
+    assert!(*recorder.found.lock().unwrap() > 0);
+    assert_eq!(finished.len(), *recorder.found.lock().unwrap());
+    assert!(finished.iter().all(|(_, action)| *action == FileAction::Parsed));
+    assert!(translation_path.join("items.txt").exists());
+
+    // Existing translation isn't overwritten in the default mode
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [(String::from("maps.txt"), SkipReason::AlreadyExists)]
+    );
+    assert!(recorder.finished.lock().unwrap().is_empty());
+
+    // Lines without the separator are reported as warnings
+    let maps_path = translation_path.join("maps.txt");
+    let content = read_to_string(&maps_path).unwrap();
+    write(&maps_path, content + "\nBroken line").unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    MapWriter::new(
+        &data_path,
+        &translation_path,
+        &game_path.join("output_observer"),
+        engine_type,
+    )
+    .dry_run(true)
+    .observer(recorder.clone())
+    .write();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        Warning::UnsplittableLine { file, content, .. } if file == "maps.txt" && content == "Broken line"
+    ));
+}
+
+#[test]
+fn observer_xp() {
+    #[derive(Default)]
+    struct Recorder {
+        found: Mutex<usize>,
+        started: Mutex<Vec<String>>,
+        finished: Mutex<Vec<(String, FileAction)>>,
+        skipped: Mutex<Vec<(String, SkipReason)>>,
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn files_found(&self, count: usize) {
+            *self.found.lock().unwrap() += count;
+        }
+
+        fn file_started(&self, file: &str) {
+            self.started.lock().unwrap().push(file.to_owned());
+        }
+
+        fn file_finished(&self, file: &str, action: FileAction) {
+            self.finished.lock().unwrap().push((file.to_owned(), action));
+        }
+
+        fn file_skipped(&self, file: &str, reason: SkipReason) {
+            self.skipped.lock().unwrap().push((file.to_owned(), reason));
+        }
+
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+    let translation_path = game_path.join("translation_observer");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    let mut maps: Vec<String> = read_dir(&data_path)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.starts_with("Map") && name.as_bytes()[3].is_ascii_digit())
+        .collect();
+    maps.sort();
+
+    // Every found map is started and parsed
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(*recorder.found.lock().unwrap(), maps.len());
+    assert_eq!(*recorder.started.lock().unwrap(), maps);
+    assert_eq!(
+        *recorder.finished.lock().unwrap(),
+        maps.iter()
+            .map(|name| (name.clone(), FileAction::Parsed))
+            .collect::<Vec<_>>()
+    );
+
+    let recorder = Arc::new(Recorder::default());
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .observer(recorder.clone())
+        .read();
+
+    let finished = recorder.finished.lock().unwrap();
+    assert!(*recorder.found.lock().unwrap() > 0);
+    assert_eq!(finished.len(), *recorder.found.lock().unwrap());
+    assert!(finished.iter().all(|(_, action)| *action == FileAction::Parsed));
+    assert!(translation_path.join("items.txt").exists());
+
+    // Existing translation isn't overwritten in the default mode
+    let recorder = Arc::new(Recorder::default());
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .observer(recorder.clone())
+        .read();
+
+    assert_eq!(
+        *recorder.skipped.lock().unwrap(),
+        [(String::from("maps.txt"), SkipReason::AlreadyExists)]
+    );
+    assert!(recorder.finished.lock().unwrap().is_empty());
+
+    // Lines without the separator are reported as warnings
+    let maps_path = translation_path.join("maps.txt");
+    let content = read_to_string(&maps_path).unwrap();
+    write(&maps_path, content + "\nBroken line").unwrap();
+
+    let recorder = Arc::new(Recorder::default());
+    MapWriter::new(
+        &data_path,
+        &translation_path,
+        &game_path.join("output_observer"),
+        engine_type,
+    )
+    .dry_run(true)
+    .observer(recorder.clone())
+    .write();
+
+    let warnings = recorder.warnings.lock().unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert!(matches!(
+        &warnings[0],
+        Warning::UnsplittableLine { file, content, .. } if file == "maps.txt" && content == "Broken line"
+    ));
+}
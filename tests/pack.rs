@@ -0,0 +1,328 @@
+use marshal_rs::{dump, load, StringMode};
+use rvpacker_txt_rs_lib::{
+    pack::TranslationPack,
+    read::SystemReader,
+    statics::LINES_SEPARATOR,
+    types::{EngineType, ProcessingMode},
+};
+use sonic_rs::{from_str, to_string, JsonValueMutTrait, Value};
+use std::{
+    env::var,
+    fs::{create_dir_all, read, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+#[test]
+fn pack_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_pack");
+    let pack_data_path = game_path.join("pack_data");
+    let system_file_path = pack_data_path.join("System.json");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&pack_data_path).unwrap();
+
+    // A default term, which is known to be in the built-in pack
+    let mut system: Value = from_str(&read_to_string(data_path.join("System.json")).unwrap()).unwrap();
+    system["elements"].as_array_mut().unwrap().push(Value::from("攻撃"));
+    write(&system_file_path, to_string(&system).unwrap()).unwrap();
+
+    let pack = Arc::new(TranslationPack::builtin(engine_type, "ja", "en").unwrap());
+    assert_eq!(pack.name(), "ja-en");
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .pack(Some(pack.clone()))
+        .read();
+    let forced = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert!(forced.contains(&format!(
+        "\n<!-- Pack: ja-en -->{LINES_SEPARATOR}\n攻撃{LINES_SEPARATOR}Attack\n"
+    )));
+
+    // Every default term is filled with the pack translation and marked
+    let lines: Vec<&str> = forced.split('\n').collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if let Some(pack_translation) = pack.get(original) {
+            assert_eq!(translation, pack_translation);
+            assert_eq!(lines[i - 1], format!("<!-- Pack: ja-en -->{LINES_SEPARATOR}"));
+        }
+    }
+
+    // Marking survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .pack(Some(pack))
+        .read();
+    let appended = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    remove_dir_all(&translation_path).unwrap();
+    remove_dir_all(&pack_data_path).unwrap();
+}
+
+#[test]
+fn pack_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_pack");
+    let pack_data_path = game_path.join("pack_data");
+    let system_file_path = pack_data_path.join("System.json");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&pack_data_path).unwrap();
+
+    // A default term, which is known to be in the built-in pack
+    let mut system: Value = from_str(&read_to_string(data_path.join("System.json")).unwrap()).unwrap();
+    system["elements"].as_array_mut().unwrap().push(Value::from("攻撃"));
+    write(&system_file_path, to_string(&system).unwrap()).unwrap();
+
+    let pack = Arc::new(TranslationPack::builtin(engine_type, "ja", "en").unwrap());
+    assert_eq!(pack.name(), "ja-en");
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .pack(Some(pack.clone()))
+        .read();
+    let forced = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert!(forced.contains(&format!(
+        "\n<!-- Pack: ja-en -->{LINES_SEPARATOR}\n攻撃{LINES_SEPARATOR}Attack\n"
+    )));
+
+    // Every default term is filled with the pack translation and marked
+    let lines: Vec<&str> = forced.split('\n').collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if let Some(pack_translation) = pack.get(original) {
+            assert_eq!(translation, pack_translation);
+            assert_eq!(lines[i - 1], format!("<!-- Pack: ja-en -->{LINES_SEPARATOR}"));
+        }
+    }
+
+    // Marking survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .pack(Some(pack))
+        .read();
+    let appended = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    remove_dir_all(&translation_path).unwrap();
+    remove_dir_all(&pack_data_path).unwrap();
+}
+
+#[test]
+fn pack_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_pack");
+    let pack_data_path = game_path.join("pack_data");
+    let system_file_path = pack_data_path.join("System.rvdata2");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&pack_data_path).unwrap();
+
+    // A default term, which is known to be in the built-in pack
+    let mut system: Value = load(
+        &read(data_path.join("System.rvdata2")).unwrap(),
+        Some(StringMode::UTF8),
+        Some(""),
+    )
+    .unwrap();
+    system["__symbol__elements"]
+        .as_array_mut()
+        .unwrap()
+        .push(Value::from("攻撃"));
+    write(&system_file_path, dump(system, Some(""))).unwrap();
+
+    let pack = Arc::new(TranslationPack::builtin(engine_type, "ja", "en").unwrap());
+    assert_eq!(pack.name(), "ja-en");
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .pack(Some(pack.clone()))
+        .read();
+    let forced = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert!(forced.contains(&format!(
+        "\n<!-- Pack: ja-en -->{LINES_SEPARATOR}\n攻撃{LINES_SEPARATOR}Attack\n"
+    )));
+
+    // Every default term is filled with the pack translation and marked
+    let lines: Vec<&str> = forced.split('\n').collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if let Some(pack_translation) = pack.get(original) {
+            assert_eq!(translation, pack_translation);
+            assert_eq!(lines[i - 1], format!("<!-- Pack: ja-en -->{LINES_SEPARATOR}"));
+        }
+    }
+
+    // Marking survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .pack(Some(pack))
+        .read();
+    let appended = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    remove_dir_all(&translation_path).unwrap();
+    remove_dir_all(&pack_data_path).unwrap();
+}
+
+#[test]
+fn pack_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_pack");
+    let pack_data_path = game_path.join("pack_data");
+    let system_file_path = pack_data_path.join("System.rvdata");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&pack_data_path).unwrap();
+
+    // A default term, which is known to be in the built-in pack
+    let mut system: Value = load(
+        &read(data_path.join("System.rvdata")).unwrap(),
+        Some(StringMode::UTF8),
+        Some(""),
+    )
+    .unwrap();
+    system["__symbol__elements"]
+        .as_array_mut()
+        .unwrap()
+        .push(Value::from("攻撃"));
+    write(&system_file_path, dump(system, Some(""))).unwrap();
+
+    let pack = Arc::new(TranslationPack::builtin(engine_type, "ja", "en").unwrap());
+    assert_eq!(pack.name(), "ja-en");
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .pack(Some(pack.clone()))
+        .read();
+    let forced = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert!(forced.contains(&format!(
+        "\n<!-- Pack: ja-en -->{LINES_SEPARATOR}\n攻撃{LINES_SEPARATOR}Attack\n"
+    )));
+
+    // Every default term is filled with the pack translation and marked
+    let lines: Vec<&str> = forced.split('\n').collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if let Some(pack_translation) = pack.get(original) {
+            assert_eq!(translation, pack_translation);
+            assert_eq!(lines[i - 1], format!("<!-- Pack: ja-en -->{LINES_SEPARATOR}"));
+        }
+    }
+
+    // Marking survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .pack(Some(pack))
+        .read();
+    let appended = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    remove_dir_all(&translation_path).unwrap();
+    remove_dir_all(&pack_data_path).unwrap();
+}
+
+#[test]
+fn pack_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_pack");
+    let pack_data_path = game_path.join("pack_data");
+    let system_file_path = pack_data_path.join("System.rxdata");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&pack_data_path).unwrap();
+
+    // A default term, which is known to be in the built-in pack
+    let mut system: Value = load(
+        &read(data_path.join("System.rxdata")).unwrap(),
+        Some(StringMode::UTF8),
+        Some(""),
+    )
+    .unwrap();
+    system["__symbol__elements"]
+        .as_array_mut()
+        .unwrap()
+        .push(Value::from("攻撃"));
+    write(&system_file_path, dump(system, Some(""))).unwrap();
+
+    let pack = Arc::new(TranslationPack::builtin(engine_type, "ja", "en").unwrap());
+    assert_eq!(pack.name(), "ja-en");
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .pack(Some(pack.clone()))
+        .read();
+    let forced = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert!(forced.contains(&format!(
+        "\n<!-- Pack: ja-en -->{LINES_SEPARATOR}\n攻撃{LINES_SEPARATOR}Attack\n"
+    )));
+
+    // Every default term is filled with the pack translation and marked
+    let lines: Vec<&str> = forced.split('\n').collect();
+
+    for (i, line) in lines.iter().enumerate() {
+        let Some((original, translation)) = line.split_once(LINES_SEPARATOR) else {
+            continue;
+        };
+
+        if let Some(pack_translation) = pack.get(original) {
+            assert_eq!(translation, pack_translation);
+            assert_eq!(lines[i - 1], format!("<!-- Pack: ja-en -->{LINES_SEPARATOR}"));
+        }
+    }
+
+    // Marking survives appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .pack(Some(pack))
+        .read();
+    let appended = read_to_string(translation_path.join("system.txt")).unwrap();
+
+    assert_eq!(forced, appended);
+
+    remove_dir_all(&translation_path).unwrap();
+    remove_dir_all(&pack_data_path).unwrap();
+}
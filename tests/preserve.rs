@@ -0,0 +1,219 @@
+use rvpacker_txt_rs_lib::{
+    read::MapReader,
+    types::{EngineType, MapsProcessingMode, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn preserve_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_preserve");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Preserve)
+        .read();
+
+    // Every line is translated, including map name comments
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!-- Map -->") => format!("{original}<#>Preserved"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Appending to an up-to-date translation changes nothing, no matter how many times it's done
+    for _ in 0..2 {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(MapsProcessingMode::Preserve)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+    }
+
+    assert_eq!(
+        translated.matches("<!-- Order -->").count(),
+        translated.matches("<!-- Map -->").count()
+    );
+}
+
+#[test]
+fn preserve_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_preserve");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Preserve)
+        .read();
+
+    // Every line is translated, including map name comments
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!-- Map -->") => format!("{original}<#>Preserved"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Appending to an up-to-date translation changes nothing, no matter how many times it's done
+    for _ in 0..2 {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(MapsProcessingMode::Preserve)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+    }
+
+    assert_eq!(
+        translated.matches("<!-- Order -->").count(),
+        translated.matches("<!-- Map -->").count()
+    );
+}
+
+#[test]
+fn preserve_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_preserve");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Preserve)
+        .read();
+
+    // Every line is translated, including map name comments
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!-- Map -->") => format!("{original}<#>Preserved"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Appending to an up-to-date translation changes nothing, no matter how many times it's done
+    for _ in 0..2 {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(MapsProcessingMode::Preserve)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+    }
+
+    assert_eq!(
+        translated.matches("<!-- Order -->").count(),
+        translated.matches("<!-- Map -->").count()
+    );
+}
+
+#[test]
+fn preserve_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_preserve");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Preserve)
+        .read();
+
+    // Every line is translated, including map name comments
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!-- Map -->") => format!("{original}<#>Preserved"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Appending to an up-to-date translation changes nothing, no matter how many times it's done
+    for _ in 0..2 {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(MapsProcessingMode::Preserve)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+    }
+
+    assert_eq!(
+        translated.matches("<!-- Order -->").count(),
+        translated.matches("<!-- Map -->").count()
+    );
+}
+
+#[test]
+fn preserve_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_preserve");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Preserve)
+        .read();
+
+    // Every line is translated, including map name comments
+    let translated: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, "")) if !original.starts_with("<!-- Map -->") => format!("{original}<#>Preserved"),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&maps_txt_path, &translated).unwrap();
+
+    // Appending to an up-to-date translation changes nothing, no matter how many times it's done
+    for _ in 0..2 {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(MapsProcessingMode::Preserve)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), translated);
+    }
+
+    assert_eq!(
+        translated.matches("<!-- Order -->").count(),
+        translated.matches("<!-- Map -->").count()
+    );
+}
@@ -0,0 +1,175 @@
+use rvpacker_txt_rs_lib::{
+    purge::MapPurger,
+    read::MapReader,
+    types::{EngineType, MapsProcessingMode, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn purge_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_purge");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    assert!(content.matches("<!-- Map -->").count() > 1);
+
+    // Stale line in the last map, which index within its map points to lines of the first map
+    write(&maps_txt_path, format!("{content}\nStale line<#>Stale translation")).unwrap();
+
+    let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .purge(None, None);
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].file, "maps.txt");
+    assert_eq!(purged[0].original, "Stale line");
+    assert_eq!(purged[0].translation, "Stale translation");
+    assert!(purged[0].reason.is_not_in_source());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+}
+
+#[test]
+fn purge_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_purge");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    assert!(content.matches("<!-- Map -->").count() > 1);
+
+    // Stale line in the last map, which index within its map points to lines of the first map
+    write(&maps_txt_path, format!("{content}\nStale line<#>Stale translation")).unwrap();
+
+    let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .purge(None, None);
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].file, "maps.txt");
+    assert_eq!(purged[0].original, "Stale line");
+    assert_eq!(purged[0].translation, "Stale translation");
+    assert!(purged[0].reason.is_not_in_source());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+}
+
+#[test]
+fn purge_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_purge");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    assert!(content.matches("<!-- Map -->").count() > 1);
+
+    // Stale line in the last map, which index within its map points to lines of the first map
+    write(&maps_txt_path, format!("{content}\nStale line<#>Stale translation")).unwrap();
+
+    let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .purge(None, None);
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].file, "maps.txt");
+    assert_eq!(purged[0].original, "Stale line");
+    assert_eq!(purged[0].translation, "Stale translation");
+    assert!(purged[0].reason.is_not_in_source());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+}
+
+#[test]
+fn purge_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_purge");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    assert!(content.matches("<!-- Map -->").count() > 1);
+
+    // Stale line in the last map, which index within its map points to lines of the first map
+    write(&maps_txt_path, format!("{content}\nStale line<#>Stale translation")).unwrap();
+
+    let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .purge(None, None);
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].file, "maps.txt");
+    assert_eq!(purged[0].original, "Stale line");
+    assert_eq!(purged[0].translation, "Stale translation");
+    assert!(purged[0].reason.is_not_in_source());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+}
+
+#[test]
+fn purge_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_purge");
+    let maps_txt_path = translation_path.join("maps.txt");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path).unwrap();
+    assert!(content.matches("<!-- Map -->").count() > 1);
+
+    // Stale line in the last map, which index within its map points to lines of the first map
+    write(&maps_txt_path, format!("{content}\nStale line<#>Stale translation")).unwrap();
+
+    let purged = MapPurger::new(&data_path, &translation_path, engine_type)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .purge(None, None);
+
+    assert_eq!(purged.len(), 1);
+    assert_eq!(purged[0].file, "maps.txt");
+    assert_eq!(purged[0].original, "Stale line");
+    assert_eq!(purged[0].translation, "Stale translation");
+    assert!(purged[0].reason.is_not_in_source());
+    assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+}
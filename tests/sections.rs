@@ -0,0 +1,462 @@
+use rvpacker_txt_rs_lib::{
+    purge::SystemPurger,
+    read::SystemReader,
+    types::{EngineType, ProcessingMode},
+    write::SystemWriter,
+};
+use std::{
+    collections::HashMap,
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn sections_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let system_file_path = data_path.join("System.json");
+    let system_txt_path = translation_path.join("system.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line is translated with the name of its section
+    let mut section: String = String::new();
+    let mut sections_of: HashMap<String, Vec<String>> = HashMap::new();
+    let translated: String = read_to_string(&system_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, _)) if original.starts_with("<!--") => {
+                section = original.trim_start_matches("<!-- ").trim_end_matches(" -->").to_owned();
+                line.to_owned()
+            }
+            Some((original, _)) if section != "Game Title" => {
+                sections_of
+                    .entry(original.to_owned())
+                    .or_default()
+                    .push(section.clone());
+                format!("{original}<#>{section}")
+            }
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<!-- Terms: "));
+    write(&system_txt_path, &translated).unwrap();
+
+    // Sections and their translation survive appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Appending keeps the order of existing lines within a section, unless sorting is enabled
+    let mut lines: Vec<&str> = translated.split('\n').collect();
+    let swapped_index = (0..lines.len() - 1)
+        .find(|&i| !lines[i].starts_with("<!--") && !lines[i + 1].starts_with("<!--"))
+        .unwrap();
+    lines.swap(swapped_index, swapped_index + 1);
+    let swapped: String = lines.join("\n");
+    write(&system_txt_path, &swapped).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), swapped);
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .sort(true)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Identical strings of different sections receive translation of their own section
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    for (original, sections) in sections_of.iter().filter(|(_, sections)| sections.len() > 1) {
+        for section in sections {
+            assert!(report
+                .replacements
+                .iter()
+                .any(|x| &x.original == original && &x.translation == section));
+        }
+    }
+
+    // Purging keeps sections and duplicates
+    let purged = SystemPurger::new(&system_file_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+}
+
+#[test]
+fn sections_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("data");
+    let system_file_path = data_path.join("System.json");
+    let system_txt_path = translation_path.join("system.txt");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line is translated with the name of its section
+    let mut section: String = String::new();
+    let mut sections_of: HashMap<String, Vec<String>> = HashMap::new();
+    let translated: String = read_to_string(&system_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, _)) if original.starts_with("<!--") => {
+                section = original.trim_start_matches("<!-- ").trim_end_matches(" -->").to_owned();
+                line.to_owned()
+            }
+            Some((original, _)) if section != "Game Title" => {
+                sections_of
+                    .entry(original.to_owned())
+                    .or_default()
+                    .push(section.clone());
+                format!("{original}<#>{section}")
+            }
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<!-- Terms: "));
+    write(&system_txt_path, &translated).unwrap();
+
+    // Sections and their translation survive appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Appending keeps the order of existing lines within a section, unless sorting is enabled
+    let mut lines: Vec<&str> = translated.split('\n').collect();
+    let swapped_index = (0..lines.len() - 1)
+        .find(|&i| !lines[i].starts_with("<!--") && !lines[i + 1].starts_with("<!--"))
+        .unwrap();
+    lines.swap(swapped_index, swapped_index + 1);
+    let swapped: String = lines.join("\n");
+    write(&system_txt_path, &swapped).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), swapped);
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .sort(true)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Identical strings of different sections receive translation of their own section
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    for (original, sections) in sections_of.iter().filter(|(_, sections)| sections.len() > 1) {
+        for section in sections {
+            assert!(report
+                .replacements
+                .iter()
+                .any(|x| &x.original == original && &x.translation == section));
+        }
+    }
+
+    // Purging keeps sections and duplicates
+    let purged = SystemPurger::new(&system_file_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+}
+
+#[test]
+fn sections_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rvdata2");
+    let system_txt_path = translation_path.join("system.txt");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line is translated with the name of its section
+    let mut section: String = String::new();
+    let mut sections_of: HashMap<String, Vec<String>> = HashMap::new();
+    let translated: String = read_to_string(&system_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, _)) if original.starts_with("<!--") => {
+                section = original.trim_start_matches("<!-- ").trim_end_matches(" -->").to_owned();
+                line.to_owned()
+            }
+            Some((original, _)) if section != "Game Title" => {
+                sections_of
+                    .entry(original.to_owned())
+                    .or_default()
+                    .push(section.clone());
+                format!("{original}<#>{section}")
+            }
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<!-- Terms: "));
+    write(&system_txt_path, &translated).unwrap();
+
+    // Sections and their translation survive appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Appending keeps the order of existing lines within a section, unless sorting is enabled
+    let mut lines: Vec<&str> = translated.split('\n').collect();
+    let swapped_index = (0..lines.len() - 1)
+        .find(|&i| !lines[i].starts_with("<!--") && !lines[i + 1].starts_with("<!--"))
+        .unwrap();
+    lines.swap(swapped_index, swapped_index + 1);
+    let swapped: String = lines.join("\n");
+    write(&system_txt_path, &swapped).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), swapped);
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .sort(true)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Identical strings of different sections receive translation of their own section
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    for (original, sections) in sections_of.iter().filter(|(_, sections)| sections.len() > 1) {
+        for section in sections {
+            assert!(report
+                .replacements
+                .iter()
+                .any(|x| &x.original == original && &x.translation == section));
+        }
+    }
+
+    // Purging keeps sections and duplicates
+    let purged = SystemPurger::new(&system_file_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+}
+
+#[test]
+fn sections_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rvdata");
+    let system_txt_path = translation_path.join("system.txt");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line is translated with the name of its section
+    let mut section: String = String::new();
+    let mut sections_of: HashMap<String, Vec<String>> = HashMap::new();
+    let translated: String = read_to_string(&system_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, _)) if original.starts_with("<!--") => {
+                section = original.trim_start_matches("<!-- ").trim_end_matches(" -->").to_owned();
+                line.to_owned()
+            }
+            Some((original, _)) if section != "Game Title" => {
+                sections_of
+                    .entry(original.to_owned())
+                    .or_default()
+                    .push(section.clone());
+                format!("{original}<#>{section}")
+            }
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<!-- Terms: "));
+    write(&system_txt_path, &translated).unwrap();
+
+    // Sections and their translation survive appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Appending keeps the order of existing lines within a section, unless sorting is enabled
+    let mut lines: Vec<&str> = translated.split('\n').collect();
+    let swapped_index = (0..lines.len() - 1)
+        .find(|&i| !lines[i].starts_with("<!--") && !lines[i + 1].starts_with("<!--"))
+        .unwrap();
+    lines.swap(swapped_index, swapped_index + 1);
+    let swapped: String = lines.join("\n");
+    write(&system_txt_path, &swapped).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), swapped);
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .sort(true)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Identical strings of different sections receive translation of their own section
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    for (original, sections) in sections_of.iter().filter(|(_, sections)| sections.len() > 1) {
+        for section in sections {
+            assert!(report
+                .replacements
+                .iter()
+                .any(|x| &x.original == original && &x.translation == section));
+        }
+    }
+
+    // Purging keeps sections and duplicates
+    let purged = SystemPurger::new(&system_file_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+}
+
+#[test]
+fn sections_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation");
+    let output_path = game_path.join("output").join("Data");
+    let system_file_path = data_path.join("System.rxdata");
+    let system_txt_path = translation_path.join("system.txt");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every line is translated with the name of its section
+    let mut section: String = String::new();
+    let mut sections_of: HashMap<String, Vec<String>> = HashMap::new();
+    let translated: String = read_to_string(&system_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line.split_once("<#>") {
+            Some((original, _)) if original.starts_with("<!--") => {
+                section = original.trim_start_matches("<!-- ").trim_end_matches(" -->").to_owned();
+                line.to_owned()
+            }
+            Some((original, _)) if section != "Game Title" => {
+                sections_of
+                    .entry(original.to_owned())
+                    .or_default()
+                    .push(section.clone());
+                format!("{original}<#>{section}")
+            }
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(translated.contains("<!-- Terms: "));
+    write(&system_txt_path, &translated).unwrap();
+
+    // Sections and their translation survive appending
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Appending keeps the order of existing lines within a section, unless sorting is enabled
+    let mut lines: Vec<&str> = translated.split('\n').collect();
+    let swapped_index = (0..lines.len() - 1)
+        .find(|&i| !lines[i].starts_with("<!--") && !lines[i + 1].starts_with("<!--"))
+        .unwrap();
+    lines.swap(swapped_index, swapped_index + 1);
+    let swapped: String = lines.join("\n");
+    write(&system_txt_path, &swapped).unwrap();
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), swapped);
+
+    SystemReader::new(&system_file_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .sort(true)
+        .read();
+    assert_eq!(read_to_string(&system_txt_path).unwrap(), translated);
+
+    // Identical strings of different sections receive translation of their own section
+    let report = SystemWriter::new(&system_file_path, &translation_path, &output_path, engine_type)
+        .dry_run(true)
+        .write();
+
+    for (original, sections) in sections_of.iter().filter(|(_, sections)| sections.len() > 1) {
+        for section in sections {
+            assert!(report
+                .replacements
+                .iter()
+                .any(|x| &x.original == original && &x.translation == section));
+        }
+    }
+
+    // Purging keeps sections and duplicates
+    let purged = SystemPurger::new(&system_file_path, &translation_path, engine_type)
+        .dry_run(true)
+        .purge(None, None);
+    assert!(purged.is_empty());
+}
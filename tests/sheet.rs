@@ -0,0 +1,781 @@
+use rvpacker_txt_rs_lib::{
+    escape,
+    read::{MapReader, OtherReader},
+    sheet::{parse_sheet, quote_cell, SheetExporter, SheetImporter},
+    types::{EngineType, MapsLayout, ProcessingMode, SheetFormat},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+};
+
+#[test]
+fn sheet_cells() {
+    let comma: char = SheetFormat::Csv.delimiter();
+    let tab: char = SheetFormat::Tsv.delimiter();
+
+    // Cells are quoted only if they contain the delimiter, quotes or new lines
+    assert_eq!(quote_cell("plain", comma), "plain");
+    assert_eq!(quote_cell("a,b", comma), "\"a,b\"");
+    assert_eq!(quote_cell("a,b", tab), "a,b");
+    assert_eq!(quote_cell("a\tb", tab), "\"a\tb\"");
+    assert_eq!(quote_cell("say \"hi\"", tab), "\"say \"\"hi\"\"\"");
+    assert_eq!(quote_cell("two\nlines", comma), "\"two\nlines\"");
+    assert_eq!(quote_cell("", comma), "");
+
+    // BOM and blank rows are skipped, quoted cells keep delimiters, quotes and new lines
+    let content = "\u{FEFF}file,source,target\r\nmaps.txt,\"a,b\",\"say \"\"hi\"\"\r\nnext\"\n\n,,\nitems.txt,x,";
+    assert_eq!(
+        parse_sheet(content, comma),
+        [
+            vec!["file", "source", "target"],
+            vec!["maps.txt", "a,b", "say \"hi\"\nnext"],
+            vec!["items.txt", "x", ""],
+        ]
+    );
+
+    // Quoted cells are parsed back as they were
+    let cells: [&str; 5] = ["plain", "a,b\tc", "say \"hi\"", "two\nlines", ""];
+
+    for delimiter in [comma, tab] {
+        let row: String = cells
+            .iter()
+            .map(|cell| quote_cell(cell, delimiter))
+            .collect::<Vec<_>>()
+            .join(&delimiter.to_string());
+
+        assert_eq!(parse_sheet(&format!("{row}\n{row}\n"), delimiter), [cells, cells]);
+    }
+}
+
+#[test]
+fn event_contexts() {
+    let translation_path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("sheet_contexts");
+    let sheets_path = translation_path.join("sheets");
+
+    create_dir_all(&translation_path).unwrap();
+    write(
+        translation_path.join("commonevents.txt"),
+        format!(
+            "<!-- Format --><#>2\n<!-- Event Name: {} --><#>\nHello<#>",
+            escape("Intro<#>1")
+        ),
+    )
+    .unwrap();
+    write(
+        translation_path.join("maps.txt"),
+        format!(
+            "<!-- Format --><#>2\n<!-- Map --><#>1\n<!-- Event Name: {} --><#>\nHello<#>",
+            escape("Guard<#>2")
+        ),
+    )
+    .unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    // Event names are unescaped with and without the map context
+    let context = |sheet: &str| -> String {
+        let rows: Vec<Vec<String>> = parse_sheet(&read_to_string(sheets_path.join(sheet)).unwrap(), ',');
+        let context_column: usize = rows[0].iter().position(|x| x == "context").unwrap();
+        rows[1][context_column].clone()
+    };
+
+    assert_eq!(context("commonevents.csv"), "Intro<#>1");
+    assert_eq!(context("maps.csv"), "Map 1 / Guard<#>2");
+}
+
+#[test]
+fn sheets_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let sheets_path = game_path.join("sheets");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let maps = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    for format in [SheetFormat::Csv, SheetFormat::Tsv] {
+        SheetExporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .notes(true)
+            .export();
+
+        // Unchanged sheets are imported without changes
+        let report = SheetImporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .import();
+
+        assert!(report.is_clean());
+        assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), maps);
+    }
+
+    // Per-map files are exported to a single maps sheet, and imported back into per-map files
+    let per_map_path = game_path.join("translation_sheets_per_map");
+    let per_map_sheets_path = game_path.join("sheets_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    SheetExporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .notes(true)
+        .export();
+
+    let sheet_path = per_map_sheets_path.join("maps.csv");
+    assert_eq!(
+        read_to_string(&sheet_path).unwrap(),
+        read_to_string(sheets_path.join("maps.csv")).unwrap()
+    );
+
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    rows[1][target_column] = String::from("Per-map");
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .import();
+    assert!(report.is_clean());
+    assert_eq!(report.imported, ["maps.txt"]);
+    assert!(!per_map_path.join("maps.txt").exists());
+
+    let map_contents: Vec<String> = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| read_to_string(entry.path()).unwrap())
+        .collect();
+    assert!(map_contents
+        .iter()
+        .all(|content| content.starts_with("<!-- Format --><#>")));
+    assert!(map_contents.iter().any(|content| content.contains("<#>Per-map")));
+
+    remove_dir_all(&per_map_path).unwrap();
+    remove_dir_all(&per_map_sheets_path).unwrap();
+
+    // Sheets with extra rows are rejected
+    let sheet_path = sheets_path.join("maps.csv");
+    let sheet = read_to_string(&sheet_path).unwrap();
+    write(&sheet_path, sheet + "maps.txt,,extra,extra\n").unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert_eq!(report.mismatched.len(), 1);
+    assert_eq!(report.mismatched[0].actual, report.mismatched[0].expected + 1);
+
+    // The first item line has an earlier draft column, which is kept when the sheet edits its translation
+    let items_txt_path = translation_path.join("items.txt");
+    let mut lines: Vec<String> = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(str::to_owned)
+        .collect();
+    let index: usize = lines.iter().position(|line| !line.starts_with("<!--")).unwrap();
+    let original: String = lines[index].split_once("<#>").unwrap().0.to_owned();
+    lines[index] = format!("{original}<#>Draft<#>Old");
+    write(&items_txt_path, lines.join("\n")).unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    let sheet_path = sheets_path.join("items.csv");
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    assert_eq!(rows[1][target_column], "Old");
+
+    let target = "Line one\nSay \"hi\", friend";
+    rows[1][target_column] = target.to_owned();
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert!(report.is_clean());
+    assert_eq!(
+        read_to_string(&items_txt_path).unwrap().split('\n').nth(index).unwrap(),
+        format!("{original}<#>Draft<#>{}", escape(target))
+    );
+}
+
+#[test]
+fn sheets_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let sheets_path = game_path.join("sheets");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let maps = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    for format in [SheetFormat::Csv, SheetFormat::Tsv] {
+        SheetExporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .notes(true)
+            .export();
+
+        // Unchanged sheets are imported without changes
+        let report = SheetImporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .import();
+
+        assert!(report.is_clean());
+        assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), maps);
+    }
+
+    // Per-map files are exported to a single maps sheet, and imported back into per-map files
+    let per_map_path = game_path.join("translation_sheets_per_map");
+    let per_map_sheets_path = game_path.join("sheets_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    SheetExporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .notes(true)
+        .export();
+
+    let sheet_path = per_map_sheets_path.join("maps.csv");
+    assert_eq!(
+        read_to_string(&sheet_path).unwrap(),
+        read_to_string(sheets_path.join("maps.csv")).unwrap()
+    );
+
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    rows[1][target_column] = String::from("Per-map");
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .import();
+    assert!(report.is_clean());
+    assert_eq!(report.imported, ["maps.txt"]);
+    assert!(!per_map_path.join("maps.txt").exists());
+
+    let map_contents: Vec<String> = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| read_to_string(entry.path()).unwrap())
+        .collect();
+    assert!(map_contents
+        .iter()
+        .all(|content| content.starts_with("<!-- Format --><#>")));
+    assert!(map_contents.iter().any(|content| content.contains("<#>Per-map")));
+
+    remove_dir_all(&per_map_path).unwrap();
+    remove_dir_all(&per_map_sheets_path).unwrap();
+
+    // Sheets with extra rows are rejected
+    let sheet_path = sheets_path.join("maps.csv");
+    let sheet = read_to_string(&sheet_path).unwrap();
+    write(&sheet_path, sheet + "maps.txt,,extra,extra\n").unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert_eq!(report.mismatched.len(), 1);
+    assert_eq!(report.mismatched[0].actual, report.mismatched[0].expected + 1);
+
+    // The first item line has an earlier draft column, which is kept when the sheet edits its translation
+    let items_txt_path = translation_path.join("items.txt");
+    let mut lines: Vec<String> = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(str::to_owned)
+        .collect();
+    let index: usize = lines.iter().position(|line| !line.starts_with("<!--")).unwrap();
+    let original: String = lines[index].split_once("<#>").unwrap().0.to_owned();
+    lines[index] = format!("{original}<#>Draft<#>Old");
+    write(&items_txt_path, lines.join("\n")).unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    let sheet_path = sheets_path.join("items.csv");
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    assert_eq!(rows[1][target_column], "Old");
+
+    let target = "Line one\nSay \"hi\", friend";
+    rows[1][target_column] = target.to_owned();
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert!(report.is_clean());
+    assert_eq!(
+        read_to_string(&items_txt_path).unwrap().split('\n').nth(index).unwrap(),
+        format!("{original}<#>Draft<#>{}", escape(target))
+    );
+}
+
+#[test]
+fn sheets_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let sheets_path = game_path.join("sheets");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let maps = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    for format in [SheetFormat::Csv, SheetFormat::Tsv] {
+        SheetExporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .notes(true)
+            .export();
+
+        // Unchanged sheets are imported without changes
+        let report = SheetImporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .import();
+
+        assert!(report.is_clean());
+        assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), maps);
+    }
+
+    // Per-map files are exported to a single maps sheet, and imported back into per-map files
+    let per_map_path = game_path.join("translation_sheets_per_map");
+    let per_map_sheets_path = game_path.join("sheets_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    SheetExporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .notes(true)
+        .export();
+
+    let sheet_path = per_map_sheets_path.join("maps.csv");
+    assert_eq!(
+        read_to_string(&sheet_path).unwrap(),
+        read_to_string(sheets_path.join("maps.csv")).unwrap()
+    );
+
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    rows[1][target_column] = String::from("Per-map");
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .import();
+    assert!(report.is_clean());
+    assert_eq!(report.imported, ["maps.txt"]);
+    assert!(!per_map_path.join("maps.txt").exists());
+
+    let map_contents: Vec<String> = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| read_to_string(entry.path()).unwrap())
+        .collect();
+    assert!(map_contents
+        .iter()
+        .all(|content| content.starts_with("<!-- Format --><#>")));
+    assert!(map_contents.iter().any(|content| content.contains("<#>Per-map")));
+
+    remove_dir_all(&per_map_path).unwrap();
+    remove_dir_all(&per_map_sheets_path).unwrap();
+
+    // Sheets with extra rows are rejected
+    let sheet_path = sheets_path.join("maps.csv");
+    let sheet = read_to_string(&sheet_path).unwrap();
+    write(&sheet_path, sheet + "maps.txt,,extra,extra\n").unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert_eq!(report.mismatched.len(), 1);
+    assert_eq!(report.mismatched[0].actual, report.mismatched[0].expected + 1);
+
+    // The first item line has an earlier draft column, which is kept when the sheet edits its translation
+    let items_txt_path = translation_path.join("items.txt");
+    let mut lines: Vec<String> = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(str::to_owned)
+        .collect();
+    let index: usize = lines.iter().position(|line| !line.starts_with("<!--")).unwrap();
+    let original: String = lines[index].split_once("<#>").unwrap().0.to_owned();
+    lines[index] = format!("{original}<#>Draft<#>Old");
+    write(&items_txt_path, lines.join("\n")).unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    let sheet_path = sheets_path.join("items.csv");
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    assert_eq!(rows[1][target_column], "Old");
+
+    let target = "Line one\nSay \"hi\", friend";
+    rows[1][target_column] = target.to_owned();
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert!(report.is_clean());
+    assert_eq!(
+        read_to_string(&items_txt_path).unwrap().split('\n').nth(index).unwrap(),
+        format!("{original}<#>Draft<#>{}", escape(target))
+    );
+}
+
+#[test]
+fn sheets_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let sheets_path = game_path.join("sheets");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let maps = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    for format in [SheetFormat::Csv, SheetFormat::Tsv] {
+        SheetExporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .notes(true)
+            .export();
+
+        // Unchanged sheets are imported without changes
+        let report = SheetImporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .import();
+
+        assert!(report.is_clean());
+        assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), maps);
+    }
+
+    // Per-map files are exported to a single maps sheet, and imported back into per-map files
+    let per_map_path = game_path.join("translation_sheets_per_map");
+    let per_map_sheets_path = game_path.join("sheets_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    SheetExporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .notes(true)
+        .export();
+
+    let sheet_path = per_map_sheets_path.join("maps.csv");
+    assert_eq!(
+        read_to_string(&sheet_path).unwrap(),
+        read_to_string(sheets_path.join("maps.csv")).unwrap()
+    );
+
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    rows[1][target_column] = String::from("Per-map");
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .import();
+    assert!(report.is_clean());
+    assert_eq!(report.imported, ["maps.txt"]);
+    assert!(!per_map_path.join("maps.txt").exists());
+
+    let map_contents: Vec<String> = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| read_to_string(entry.path()).unwrap())
+        .collect();
+    assert!(map_contents
+        .iter()
+        .all(|content| content.starts_with("<!-- Format --><#>")));
+    assert!(map_contents.iter().any(|content| content.contains("<#>Per-map")));
+
+    remove_dir_all(&per_map_path).unwrap();
+    remove_dir_all(&per_map_sheets_path).unwrap();
+
+    // Sheets with extra rows are rejected
+    let sheet_path = sheets_path.join("maps.csv");
+    let sheet = read_to_string(&sheet_path).unwrap();
+    write(&sheet_path, sheet + "maps.txt,,extra,extra\n").unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert_eq!(report.mismatched.len(), 1);
+    assert_eq!(report.mismatched[0].actual, report.mismatched[0].expected + 1);
+
+    // The first item line has an earlier draft column, which is kept when the sheet edits its translation
+    let items_txt_path = translation_path.join("items.txt");
+    let mut lines: Vec<String> = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(str::to_owned)
+        .collect();
+    let index: usize = lines.iter().position(|line| !line.starts_with("<!--")).unwrap();
+    let original: String = lines[index].split_once("<#>").unwrap().0.to_owned();
+    lines[index] = format!("{original}<#>Draft<#>Old");
+    write(&items_txt_path, lines.join("\n")).unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    let sheet_path = sheets_path.join("items.csv");
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    assert_eq!(rows[1][target_column], "Old");
+
+    let target = "Line one\nSay \"hi\", friend";
+    rows[1][target_column] = target.to_owned();
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert!(report.is_clean());
+    assert_eq!(
+        read_to_string(&items_txt_path).unwrap().split('\n').nth(index).unwrap(),
+        format!("{original}<#>Draft<#>{}", escape(target))
+    );
+}
+
+#[test]
+fn sheets_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let sheets_path = game_path.join("sheets");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let maps = read_to_string(translation_path.join("maps.txt")).unwrap();
+
+    for format in [SheetFormat::Csv, SheetFormat::Tsv] {
+        SheetExporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .notes(true)
+            .export();
+
+        // Unchanged sheets are imported without changes
+        let report = SheetImporter::new(&translation_path, &sheets_path)
+            .format(format)
+            .import();
+
+        assert!(report.is_clean());
+        assert_eq!(read_to_string(translation_path.join("maps.txt")).unwrap(), maps);
+    }
+
+    // Per-map files are exported to a single maps sheet, and imported back into per-map files
+    let per_map_path = game_path.join("translation_sheets_per_map");
+    let per_map_sheets_path = game_path.join("sheets_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    SheetExporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .notes(true)
+        .export();
+
+    let sheet_path = per_map_sheets_path.join("maps.csv");
+    assert_eq!(
+        read_to_string(&sheet_path).unwrap(),
+        read_to_string(sheets_path.join("maps.csv")).unwrap()
+    );
+
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    rows[1][target_column] = String::from("Per-map");
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&per_map_path, &per_map_sheets_path)
+        .maps_layout(MapsLayout::PerMap)
+        .import();
+    assert!(report.is_clean());
+    assert_eq!(report.imported, ["maps.txt"]);
+    assert!(!per_map_path.join("maps.txt").exists());
+
+    let map_contents: Vec<String> = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| read_to_string(entry.path()).unwrap())
+        .collect();
+    assert!(map_contents
+        .iter()
+        .all(|content| content.starts_with("<!-- Format --><#>")));
+    assert!(map_contents.iter().any(|content| content.contains("<#>Per-map")));
+
+    remove_dir_all(&per_map_path).unwrap();
+    remove_dir_all(&per_map_sheets_path).unwrap();
+
+    // Sheets with extra rows are rejected
+    let sheet_path = sheets_path.join("maps.csv");
+    let sheet = read_to_string(&sheet_path).unwrap();
+    write(&sheet_path, sheet + "maps.txt,,extra,extra\n").unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert_eq!(report.mismatched.len(), 1);
+    assert_eq!(report.mismatched[0].actual, report.mismatched[0].expected + 1);
+
+    // The first item line has an earlier draft column, which is kept when the sheet edits its translation
+    let items_txt_path = translation_path.join("items.txt");
+    let mut lines: Vec<String> = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(str::to_owned)
+        .collect();
+    let index: usize = lines.iter().position(|line| !line.starts_with("<!--")).unwrap();
+    let original: String = lines[index].split_once("<#>").unwrap().0.to_owned();
+    lines[index] = format!("{original}<#>Draft<#>Old");
+    write(&items_txt_path, lines.join("\n")).unwrap();
+
+    SheetExporter::new(&translation_path, &sheets_path).export();
+
+    let sheet_path = sheets_path.join("items.csv");
+    let mut rows: Vec<Vec<String>> = parse_sheet(&read_to_string(&sheet_path).unwrap(), ',');
+    let target_column: usize = rows[0].iter().position(|x| x == "target").unwrap();
+    assert_eq!(rows[1][target_column], "Old");
+
+    let target = "Line one\nSay \"hi\", friend";
+    rows[1][target_column] = target.to_owned();
+    let sheet: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|cell| quote_cell(cell, ','))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .collect();
+    write(&sheet_path, sheet.join("\n")).unwrap();
+
+    let report = SheetImporter::new(&translation_path, &sheets_path).import();
+
+    assert!(report.is_clean());
+    assert_eq!(
+        read_to_string(&items_txt_path).unwrap().split('\n').nth(index).unwrap(),
+        format!("{original}<#>Draft<#>{}", escape(target))
+    );
+}
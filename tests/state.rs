@@ -0,0 +1,736 @@
+use rvpacker_txt_rs_lib::{
+    purge::OtherPurger,
+    read::{OtherReader, PluginReader, ScriptReader},
+    state::{parse_states, state_comment},
+    types::{EngineType, LineState, ProcessingMode, PurgeReason, PurgedLine},
+    write::{OtherWriter, PluginWriter, ScriptWriter},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn plugin_states() {
+    let translation_path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("plugin_states");
+    let plugins_file_path = translation_path.join("plugins.js");
+    let plugins_txt_path = translation_path.join("plugins.txt");
+
+    create_dir_all(&translation_path).unwrap();
+    write(
+        &plugins_file_path,
+        r#"var $plugins = [{"name":"Greetings","status":true,"description":"","parameters":{"Morning":"Good morning","Night":"Good night"}}];"#,
+    )
+    .unwrap();
+
+    PluginReader::new(&plugins_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Both lines have their own state
+    let seeded: String = read_to_string(&plugins_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Good morning<#>" => format!("{}\n{line}Доброе утро", state_comment(LineState::Approved)),
+            "Good night<#>" => format!("{}\n{line}Спокойной ночи", state_comment(LineState::Fuzzy)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&plugins_txt_path, &seeded).unwrap();
+
+    // States stay attached to their lines after appending
+    PluginReader::new(&plugins_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+
+    let content: String = read_to_string(&plugins_txt_path).unwrap();
+    assert_eq!(content, seeded);
+    assert_eq!(
+        parse_states(&content),
+        [
+            (
+                String::from("Good morning"),
+                String::from("Доброе утро"),
+                LineState::Approved
+            ),
+            (
+                String::from("Good night"),
+                String::from("Спокойной ночи"),
+                LineState::Fuzzy
+            ),
+        ]
+    );
+}
+
+#[test]
+fn states_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_states");
+    let output_path = game_path.join("output_states");
+    let items_txt_path = translation_path.join("items.txt");
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every item line is translated, some lines have a state
+    let seeded: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Sword<#lt#>>X<#>" => format!("{}\n{line}Blade", state_comment(LineState::Approved)),
+            "エーテル<#>" => format!("{line}Ether"),
+            "MPを回復<#>" => format!("{}\n{line}Restores MP", state_comment(LineState::NeedsReview)),
+            _ if !line.starts_with("<!--") => format!("{}\n{line}Machine", state_comment(LineState::MachineTranslated)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &seeded).unwrap();
+
+    // States survive appending
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), seeded);
+
+    let states: Vec<(String, String, LineState)> = parse_states(&seeded);
+    assert_eq!(states.len(), 3);
+    assert!(states.contains(&(
+        String::from("MPを回復"),
+        String::from("Restores MP"),
+        LineState::NeedsReview
+    )));
+
+    // Written translations of the items
+    let written = |skip: Vec<LineState>, skip_stateless: bool| -> Vec<String> {
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .skip_states(skip)
+            .skip_stateless(skip_stateless)
+            .dry_run(true)
+            .write()
+            .replacements
+            .into_iter()
+            .filter(|x| x.file.starts_with("Items") && !x.translation.is_empty())
+            .map(|x| x.translation)
+            .collect()
+    };
+
+    let mut all: Vec<String> = written(Vec::new(), false);
+    all.sort();
+    assert_eq!(all, ["Blade", "Ether", "Machine", "Restores MP"]);
+
+    // Lines of skipped states are cleared
+    let mut reviewed: Vec<String> = written(vec![LineState::MachineTranslated, LineState::NeedsReview], false);
+    reviewed.sort();
+    assert_eq!(reviewed, ["Blade", "Ether"]);
+
+    // Lines without state are cleared too, so only approved lines are shipped
+    assert_eq!(
+        written(
+            vec![LineState::MachineTranslated, LineState::Fuzzy, LineState::NeedsReview],
+            true
+        ),
+        ["Blade"]
+    );
+
+    // Lines of purged states are removed along with their state comments
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_states(vec![LineState::NeedsReview])
+        .purge(None, None);
+    assert_eq!(
+        purged
+            .into_iter()
+            .filter(|x| x.reason == PurgeReason::State)
+            .collect::<Vec<_>>(),
+        [PurgedLine {
+            file: String::from("items.txt"),
+            map: None,
+            original: String::from("MPを回復"),
+            translation: String::from("Restores MP"),
+            reason: PurgeReason::State,
+        }]
+    );
+
+    let content: String = read_to_string(&items_txt_path).unwrap();
+    assert!(!content.contains("MPを回復"));
+    assert_eq!(parse_states(&content).len(), 2);
+
+    // Plugins writer skips states as well
+    PluginReader::new(&plugins_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let plugins_txt_path = translation_path.join("plugins.txt");
+    let translated: String = read_to_string(&plugins_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}Translated line", state_comment(LineState::Fuzzy))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&plugins_txt_path, &translated).unwrap();
+
+    let is_translated = |skip: Vec<LineState>| -> bool {
+        PluginWriter::new(&plugins_file_path, &translation_path, &output_path)
+            .skip_states(skip)
+            .dry_run(true)
+            .write()
+            .replacements
+            .iter()
+            .any(|x| x.translation.contains("Translated line"))
+    };
+
+    assert!(is_translated(Vec::new()));
+    assert!(!is_translated(vec![LineState::Fuzzy]));
+}
+
+#[test]
+fn states_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_states");
+    let output_path = game_path.join("output_states");
+    let items_txt_path = translation_path.join("items.txt");
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every item line is translated, some lines have a state
+    let seeded: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Sword<#lt#>>X<#>" => format!("{}\n{line}Blade", state_comment(LineState::Approved)),
+            "エーテル<#>" => format!("{line}Ether"),
+            "MPを回復<#>" => format!("{}\n{line}Restores MP", state_comment(LineState::NeedsReview)),
+            _ if !line.starts_with("<!--") => format!("{}\n{line}Machine", state_comment(LineState::MachineTranslated)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &seeded).unwrap();
+
+    // States survive appending
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), seeded);
+
+    let states: Vec<(String, String, LineState)> = parse_states(&seeded);
+    assert_eq!(states.len(), 3);
+    assert!(states.contains(&(
+        String::from("MPを回復"),
+        String::from("Restores MP"),
+        LineState::NeedsReview
+    )));
+
+    // Written translations of the items
+    let written = |skip: Vec<LineState>, skip_stateless: bool| -> Vec<String> {
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .skip_states(skip)
+            .skip_stateless(skip_stateless)
+            .dry_run(true)
+            .write()
+            .replacements
+            .into_iter()
+            .filter(|x| x.file.starts_with("Items") && !x.translation.is_empty())
+            .map(|x| x.translation)
+            .collect()
+    };
+
+    let mut all: Vec<String> = written(Vec::new(), false);
+    all.sort();
+    assert_eq!(all, ["Blade", "Ether", "Machine", "Restores MP"]);
+
+    // Lines of skipped states are cleared
+    let mut reviewed: Vec<String> = written(vec![LineState::MachineTranslated, LineState::NeedsReview], false);
+    reviewed.sort();
+    assert_eq!(reviewed, ["Blade", "Ether"]);
+
+    // Lines without state are cleared too, so only approved lines are shipped
+    assert_eq!(
+        written(
+            vec![LineState::MachineTranslated, LineState::Fuzzy, LineState::NeedsReview],
+            true
+        ),
+        ["Blade"]
+    );
+
+    // Lines of purged states are removed along with their state comments
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_states(vec![LineState::NeedsReview])
+        .purge(None, None);
+    assert_eq!(
+        purged
+            .into_iter()
+            .filter(|x| x.reason == PurgeReason::State)
+            .collect::<Vec<_>>(),
+        [PurgedLine {
+            file: String::from("items.txt"),
+            map: None,
+            original: String::from("MPを回復"),
+            translation: String::from("Restores MP"),
+            reason: PurgeReason::State,
+        }]
+    );
+
+    let content: String = read_to_string(&items_txt_path).unwrap();
+    assert!(!content.contains("MPを回復"));
+    assert_eq!(parse_states(&content).len(), 2);
+
+    // Plugins writer skips states as well
+    PluginReader::new(&plugins_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let plugins_txt_path = translation_path.join("plugins.txt");
+    let translated: String = read_to_string(&plugins_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}Translated line", state_comment(LineState::Fuzzy))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&plugins_txt_path, &translated).unwrap();
+
+    let is_translated = |skip: Vec<LineState>| -> bool {
+        PluginWriter::new(&plugins_file_path, &translation_path, &output_path)
+            .skip_states(skip)
+            .dry_run(true)
+            .write()
+            .replacements
+            .iter()
+            .any(|x| x.translation.contains("Translated line"))
+    };
+
+    assert!(is_translated(Vec::new()));
+    assert!(!is_translated(vec![LineState::Fuzzy]));
+}
+
+#[test]
+fn states_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_states");
+    let output_path = game_path.join("output_states");
+    let items_txt_path = translation_path.join("items.txt");
+    let scripts_file_path = data_path.join("Scripts.rvdata2");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every item line is translated, some lines have a state
+    let seeded: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Sword<#lt#>>X<#>" => format!("{}\n{line}Blade", state_comment(LineState::Approved)),
+            "エーテル<#>" => format!("{line}Ether"),
+            "MPを回復<#>" => format!("{}\n{line}Restores MP", state_comment(LineState::NeedsReview)),
+            _ if !line.starts_with("<!--") => format!("{}\n{line}Machine", state_comment(LineState::MachineTranslated)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &seeded).unwrap();
+
+    // States survive appending
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), seeded);
+
+    let states: Vec<(String, String, LineState)> = parse_states(&seeded);
+    assert_eq!(states.len(), 3);
+    assert!(states.contains(&(
+        String::from("MPを回復"),
+        String::from("Restores MP"),
+        LineState::NeedsReview
+    )));
+
+    // Written translations of the items
+    let written = |skip: Vec<LineState>, skip_stateless: bool| -> Vec<String> {
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .skip_states(skip)
+            .skip_stateless(skip_stateless)
+            .dry_run(true)
+            .write()
+            .replacements
+            .into_iter()
+            .filter(|x| x.file.starts_with("Items") && !x.translation.is_empty())
+            .map(|x| x.translation)
+            .collect()
+    };
+
+    let mut all: Vec<String> = written(Vec::new(), false);
+    all.sort();
+    assert_eq!(all, ["Blade", "Ether", "Machine", "Restores MP"]);
+
+    // Lines of skipped states are cleared
+    let mut reviewed: Vec<String> = written(vec![LineState::MachineTranslated, LineState::NeedsReview], false);
+    reviewed.sort();
+    assert_eq!(reviewed, ["Blade", "Ether"]);
+
+    // Lines without state are cleared too, so only approved lines are shipped
+    assert_eq!(
+        written(
+            vec![LineState::MachineTranslated, LineState::Fuzzy, LineState::NeedsReview],
+            true
+        ),
+        ["Blade"]
+    );
+
+    // Lines of purged states are removed along with their state comments
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_states(vec![LineState::NeedsReview])
+        .purge(None, None);
+    assert_eq!(
+        purged
+            .into_iter()
+            .filter(|x| x.reason == PurgeReason::State)
+            .collect::<Vec<_>>(),
+        [PurgedLine {
+            file: String::from("items.txt"),
+            map: None,
+            original: String::from("MPを回復"),
+            translation: String::from("Restores MP"),
+            reason: PurgeReason::State,
+        }]
+    );
+
+    let content: String = read_to_string(&items_txt_path).unwrap();
+    assert!(!content.contains("MPを回復"));
+    assert_eq!(parse_states(&content).len(), 2);
+
+    // Scripts writer skips states as well
+    ScriptReader::new(&scripts_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let scripts_txt_path = translation_path.join("scripts.txt");
+    let translated: String = read_to_string(&scripts_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}Translated line", state_comment(LineState::Fuzzy))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&scripts_txt_path, &translated).unwrap();
+
+    let is_translated = |skip: Vec<LineState>| -> bool {
+        ScriptWriter::new(&scripts_file_path, &translation_path, &output_path)
+            .skip_states(skip)
+            .dry_run(true)
+            .write()
+            .replacements
+            .iter()
+            .any(|x| x.translation.contains("Translated line"))
+    };
+
+    assert!(is_translated(Vec::new()));
+    assert!(!is_translated(vec![LineState::Fuzzy]));
+}
+
+#[test]
+fn states_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_states");
+    let output_path = game_path.join("output_states");
+    let items_txt_path = translation_path.join("items.txt");
+    let scripts_file_path = data_path.join("Scripts.rvdata");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every item line is translated, some lines have a state
+    let seeded: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Sword<#lt#>>X<#>" => format!("{}\n{line}Blade", state_comment(LineState::Approved)),
+            "エーテル<#>" => format!("{line}Ether"),
+            "MPを回復<#>" => format!("{}\n{line}Restores MP", state_comment(LineState::NeedsReview)),
+            _ if !line.starts_with("<!--") => format!("{}\n{line}Machine", state_comment(LineState::MachineTranslated)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &seeded).unwrap();
+
+    // States survive appending
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), seeded);
+
+    let states: Vec<(String, String, LineState)> = parse_states(&seeded);
+    assert_eq!(states.len(), 3);
+    assert!(states.contains(&(
+        String::from("MPを回復"),
+        String::from("Restores MP"),
+        LineState::NeedsReview
+    )));
+
+    // Written translations of the items
+    let written = |skip: Vec<LineState>, skip_stateless: bool| -> Vec<String> {
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .skip_states(skip)
+            .skip_stateless(skip_stateless)
+            .dry_run(true)
+            .write()
+            .replacements
+            .into_iter()
+            .filter(|x| x.file.starts_with("Items") && !x.translation.is_empty())
+            .map(|x| x.translation)
+            .collect()
+    };
+
+    let mut all: Vec<String> = written(Vec::new(), false);
+    all.sort();
+    assert_eq!(all, ["Blade", "Ether", "Machine", "Restores MP"]);
+
+    // Lines of skipped states are cleared
+    let mut reviewed: Vec<String> = written(vec![LineState::MachineTranslated, LineState::NeedsReview], false);
+    reviewed.sort();
+    assert_eq!(reviewed, ["Blade", "Ether"]);
+
+    // Lines without state are cleared too, so only approved lines are shipped
+    assert_eq!(
+        written(
+            vec![LineState::MachineTranslated, LineState::Fuzzy, LineState::NeedsReview],
+            true
+        ),
+        ["Blade"]
+    );
+
+    // Lines of purged states are removed along with their state comments
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_states(vec![LineState::NeedsReview])
+        .purge(None, None);
+    assert_eq!(
+        purged
+            .into_iter()
+            .filter(|x| x.reason == PurgeReason::State)
+            .collect::<Vec<_>>(),
+        [PurgedLine {
+            file: String::from("items.txt"),
+            map: None,
+            original: String::from("MPを回復"),
+            translation: String::from("Restores MP"),
+            reason: PurgeReason::State,
+        }]
+    );
+
+    let content: String = read_to_string(&items_txt_path).unwrap();
+    assert!(!content.contains("MPを回復"));
+    assert_eq!(parse_states(&content).len(), 2);
+
+    // Scripts writer skips states as well
+    ScriptReader::new(&scripts_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let scripts_txt_path = translation_path.join("scripts.txt");
+    let translated: String = read_to_string(&scripts_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}Translated line", state_comment(LineState::Fuzzy))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&scripts_txt_path, &translated).unwrap();
+
+    let is_translated = |skip: Vec<LineState>| -> bool {
+        ScriptWriter::new(&scripts_file_path, &translation_path, &output_path)
+            .skip_states(skip)
+            .dry_run(true)
+            .write()
+            .replacements
+            .iter()
+            .any(|x| x.translation.contains("Translated line"))
+    };
+
+    assert!(is_translated(Vec::new()));
+    assert!(!is_translated(vec![LineState::Fuzzy]));
+}
+
+#[test]
+fn states_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation_states");
+    let output_path = game_path.join("output_states");
+    let items_txt_path = translation_path.join("items.txt");
+    let scripts_file_path = data_path.join("Scripts.rxdata");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+    create_dir_all(&output_path).unwrap();
+
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    // Every item line is translated, some lines have a state
+    let seeded: String = read_to_string(&items_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| match line {
+            "Sword<#lt#>>X<#>" => format!("{}\n{line}Blade", state_comment(LineState::Approved)),
+            "エーテル<#>" => format!("{line}Ether"),
+            "MPを回復<#>" => format!("{}\n{line}Restores MP", state_comment(LineState::NeedsReview)),
+            _ if !line.starts_with("<!--") => format!("{}\n{line}Machine", state_comment(LineState::MachineTranslated)),
+            _ => line.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&items_txt_path, &seeded).unwrap();
+
+    // States survive appending
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert_eq!(read_to_string(&items_txt_path).unwrap(), seeded);
+
+    let states: Vec<(String, String, LineState)> = parse_states(&seeded);
+    assert_eq!(states.len(), 3);
+    assert!(states.contains(&(
+        String::from("MPを回復"),
+        String::from("Restores MP"),
+        LineState::NeedsReview
+    )));
+
+    // Written translations of the items
+    let written = |skip: Vec<LineState>, skip_stateless: bool| -> Vec<String> {
+        OtherWriter::new(&data_path, &translation_path, &output_path, engine_type)
+            .skip_states(skip)
+            .skip_stateless(skip_stateless)
+            .dry_run(true)
+            .write()
+            .replacements
+            .into_iter()
+            .filter(|x| x.file.starts_with("Items") && !x.translation.is_empty())
+            .map(|x| x.translation)
+            .collect()
+    };
+
+    let mut all: Vec<String> = written(Vec::new(), false);
+    all.sort();
+    assert_eq!(all, ["Blade", "Ether", "Machine", "Restores MP"]);
+
+    // Lines of skipped states are cleared
+    let mut reviewed: Vec<String> = written(vec![LineState::MachineTranslated, LineState::NeedsReview], false);
+    reviewed.sort();
+    assert_eq!(reviewed, ["Blade", "Ether"]);
+
+    // Lines without state are cleared too, so only approved lines are shipped
+    assert_eq!(
+        written(
+            vec![LineState::MachineTranslated, LineState::Fuzzy, LineState::NeedsReview],
+            true
+        ),
+        ["Blade"]
+    );
+
+    // Lines of purged states are removed along with their state comments
+    let purged = OtherPurger::new(&data_path, &translation_path, engine_type)
+        .purge_states(vec![LineState::NeedsReview])
+        .purge(None, None);
+    assert_eq!(
+        purged
+            .into_iter()
+            .filter(|x| x.reason == PurgeReason::State)
+            .collect::<Vec<_>>(),
+        [PurgedLine {
+            file: String::from("items.txt"),
+            map: None,
+            original: String::from("MPを回復"),
+            translation: String::from("Restores MP"),
+            reason: PurgeReason::State,
+        }]
+    );
+
+    let content: String = read_to_string(&items_txt_path).unwrap();
+    assert!(!content.contains("MPを回復"));
+    assert_eq!(parse_states(&content).len(), 2);
+
+    // Scripts writer skips states as well
+    ScriptReader::new(&scripts_file_path, &translation_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let scripts_txt_path = translation_path.join("scripts.txt");
+    let translated: String = read_to_string(&scripts_txt_path)
+        .unwrap()
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("<!--") {
+                line.to_owned()
+            } else {
+                format!("{}\n{line}Translated line", state_comment(LineState::Fuzzy))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    write(&scripts_txt_path, &translated).unwrap();
+
+    let is_translated = |skip: Vec<LineState>| -> bool {
+        ScriptWriter::new(&scripts_file_path, &translation_path, &output_path)
+            .skip_states(skip)
+            .dry_run(true)
+            .write()
+            .replacements
+            .iter()
+            .any(|x| x.translation.contains("Translated line"))
+    };
+
+    assert!(is_translated(Vec::new()));
+    assert!(!is_translated(vec![LineState::Fuzzy]));
+}
@@ -0,0 +1,874 @@
+use rvpacker_txt_rs_lib::{
+    read::{MapReader, OtherReader, PluginReader},
+    stats::{read_stats, write_stats, FileStats, StatsCollector},
+    types::{EngineType, MapsLayout, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+};
+
+#[test]
+fn stats_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let stats = StatsCollector::new(&translation_path, engine_type)
+        .original_path(Some(&data_path))
+        .collect();
+
+    // Per-map files are counted as a single maps file, and their unused lines are counted
+    let per_map_path = game_path.join("translation_stats_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    OtherReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let collect_per_map = || {
+        StatsCollector::new(&per_map_path, engine_type)
+            .original_path(Some(&data_path))
+            .maps_layout(MapsLayout::PerMap)
+            .collect()
+    };
+
+    let per_map_stats = collect_per_map();
+    assert_eq!(per_map_stats.files["maps.txt"], stats.files["maps.txt"]);
+    assert_eq!(per_map_stats.maps, stats.maps);
+
+    let map_file_path = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .min()
+        .unwrap();
+    let map_content = read_to_string(&map_file_path).unwrap();
+    write(&map_file_path, format!("{map_content}\nStale line<#>Stale translation")).unwrap();
+
+    // Unused lines are attributed to their maps
+    let first_map: u16 = *stats.maps.keys().min().unwrap();
+    let unused_stats = collect_per_map();
+    assert_eq!(
+        unused_stats.files["maps.txt"].unused,
+        stats.files["maps.txt"].unused + 1
+    );
+    assert_eq!(unused_stats.maps[&first_map].unused, stats.maps[&first_map].unused + 1);
+    assert_eq!(
+        unused_stats.maps.values().map(|x| x.unused).sum::<usize>(),
+        unused_stats.files["maps.txt"].unused
+    );
+
+    // Unused lines of plugins are counted, if the plugins file is set
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let plugins_txt_path = per_map_path.join("plugins.txt");
+
+    PluginReader::new(&plugins_file_path, &per_map_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    let plugins_content = read_to_string(&plugins_txt_path).unwrap();
+    write(
+        &plugins_txt_path,
+        format!("{plugins_content}\nStale plugin line<#>Stale translation"),
+    )
+    .unwrap();
+
+    let plugins_stats = StatsCollector::new(&per_map_path, engine_type)
+        .original_path(Some(&data_path))
+        .plugins_file_path(Some(&plugins_file_path))
+        .maps_layout(MapsLayout::PerMap)
+        .collect();
+    assert_eq!(plugins_stats.files["plugins.txt"].unused, 1);
+    assert_eq!(collect_per_map().files["plugins.txt"].unused, 0);
+
+    remove_dir_all(&per_map_path).unwrap();
+    write_stats(&stats, &translation_path);
+
+    assert_eq!(read_stats(&translation_path), Some(stats));
+
+    // Counts of a known translation
+    let known_path = game_path.join("translation_stats");
+    create_dir_all(&known_path).unwrap();
+
+    write(
+        known_path.join("maps.txt"),
+        "<!-- Map --><#>Map001\nHello world<#>Привет мир\nYes<#>\nHello world<#>Привет мир\n<!-- Map --><#>Map002\n\
+         <!-- State --><#>approved\nYes<#>Да\nGoodbye<#>",
+    )
+    .unwrap();
+    write(known_path.join("items.txt"), "Potion<#>Зелье\nGoodbye<#>Пока\nEther<#>").unwrap();
+
+    let stats = StatsCollector::new(&known_path, engine_type).collect();
+
+    assert_eq!(
+        stats.maps[&1],
+        FileStats {
+            total: 3,
+            translated: 2,
+            duplicates: 1,
+            source_chars: 25,
+            source_words: 5,
+            target_chars: 20,
+            target_words: 4,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.maps[&2],
+        FileStats {
+            total: 2,
+            translated: 1,
+            duplicates: 1,
+            source_chars: 10,
+            source_words: 2,
+            target_chars: 2,
+            target_words: 1,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["maps.txt"],
+        FileStats {
+            total: 5,
+            translated: 3,
+            duplicates: 2,
+            source_chars: 35,
+            source_words: 7,
+            target_chars: 22,
+            target_words: 5,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["items.txt"],
+        FileStats {
+            total: 3,
+            translated: 2,
+            source_chars: 18,
+            source_words: 3,
+            target_chars: 9,
+            target_words: 2,
+            ..FileStats::default()
+        }
+    );
+
+    // Duplicates of the whole project are counted across files
+    assert_eq!(
+        stats.total,
+        FileStats {
+            total: 8,
+            translated: 5,
+            duplicates: 3,
+            source_chars: 53,
+            source_words: 10,
+            target_chars: 31,
+            target_words: 7,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.summary(None).split('\n').next(),
+        Some(
+            "Total: 5/8 (62.5%), source: 53 chars/10 words, translation: 31 chars/7 words, duplicates: 3, unused: 0, \
+             machine: 0, fuzzy: 0, needs review: 0, approved: 1"
+        )
+    );
+
+    remove_dir_all(&known_path).unwrap();
+}
+
+#[test]
+fn stats_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let stats = StatsCollector::new(&translation_path, engine_type)
+        .original_path(Some(&data_path))
+        .collect();
+
+    // Per-map files are counted as a single maps file, and their unused lines are counted
+    let per_map_path = game_path.join("translation_stats_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    OtherReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let collect_per_map = || {
+        StatsCollector::new(&per_map_path, engine_type)
+            .original_path(Some(&data_path))
+            .maps_layout(MapsLayout::PerMap)
+            .collect()
+    };
+
+    let per_map_stats = collect_per_map();
+    assert_eq!(per_map_stats.files["maps.txt"], stats.files["maps.txt"]);
+    assert_eq!(per_map_stats.maps, stats.maps);
+
+    let map_file_path = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .min()
+        .unwrap();
+    let map_content = read_to_string(&map_file_path).unwrap();
+    write(&map_file_path, format!("{map_content}\nStale line<#>Stale translation")).unwrap();
+
+    // Unused lines are attributed to their maps
+    let first_map: u16 = *stats.maps.keys().min().unwrap();
+    let unused_stats = collect_per_map();
+    assert_eq!(
+        unused_stats.files["maps.txt"].unused,
+        stats.files["maps.txt"].unused + 1
+    );
+    assert_eq!(unused_stats.maps[&first_map].unused, stats.maps[&first_map].unused + 1);
+    assert_eq!(
+        unused_stats.maps.values().map(|x| x.unused).sum::<usize>(),
+        unused_stats.files["maps.txt"].unused
+    );
+
+    // Unused lines of plugins are counted, if the plugins file is set
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let plugins_txt_path = per_map_path.join("plugins.txt");
+
+    PluginReader::new(&plugins_file_path, &per_map_path)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    let plugins_content = read_to_string(&plugins_txt_path).unwrap();
+    write(
+        &plugins_txt_path,
+        format!("{plugins_content}\nStale plugin line<#>Stale translation"),
+    )
+    .unwrap();
+
+    let plugins_stats = StatsCollector::new(&per_map_path, engine_type)
+        .original_path(Some(&data_path))
+        .plugins_file_path(Some(&plugins_file_path))
+        .maps_layout(MapsLayout::PerMap)
+        .collect();
+    assert_eq!(plugins_stats.files["plugins.txt"].unused, 1);
+    assert_eq!(collect_per_map().files["plugins.txt"].unused, 0);
+
+    remove_dir_all(&per_map_path).unwrap();
+    write_stats(&stats, &translation_path);
+
+    assert_eq!(read_stats(&translation_path), Some(stats));
+
+    // Counts of a known translation
+    let known_path = game_path.join("translation_stats");
+    create_dir_all(&known_path).unwrap();
+
+    write(
+        known_path.join("maps.txt"),
+        "<!-- Map --><#>Map001\nHello world<#>Привет мир\nYes<#>\nHello world<#>Привет мир\n<!-- Map --><#>Map002\n\
+         <!-- State --><#>approved\nYes<#>Да\nGoodbye<#>",
+    )
+    .unwrap();
+    write(known_path.join("items.txt"), "Potion<#>Зелье\nGoodbye<#>Пока\nEther<#>").unwrap();
+
+    let stats = StatsCollector::new(&known_path, engine_type).collect();
+
+    assert_eq!(
+        stats.maps[&1],
+        FileStats {
+            total: 3,
+            translated: 2,
+            duplicates: 1,
+            source_chars: 25,
+            source_words: 5,
+            target_chars: 20,
+            target_words: 4,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.maps[&2],
+        FileStats {
+            total: 2,
+            translated: 1,
+            duplicates: 1,
+            source_chars: 10,
+            source_words: 2,
+            target_chars: 2,
+            target_words: 1,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["maps.txt"],
+        FileStats {
+            total: 5,
+            translated: 3,
+            duplicates: 2,
+            source_chars: 35,
+            source_words: 7,
+            target_chars: 22,
+            target_words: 5,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["items.txt"],
+        FileStats {
+            total: 3,
+            translated: 2,
+            source_chars: 18,
+            source_words: 3,
+            target_chars: 9,
+            target_words: 2,
+            ..FileStats::default()
+        }
+    );
+
+    // Duplicates of the whole project are counted across files
+    assert_eq!(
+        stats.total,
+        FileStats {
+            total: 8,
+            translated: 5,
+            duplicates: 3,
+            source_chars: 53,
+            source_words: 10,
+            target_chars: 31,
+            target_words: 7,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.summary(None).split('\n').next(),
+        Some(
+            "Total: 5/8 (62.5%), source: 53 chars/10 words, translation: 31 chars/7 words, duplicates: 3, unused: 0, \
+             machine: 0, fuzzy: 0, needs review: 0, approved: 1"
+        )
+    );
+
+    remove_dir_all(&known_path).unwrap();
+}
+
+#[test]
+fn stats_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let stats = StatsCollector::new(&translation_path, engine_type)
+        .original_path(Some(&data_path))
+        .collect();
+
+    // Per-map files are counted as a single maps file, and their unused lines are counted
+    let per_map_path = game_path.join("translation_stats_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    OtherReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let collect_per_map = || {
+        StatsCollector::new(&per_map_path, engine_type)
+            .original_path(Some(&data_path))
+            .maps_layout(MapsLayout::PerMap)
+            .collect()
+    };
+
+    let per_map_stats = collect_per_map();
+    assert_eq!(per_map_stats.files["maps.txt"], stats.files["maps.txt"]);
+    assert_eq!(per_map_stats.maps, stats.maps);
+
+    let map_file_path = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .min()
+        .unwrap();
+    let map_content = read_to_string(&map_file_path).unwrap();
+    write(&map_file_path, format!("{map_content}\nStale line<#>Stale translation")).unwrap();
+
+    // Unused lines are attributed to their maps
+    let first_map: u16 = *stats.maps.keys().min().unwrap();
+    let unused_stats = collect_per_map();
+    assert_eq!(
+        unused_stats.files["maps.txt"].unused,
+        stats.files["maps.txt"].unused + 1
+    );
+    assert_eq!(unused_stats.maps[&first_map].unused, stats.maps[&first_map].unused + 1);
+    assert_eq!(
+        unused_stats.maps.values().map(|x| x.unused).sum::<usize>(),
+        unused_stats.files["maps.txt"].unused
+    );
+
+    remove_dir_all(&per_map_path).unwrap();
+    write_stats(&stats, &translation_path);
+
+    assert_eq!(read_stats(&translation_path), Some(stats));
+
+    // Counts of a known translation
+    let known_path = game_path.join("translation_stats");
+    create_dir_all(&known_path).unwrap();
+
+    write(
+        known_path.join("maps.txt"),
+        "<!-- Map --><#>Map001\nHello world<#>Привет мир\nYes<#>\nHello world<#>Привет мир\n<!-- Map --><#>Map002\n\
+         <!-- State --><#>approved\nYes<#>Да\nGoodbye<#>",
+    )
+    .unwrap();
+    write(known_path.join("items.txt"), "Potion<#>Зелье\nGoodbye<#>Пока\nEther<#>").unwrap();
+
+    let stats = StatsCollector::new(&known_path, engine_type).collect();
+
+    assert_eq!(
+        stats.maps[&1],
+        FileStats {
+            total: 3,
+            translated: 2,
+            duplicates: 1,
+            source_chars: 25,
+            source_words: 5,
+            target_chars: 20,
+            target_words: 4,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.maps[&2],
+        FileStats {
+            total: 2,
+            translated: 1,
+            duplicates: 1,
+            source_chars: 10,
+            source_words: 2,
+            target_chars: 2,
+            target_words: 1,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["maps.txt"],
+        FileStats {
+            total: 5,
+            translated: 3,
+            duplicates: 2,
+            source_chars: 35,
+            source_words: 7,
+            target_chars: 22,
+            target_words: 5,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["items.txt"],
+        FileStats {
+            total: 3,
+            translated: 2,
+            source_chars: 18,
+            source_words: 3,
+            target_chars: 9,
+            target_words: 2,
+            ..FileStats::default()
+        }
+    );
+
+    // Duplicates of the whole project are counted across files
+    assert_eq!(
+        stats.total,
+        FileStats {
+            total: 8,
+            translated: 5,
+            duplicates: 3,
+            source_chars: 53,
+            source_words: 10,
+            target_chars: 31,
+            target_words: 7,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.summary(None).split('\n').next(),
+        Some(
+            "Total: 5/8 (62.5%), source: 53 chars/10 words, translation: 31 chars/7 words, duplicates: 3, unused: 0, \
+             machine: 0, fuzzy: 0, needs review: 0, approved: 1"
+        )
+    );
+
+    remove_dir_all(&known_path).unwrap();
+}
+
+#[test]
+fn stats_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let stats = StatsCollector::new(&translation_path, engine_type)
+        .original_path(Some(&data_path))
+        .collect();
+
+    // Per-map files are counted as a single maps file, and their unused lines are counted
+    let per_map_path = game_path.join("translation_stats_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    OtherReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let collect_per_map = || {
+        StatsCollector::new(&per_map_path, engine_type)
+            .original_path(Some(&data_path))
+            .maps_layout(MapsLayout::PerMap)
+            .collect()
+    };
+
+    let per_map_stats = collect_per_map();
+    assert_eq!(per_map_stats.files["maps.txt"], stats.files["maps.txt"]);
+    assert_eq!(per_map_stats.maps, stats.maps);
+
+    let map_file_path = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .min()
+        .unwrap();
+    let map_content = read_to_string(&map_file_path).unwrap();
+    write(&map_file_path, format!("{map_content}\nStale line<#>Stale translation")).unwrap();
+
+    // Unused lines are attributed to their maps
+    let first_map: u16 = *stats.maps.keys().min().unwrap();
+    let unused_stats = collect_per_map();
+    assert_eq!(
+        unused_stats.files["maps.txt"].unused,
+        stats.files["maps.txt"].unused + 1
+    );
+    assert_eq!(unused_stats.maps[&first_map].unused, stats.maps[&first_map].unused + 1);
+    assert_eq!(
+        unused_stats.maps.values().map(|x| x.unused).sum::<usize>(),
+        unused_stats.files["maps.txt"].unused
+    );
+
+    remove_dir_all(&per_map_path).unwrap();
+    write_stats(&stats, &translation_path);
+
+    assert_eq!(read_stats(&translation_path), Some(stats));
+
+    // Counts of a known translation
+    let known_path = game_path.join("translation_stats");
+    create_dir_all(&known_path).unwrap();
+
+    write(
+        known_path.join("maps.txt"),
+        "<!-- Map --><#>Map001\nHello world<#>Привет мир\nYes<#>\nHello world<#>Привет мир\n<!-- Map --><#>Map002\n\
+         <!-- State --><#>approved\nYes<#>Да\nGoodbye<#>",
+    )
+    .unwrap();
+    write(known_path.join("items.txt"), "Potion<#>Зелье\nGoodbye<#>Пока\nEther<#>").unwrap();
+
+    let stats = StatsCollector::new(&known_path, engine_type).collect();
+
+    assert_eq!(
+        stats.maps[&1],
+        FileStats {
+            total: 3,
+            translated: 2,
+            duplicates: 1,
+            source_chars: 25,
+            source_words: 5,
+            target_chars: 20,
+            target_words: 4,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.maps[&2],
+        FileStats {
+            total: 2,
+            translated: 1,
+            duplicates: 1,
+            source_chars: 10,
+            source_words: 2,
+            target_chars: 2,
+            target_words: 1,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["maps.txt"],
+        FileStats {
+            total: 5,
+            translated: 3,
+            duplicates: 2,
+            source_chars: 35,
+            source_words: 7,
+            target_chars: 22,
+            target_words: 5,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["items.txt"],
+        FileStats {
+            total: 3,
+            translated: 2,
+            source_chars: 18,
+            source_words: 3,
+            target_chars: 9,
+            target_words: 2,
+            ..FileStats::default()
+        }
+    );
+
+    // Duplicates of the whole project are counted across files
+    assert_eq!(
+        stats.total,
+        FileStats {
+            total: 8,
+            translated: 5,
+            duplicates: 3,
+            source_chars: 53,
+            source_words: 10,
+            target_chars: 31,
+            target_words: 7,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.summary(None).split('\n').next(),
+        Some(
+            "Total: 5/8 (62.5%), source: 53 chars/10 words, translation: 31 chars/7 words, duplicates: 3, unused: 0, \
+             machine: 0, fuzzy: 0, needs review: 0, approved: 1"
+        )
+    );
+
+    remove_dir_all(&known_path).unwrap();
+}
+
+#[test]
+fn stats_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let translation_path = game_path.join("translation");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&translation_path).unwrap();
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+    OtherReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let stats = StatsCollector::new(&translation_path, engine_type)
+        .original_path(Some(&data_path))
+        .collect();
+
+    // Per-map files are counted as a single maps file, and their unused lines are counted
+    let per_map_path = game_path.join("translation_stats_per_map");
+    create_dir_all(&per_map_path).unwrap();
+
+    MapReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_layout(MapsLayout::PerMap)
+        .read();
+    OtherReader::new(&data_path, &per_map_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .read();
+
+    let collect_per_map = || {
+        StatsCollector::new(&per_map_path, engine_type)
+            .original_path(Some(&data_path))
+            .maps_layout(MapsLayout::PerMap)
+            .collect()
+    };
+
+    let per_map_stats = collect_per_map();
+    assert_eq!(per_map_stats.files["maps.txt"], stats.files["maps.txt"]);
+    assert_eq!(per_map_stats.maps, stats.maps);
+
+    let map_file_path = read_dir(per_map_path.join("maps"))
+        .unwrap()
+        .flatten()
+        .map(|entry| entry.path())
+        .min()
+        .unwrap();
+    let map_content = read_to_string(&map_file_path).unwrap();
+    write(&map_file_path, format!("{map_content}\nStale line<#>Stale translation")).unwrap();
+
+    // Unused lines are attributed to their maps
+    let first_map: u16 = *stats.maps.keys().min().unwrap();
+    let unused_stats = collect_per_map();
+    assert_eq!(
+        unused_stats.files["maps.txt"].unused,
+        stats.files["maps.txt"].unused + 1
+    );
+    assert_eq!(unused_stats.maps[&first_map].unused, stats.maps[&first_map].unused + 1);
+    assert_eq!(
+        unused_stats.maps.values().map(|x| x.unused).sum::<usize>(),
+        unused_stats.files["maps.txt"].unused
+    );
+
+    remove_dir_all(&per_map_path).unwrap();
+    write_stats(&stats, &translation_path);
+
+    assert_eq!(read_stats(&translation_path), Some(stats));
+
+    // Counts of a known translation
+    let known_path = game_path.join("translation_stats");
+    create_dir_all(&known_path).unwrap();
+
+    write(
+        known_path.join("maps.txt"),
+        "<!-- Map --><#>Map001\nHello world<#>Привет мир\nYes<#>\nHello world<#>Привет мир\n<!-- Map --><#>Map002\n\
+         <!-- State --><#>approved\nYes<#>Да\nGoodbye<#>",
+    )
+    .unwrap();
+    write(known_path.join("items.txt"), "Potion<#>Зелье\nGoodbye<#>Пока\nEther<#>").unwrap();
+
+    let stats = StatsCollector::new(&known_path, engine_type).collect();
+
+    assert_eq!(
+        stats.maps[&1],
+        FileStats {
+            total: 3,
+            translated: 2,
+            duplicates: 1,
+            source_chars: 25,
+            source_words: 5,
+            target_chars: 20,
+            target_words: 4,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.maps[&2],
+        FileStats {
+            total: 2,
+            translated: 1,
+            duplicates: 1,
+            source_chars: 10,
+            source_words: 2,
+            target_chars: 2,
+            target_words: 1,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["maps.txt"],
+        FileStats {
+            total: 5,
+            translated: 3,
+            duplicates: 2,
+            source_chars: 35,
+            source_words: 7,
+            target_chars: 22,
+            target_words: 5,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.files["items.txt"],
+        FileStats {
+            total: 3,
+            translated: 2,
+            source_chars: 18,
+            source_words: 3,
+            target_chars: 9,
+            target_words: 2,
+            ..FileStats::default()
+        }
+    );
+
+    // Duplicates of the whole project are counted across files
+    assert_eq!(
+        stats.total,
+        FileStats {
+            total: 8,
+            translated: 5,
+            duplicates: 3,
+            source_chars: 53,
+            source_words: 10,
+            target_chars: 31,
+            target_words: 7,
+            approved: 1,
+            ..FileStats::default()
+        }
+    );
+    assert_eq!(
+        stats.summary(None).split('\n').next(),
+        Some(
+            "Total: 5/8 (62.5%), source: 53 chars/10 words, translation: 31 chars/7 words, duplicates: 3, unused: 0, \
+             machine: 0, fuzzy: 0, needs review: 0, approved: 1"
+        )
+    );
+
+    remove_dir_all(&known_path).unwrap();
+}
@@ -0,0 +1,682 @@
+use rvpacker_txt_rs_lib::{
+    observer::{Observer, Warning},
+    read::MapReader,
+    types::{EngineType, HeaderMode, MapsLayout, MapsProcessingMode, ProcessingMode},
+    write::MapWriter,
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+#[test]
+fn streaming_mz() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let regular_path = game_path.join("translation_regular");
+    let streaming_path = game_path.join("translation_streaming");
+    let engine_type = EngineType::New;
+
+    let read_files = |path: &PathBuf| -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                (
+                    path.file_name().unwrap().to_str().unwrap().to_owned(),
+                    read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_layout in [MapsLayout::Single, MapsLayout::PerMapNamed] {
+        for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+            for tree_order in [false, true] {
+                for path in [&regular_path, &streaming_path] {
+                    if path.exists() {
+                        remove_dir_all(path).unwrap();
+                    }
+
+                    create_dir_all(path).unwrap();
+
+                    MapReader::new(&data_path, path, engine_type)
+                        .processing_mode(ProcessingMode::Force)
+                        .maps_processing_mode(maps_processing_mode)
+                        .maps_layout(maps_layout)
+                        .tree_order(tree_order)
+                        .streaming(path == &streaming_path)
+                        .read();
+                }
+
+                // Streaming doesn't change the output
+                let maps_dir = if maps_layout.is_single() { "" } else { "maps" };
+                assert_eq!(
+                    read_files(&regular_path.join(maps_dir)),
+                    read_files(&streaming_path.join(maps_dir))
+                );
+            }
+        }
+    }
+
+    // Every second line is translated, so the dry run reports both replacements and untranslated lines
+    let maps_txt_path = regular_path.join("maps.txt");
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 2 == 0 && !line.starts_with("<!--") {
+                format!("{line}Streamed")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, content).unwrap();
+
+    let write_maps = |streaming: bool| {
+        MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+            .maps_processing_mode(MapsProcessingMode::Separate)
+            .dry_run(true)
+            .streaming(streaming)
+            .write()
+    };
+
+    let regular = write_maps(false);
+    let streaming = write_maps(true);
+
+    assert!(!regular.replacements.is_empty());
+    assert_eq!(regular.replacements, streaming.replacements);
+    assert_eq!(regular.untranslated, streaming.untranslated);
+
+    // Settings, that need the whole content at once, are reported
+    let recorder = Arc::new(Recorder::default());
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .streaming(true)
+        .observer(recorder.clone())
+        .read();
+    MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+        .header_mode(HeaderMode::Ignore)
+        .dry_run(true)
+        .streaming(true)
+        .observer(recorder.clone())
+        .write();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["processing_mode".into()],
+            },
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["maps_processing_mode".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn streaming_mv() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let regular_path = game_path.join("translation_regular");
+    let streaming_path = game_path.join("translation_streaming");
+    let engine_type = EngineType::New;
+
+    let read_files = |path: &PathBuf| -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                (
+                    path.file_name().unwrap().to_str().unwrap().to_owned(),
+                    read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_layout in [MapsLayout::Single, MapsLayout::PerMapNamed] {
+        for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+            for tree_order in [false, true] {
+                for path in [&regular_path, &streaming_path] {
+                    if path.exists() {
+                        remove_dir_all(path).unwrap();
+                    }
+
+                    create_dir_all(path).unwrap();
+
+                    MapReader::new(&data_path, path, engine_type)
+                        .processing_mode(ProcessingMode::Force)
+                        .maps_processing_mode(maps_processing_mode)
+                        .maps_layout(maps_layout)
+                        .tree_order(tree_order)
+                        .streaming(path == &streaming_path)
+                        .read();
+                }
+
+                // Streaming doesn't change the output
+                let maps_dir = if maps_layout.is_single() { "" } else { "maps" };
+                assert_eq!(
+                    read_files(&regular_path.join(maps_dir)),
+                    read_files(&streaming_path.join(maps_dir))
+                );
+            }
+        }
+    }
+
+    // Every second line is translated, so the dry run reports both replacements and untranslated lines
+    let maps_txt_path = regular_path.join("maps.txt");
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 2 == 0 && !line.starts_with("<!--") {
+                format!("{line}Streamed")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, content).unwrap();
+
+    let write_maps = |streaming: bool| {
+        MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+            .maps_processing_mode(MapsProcessingMode::Separate)
+            .dry_run(true)
+            .streaming(streaming)
+            .write()
+    };
+
+    let regular = write_maps(false);
+    let streaming = write_maps(true);
+
+    assert!(!regular.replacements.is_empty());
+    assert_eq!(regular.replacements, streaming.replacements);
+    assert_eq!(regular.untranslated, streaming.untranslated);
+
+    // Settings, that need the whole content at once, are reported
+    let recorder = Arc::new(Recorder::default());
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .streaming(true)
+        .observer(recorder.clone())
+        .read();
+    MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+        .header_mode(HeaderMode::Ignore)
+        .dry_run(true)
+        .streaming(true)
+        .observer(recorder.clone())
+        .write();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["processing_mode".into()],
+            },
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["maps_processing_mode".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn streaming_vxace() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let regular_path = game_path.join("translation_regular");
+    let streaming_path = game_path.join("translation_streaming");
+    let engine_type = EngineType::VXAce;
+
+    let read_files = |path: &PathBuf| -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                (
+                    path.file_name().unwrap().to_str().unwrap().to_owned(),
+                    read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_layout in [MapsLayout::Single, MapsLayout::PerMapNamed] {
+        for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+            for tree_order in [false, true] {
+                for path in [&regular_path, &streaming_path] {
+                    if path.exists() {
+                        remove_dir_all(path).unwrap();
+                    }
+
+                    create_dir_all(path).unwrap();
+
+                    MapReader::new(&data_path, path, engine_type)
+                        .processing_mode(ProcessingMode::Force)
+                        .maps_processing_mode(maps_processing_mode)
+                        .maps_layout(maps_layout)
+                        .tree_order(tree_order)
+                        .streaming(path == &streaming_path)
+                        .read();
+                }
+
+                // Streaming doesn't change the output
+                let maps_dir = if maps_layout.is_single() { "" } else { "maps" };
+                assert_eq!(
+                    read_files(&regular_path.join(maps_dir)),
+                    read_files(&streaming_path.join(maps_dir))
+                );
+            }
+        }
+    }
+
+    // Every second line is translated, so the dry run reports both replacements and untranslated lines
+    let maps_txt_path = regular_path.join("maps.txt");
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 2 == 0 && !line.starts_with("<!--") {
+                format!("{line}Streamed")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, content).unwrap();
+
+    let write_maps = |streaming: bool| {
+        MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+            .maps_processing_mode(MapsProcessingMode::Separate)
+            .dry_run(true)
+            .streaming(streaming)
+            .write()
+    };
+
+    let regular = write_maps(false);
+    let streaming = write_maps(true);
+
+    assert!(!regular.replacements.is_empty());
+    assert_eq!(regular.replacements, streaming.replacements);
+    assert_eq!(regular.untranslated, streaming.untranslated);
+
+    // Settings, that need the whole content at once, are reported
+    let recorder = Arc::new(Recorder::default());
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .streaming(true)
+        .observer(recorder.clone())
+        .read();
+    MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+        .header_mode(HeaderMode::Ignore)
+        .dry_run(true)
+        .streaming(true)
+        .observer(recorder.clone())
+        .write();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["processing_mode".into()],
+            },
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["maps_processing_mode".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn streaming_vx() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let regular_path = game_path.join("translation_regular");
+    let streaming_path = game_path.join("translation_streaming");
+    let engine_type = EngineType::VX;
+
+    let read_files = |path: &PathBuf| -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                (
+                    path.file_name().unwrap().to_str().unwrap().to_owned(),
+                    read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_layout in [MapsLayout::Single, MapsLayout::PerMapNamed] {
+        for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+            for tree_order in [false, true] {
+                for path in [&regular_path, &streaming_path] {
+                    if path.exists() {
+                        remove_dir_all(path).unwrap();
+                    }
+
+                    create_dir_all(path).unwrap();
+
+                    MapReader::new(&data_path, path, engine_type)
+                        .processing_mode(ProcessingMode::Force)
+                        .maps_processing_mode(maps_processing_mode)
+                        .maps_layout(maps_layout)
+                        .tree_order(tree_order)
+                        .streaming(path == &streaming_path)
+                        .read();
+                }
+
+                // Streaming doesn't change the output
+                let maps_dir = if maps_layout.is_single() { "" } else { "maps" };
+                assert_eq!(
+                    read_files(&regular_path.join(maps_dir)),
+                    read_files(&streaming_path.join(maps_dir))
+                );
+            }
+        }
+    }
+
+    // Every second line is translated, so the dry run reports both replacements and untranslated lines
+    let maps_txt_path = regular_path.join("maps.txt");
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 2 == 0 && !line.starts_with("<!--") {
+                format!("{line}Streamed")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, content).unwrap();
+
+    let write_maps = |streaming: bool| {
+        MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+            .maps_processing_mode(MapsProcessingMode::Separate)
+            .dry_run(true)
+            .streaming(streaming)
+            .write()
+    };
+
+    let regular = write_maps(false);
+    let streaming = write_maps(true);
+
+    assert!(!regular.replacements.is_empty());
+    assert_eq!(regular.replacements, streaming.replacements);
+    assert_eq!(regular.untranslated, streaming.untranslated);
+
+    // Settings, that need the whole content at once, are reported
+    let recorder = Arc::new(Recorder::default());
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .streaming(true)
+        .observer(recorder.clone())
+        .read();
+    MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+        .header_mode(HeaderMode::Ignore)
+        .dry_run(true)
+        .streaming(true)
+        .observer(recorder.clone())
+        .write();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["processing_mode".into()],
+            },
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["maps_processing_mode".into()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn streaming_xp() {
+    #[derive(Default)]
+    struct Recorder {
+        warnings: Mutex<Vec<Warning>>,
+    }
+
+    impl Observer for Recorder {
+        fn warning(&self, warning: Warning) {
+            self.warnings.lock().unwrap().push(warning);
+        }
+    }
+
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let regular_path = game_path.join("translation_regular");
+    let streaming_path = game_path.join("translation_streaming");
+    let engine_type = EngineType::XP;
+
+    let read_files = |path: &PathBuf| -> Vec<(String, String)> {
+        let mut files: Vec<(String, String)> = read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .map(|path| {
+                (
+                    path.file_name().unwrap().to_str().unwrap().to_owned(),
+                    read_to_string(&path).unwrap(),
+                )
+            })
+            .collect();
+        files.sort();
+        files
+    };
+
+    for maps_layout in [MapsLayout::Single, MapsLayout::PerMapNamed] {
+        for maps_processing_mode in [MapsProcessingMode::Default, MapsProcessingMode::Separate] {
+            for tree_order in [false, true] {
+                for path in [&regular_path, &streaming_path] {
+                    if path.exists() {
+                        remove_dir_all(path).unwrap();
+                    }
+
+                    create_dir_all(path).unwrap();
+
+                    MapReader::new(&data_path, path, engine_type)
+                        .processing_mode(ProcessingMode::Force)
+                        .maps_processing_mode(maps_processing_mode)
+                        .maps_layout(maps_layout)
+                        .tree_order(tree_order)
+                        .streaming(path == &streaming_path)
+                        .read();
+                }
+
+                // Streaming doesn't change the output
+                let maps_dir = if maps_layout.is_single() { "" } else { "maps" };
+                assert_eq!(
+                    read_files(&regular_path.join(maps_dir)),
+                    read_files(&streaming_path.join(maps_dir))
+                );
+            }
+        }
+    }
+
+    // Every second line is translated, so the dry run reports both replacements and untranslated lines
+    let maps_txt_path = regular_path.join("maps.txt");
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Force)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .read();
+
+    let content: String = read_to_string(&maps_txt_path)
+        .unwrap()
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if i % 2 == 0 && !line.starts_with("<!--") {
+                format!("{line}Streamed")
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    write(&maps_txt_path, content).unwrap();
+
+    let write_maps = |streaming: bool| {
+        MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+            .maps_processing_mode(MapsProcessingMode::Separate)
+            .dry_run(true)
+            .streaming(streaming)
+            .write()
+    };
+
+    let regular = write_maps(false);
+    let streaming = write_maps(true);
+
+    assert!(!regular.replacements.is_empty());
+    assert_eq!(regular.replacements, streaming.replacements);
+    assert_eq!(regular.untranslated, streaming.untranslated);
+
+    // Settings, that need the whole content at once, are reported
+    let recorder = Arc::new(Recorder::default());
+
+    MapReader::new(&data_path, &regular_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .maps_processing_mode(MapsProcessingMode::Separate)
+        .streaming(true)
+        .observer(recorder.clone())
+        .read();
+    MapWriter::new(&data_path, &regular_path, &regular_path, engine_type)
+        .header_mode(HeaderMode::Ignore)
+        .dry_run(true)
+        .streaming(true)
+        .observer(recorder.clone())
+        .write();
+
+    assert_eq!(
+        *recorder.warnings.lock().unwrap(),
+        [
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["processing_mode".into()],
+            },
+            Warning::StreamingUnsupported {
+                file: "maps.txt".into(),
+                settings: vec!["maps_processing_mode".into()],
+            },
+        ]
+    );
+}
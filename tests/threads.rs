@@ -0,0 +1,295 @@
+use rvpacker_txt_rs_lib::{
+    purge::{MapPurger, OtherPurger},
+    read::{MapReader, OtherReader},
+    types::{EngineType, ProcessingMode},
+};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_dir, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn threads_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let sequential_path = game_path.join("translation_sequential");
+    let parallel_path = game_path.join("translation_parallel");
+    let engine_type = EngineType::New;
+
+    for (translation_path, threads) in [(&sequential_path, 1), (&parallel_path, 4)] {
+        create_dir_all(translation_path).unwrap();
+
+        MapReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+        OtherReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+    }
+
+    // Output doesn't depend on the number of threads
+    for entry in read_dir(&sequential_path).unwrap() {
+        let file_name = entry.unwrap().file_name();
+
+        assert_eq!(
+            read_to_string(sequential_path.join(&file_name)).unwrap(),
+            read_to_string(parallel_path.join(&file_name)).unwrap()
+        );
+    }
+
+    for file_name in ["maps.txt", "items.txt"] {
+        let path = sequential_path.join(file_name);
+        let content = read_to_string(&path).unwrap();
+        write(path, content + "\nStale line<#>Stale translation").unwrap();
+    }
+
+    let purge = |threads: usize| {
+        let mut purged = MapPurger::new(&data_path, &sequential_path, engine_type)
+            .threads(threads)
+            .dry_run(true)
+            .purge(None, None);
+        purged.extend(
+            OtherPurger::new(&data_path, &sequential_path, engine_type)
+                .threads(threads)
+                .dry_run(true)
+                .purge(None, None),
+        );
+        purged
+    };
+
+    let purged = purge(1);
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged, purge(4));
+}
+
+#[test]
+fn threads_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let sequential_path = game_path.join("translation_sequential");
+    let parallel_path = game_path.join("translation_parallel");
+    let engine_type = EngineType::New;
+
+    for (translation_path, threads) in [(&sequential_path, 1), (&parallel_path, 4)] {
+        create_dir_all(translation_path).unwrap();
+
+        MapReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+        OtherReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+    }
+
+    // Output doesn't depend on the number of threads
+    for entry in read_dir(&sequential_path).unwrap() {
+        let file_name = entry.unwrap().file_name();
+
+        assert_eq!(
+            read_to_string(sequential_path.join(&file_name)).unwrap(),
+            read_to_string(parallel_path.join(&file_name)).unwrap()
+        );
+    }
+
+    for file_name in ["maps.txt", "items.txt"] {
+        let path = sequential_path.join(file_name);
+        let content = read_to_string(&path).unwrap();
+        write(path, content + "\nStale line<#>Stale translation").unwrap();
+    }
+
+    let purge = |threads: usize| {
+        let mut purged = MapPurger::new(&data_path, &sequential_path, engine_type)
+            .threads(threads)
+            .dry_run(true)
+            .purge(None, None);
+        purged.extend(
+            OtherPurger::new(&data_path, &sequential_path, engine_type)
+                .threads(threads)
+                .dry_run(true)
+                .purge(None, None),
+        );
+        purged
+    };
+
+    let purged = purge(1);
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged, purge(4));
+}
+
+#[test]
+fn threads_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let sequential_path = game_path.join("translation_sequential");
+    let parallel_path = game_path.join("translation_parallel");
+    let engine_type = EngineType::VXAce;
+
+    for (translation_path, threads) in [(&sequential_path, 1), (&parallel_path, 4)] {
+        create_dir_all(translation_path).unwrap();
+
+        MapReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+        OtherReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+    }
+
+    // Output doesn't depend on the number of threads
+    for entry in read_dir(&sequential_path).unwrap() {
+        let file_name = entry.unwrap().file_name();
+
+        assert_eq!(
+            read_to_string(sequential_path.join(&file_name)).unwrap(),
+            read_to_string(parallel_path.join(&file_name)).unwrap()
+        );
+    }
+
+    for file_name in ["maps.txt", "items.txt"] {
+        let path = sequential_path.join(file_name);
+        let content = read_to_string(&path).unwrap();
+        write(path, content + "\nStale line<#>Stale translation").unwrap();
+    }
+
+    let purge = |threads: usize| {
+        let mut purged = MapPurger::new(&data_path, &sequential_path, engine_type)
+            .threads(threads)
+            .dry_run(true)
+            .purge(None, None);
+        purged.extend(
+            OtherPurger::new(&data_path, &sequential_path, engine_type)
+                .threads(threads)
+                .dry_run(true)
+                .purge(None, None),
+        );
+        purged
+    };
+
+    let purged = purge(1);
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged, purge(4));
+}
+
+#[test]
+fn threads_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let sequential_path = game_path.join("translation_sequential");
+    let parallel_path = game_path.join("translation_parallel");
+    let engine_type = EngineType::VX;
+
+    for (translation_path, threads) in [(&sequential_path, 1), (&parallel_path, 4)] {
+        create_dir_all(translation_path).unwrap();
+
+        MapReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+        OtherReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+    }
+
+    // Output doesn't depend on the number of threads
+    for entry in read_dir(&sequential_path).unwrap() {
+        let file_name = entry.unwrap().file_name();
+
+        assert_eq!(
+            read_to_string(sequential_path.join(&file_name)).unwrap(),
+            read_to_string(parallel_path.join(&file_name)).unwrap()
+        );
+    }
+
+    for file_name in ["maps.txt", "items.txt"] {
+        let path = sequential_path.join(file_name);
+        let content = read_to_string(&path).unwrap();
+        write(path, content + "\nStale line<#>Stale translation").unwrap();
+    }
+
+    let purge = |threads: usize| {
+        let mut purged = MapPurger::new(&data_path, &sequential_path, engine_type)
+            .threads(threads)
+            .dry_run(true)
+            .purge(None, None);
+        purged.extend(
+            OtherPurger::new(&data_path, &sequential_path, engine_type)
+                .threads(threads)
+                .dry_run(true)
+                .purge(None, None),
+        );
+        purged
+    };
+
+    let purged = purge(1);
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged, purge(4));
+}
+
+#[test]
+fn threads_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let sequential_path = game_path.join("translation_sequential");
+    let parallel_path = game_path.join("translation_parallel");
+    let engine_type = EngineType::XP;
+
+    for (translation_path, threads) in [(&sequential_path, 1), (&parallel_path, 4)] {
+        create_dir_all(translation_path).unwrap();
+
+        MapReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+        OtherReader::new(&data_path, translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .threads(threads)
+            .read();
+    }
+
+    // Output doesn't depend on the number of threads
+    for entry in read_dir(&sequential_path).unwrap() {
+        let file_name = entry.unwrap().file_name();
+
+        assert_eq!(
+            read_to_string(sequential_path.join(&file_name)).unwrap(),
+            read_to_string(parallel_path.join(&file_name)).unwrap()
+        );
+    }
+
+    for file_name in ["maps.txt", "items.txt"] {
+        let path = sequential_path.join(file_name);
+        let content = read_to_string(&path).unwrap();
+        write(path, content + "\nStale line<#>Stale translation").unwrap();
+    }
+
+    let purge = |threads: usize| {
+        let mut purged = MapPurger::new(&data_path, &sequential_path, engine_type)
+            .threads(threads)
+            .dry_run(true)
+            .purge(None, None);
+        purged.extend(
+            OtherPurger::new(&data_path, &sequential_path, engine_type)
+                .threads(threads)
+                .dry_run(true)
+                .purge(None, None),
+        );
+        purged
+    };
+
+    let purged = purge(1);
+    assert_eq!(purged.len(), 2);
+    assert_eq!(purged, purge(4));
+}
@@ -0,0 +1,428 @@
+use rvpacker_txt_rs_lib::{
+    escape,
+    read::MapReader,
+    tree::MapTree,
+    types::{EngineType, MapsProcessingMode, ProcessingMode},
+};
+use sonic_rs::{from_str, Value};
+use std::{
+    env::var,
+    fs::{create_dir_all, read_to_string},
+    path::PathBuf,
+};
+
+#[test]
+fn tree_order() {
+    // 1 and 4 are roots, 2 and 3 are children of 1, ordered by their order, 5 and 6 reference each other,
+    // and 7 references a missing parent
+    let mapinfos: Value = from_str(
+        r#"[
+            null,
+            {"id": 1, "name": "World", "parentId": 0, "order": 2, "expanded": true},
+            {"id": 2, "name": "Town", "parentId": 1, "order": 4, "expanded": false},
+            {"id": 3, "name": "Field", "parentId": 1, "order": 3, "expanded": false},
+            {"id": 4, "name": "Title", "parentId": 0, "order": 1, "expanded": false},
+            {"id": 5, "name": "Loop A", "parentId": 6, "order": 5, "expanded": false},
+            {"id": 6, "name": "Loop B", "parentId": 5, "order": 6, "expanded": false},
+            {"id": 7, "name": "Orphan", "parentId": 99, "order": 7, "expanded": false}
+        ]"#,
+    )
+    .unwrap();
+    let tree = MapTree::from_value(&mapinfos, EngineType::New);
+
+    assert_eq!(tree.maps.len(), 7);
+    assert_eq!(tree.traverse(), [4, 1, 3, 2, 7, 5, 6]);
+    assert_eq!(tree.path(2), ["World", "Town"]);
+    assert_eq!(tree.path(7), ["Orphan"]);
+    // Path of maps with cyclic parents is limited by the count of maps
+    assert_eq!(tree.path(5).len(), tree.maps.len());
+    assert!(tree.path(100).is_empty());
+
+    // Marshal engines store entries in a hash by map number
+    let mapinfos: Value = from_str(
+        r#"{
+            "__integer__2": {"__symbol__name": "Child", "__symbol__parent_id": 1, "__symbol__order": 1},
+            "__integer__1": {"__symbol__name": "Root", "__symbol__parent_id": 0, "__symbol__order": 2}
+        }"#,
+    )
+    .unwrap();
+    let tree = MapTree::from_value(&mapinfos, EngineType::VXAce);
+
+    assert_eq!(tree.traverse(), [1, 2]);
+    assert_eq!(tree.path(2), ["Root", "Child"]);
+}
+
+#[test]
+fn tree_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_tree");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let tree = MapTree::parse(data_path.join("MapInfos.json"), engine_type);
+    let order: Vec<u16> = tree.traverse();
+    assert_eq!(order.len(), tree.maps.len());
+
+    // Parents precede their children
+    for (i, map_number) in order.iter().enumerate() {
+        let map = tree.get(*map_number).unwrap();
+
+        if let Some(parent_position) = order.iter().position(|x| *x == map.parent_id) {
+            assert!(parent_position < i);
+        }
+
+        assert_eq!(tree.path(*map_number).last().copied(), Some(map.name.as_str()));
+    }
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+
+        let content: String = read_to_string(&maps_txt_path).unwrap();
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut positions: Vec<usize> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(map_number) = line.strip_prefix("<!-- Map --><#>") else {
+                continue;
+            };
+            let map_number: u16 = map_number.parse().unwrap();
+            let path: Vec<&str> = tree.path(map_number);
+
+            if !path.is_empty() {
+                assert_eq!(
+                    lines[i + 1],
+                    format!("<!-- Map Path: {} --><#>", escape(&path.join("/")))
+                );
+            }
+
+            positions.push(order.iter().position(|x| *x == map_number).unwrap_or(usize::MAX));
+        }
+
+        // Maps are ordered by the tree
+        assert!(positions.is_sorted());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert!(!read_to_string(&maps_txt_path).unwrap().contains("<!-- Map Path: "));
+}
+
+#[test]
+fn tree_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+
+    let translation_path = game_path.join("translation_tree");
+    let engine_type = EngineType::New;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let tree = MapTree::parse(data_path.join("MapInfos.json"), engine_type);
+    let order: Vec<u16> = tree.traverse();
+    assert_eq!(order.len(), tree.maps.len());
+
+    // Parents precede their children
+    for (i, map_number) in order.iter().enumerate() {
+        let map = tree.get(*map_number).unwrap();
+
+        if let Some(parent_position) = order.iter().position(|x| *x == map.parent_id) {
+            assert!(parent_position < i);
+        }
+
+        assert_eq!(tree.path(*map_number).last().copied(), Some(map.name.as_str()));
+    }
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+
+        let content: String = read_to_string(&maps_txt_path).unwrap();
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut positions: Vec<usize> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(map_number) = line.strip_prefix("<!-- Map --><#>") else {
+                continue;
+            };
+            let map_number: u16 = map_number.parse().unwrap();
+            let path: Vec<&str> = tree.path(map_number);
+
+            if !path.is_empty() {
+                assert_eq!(
+                    lines[i + 1],
+                    format!("<!-- Map Path: {} --><#>", escape(&path.join("/")))
+                );
+            }
+
+            positions.push(order.iter().position(|x| *x == map_number).unwrap_or(usize::MAX));
+        }
+
+        // Maps are ordered by the tree
+        assert!(positions.is_sorted());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert!(!read_to_string(&maps_txt_path).unwrap().contains("<!-- Map Path: "));
+}
+
+#[test]
+fn tree_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_tree");
+    let engine_type = EngineType::VXAce;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let tree = MapTree::parse(data_path.join("MapInfos.rvdata2"), engine_type);
+    let order: Vec<u16> = tree.traverse();
+    assert_eq!(order.len(), tree.maps.len());
+
+    // Parents precede their children
+    for (i, map_number) in order.iter().enumerate() {
+        let map = tree.get(*map_number).unwrap();
+
+        if let Some(parent_position) = order.iter().position(|x| *x == map.parent_id) {
+            assert!(parent_position < i);
+        }
+
+        assert_eq!(tree.path(*map_number).last().copied(), Some(map.name.as_str()));
+    }
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+
+        let content: String = read_to_string(&maps_txt_path).unwrap();
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut positions: Vec<usize> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(map_number) = line.strip_prefix("<!-- Map --><#>") else {
+                continue;
+            };
+            let map_number: u16 = map_number.parse().unwrap();
+            let path: Vec<&str> = tree.path(map_number);
+
+            if !path.is_empty() {
+                assert_eq!(
+                    lines[i + 1],
+                    format!("<!-- Map Path: {} --><#>", escape(&path.join("/")))
+                );
+            }
+
+            positions.push(order.iter().position(|x| *x == map_number).unwrap_or(usize::MAX));
+        }
+
+        // Maps are ordered by the tree
+        assert!(positions.is_sorted());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert!(!read_to_string(&maps_txt_path).unwrap().contains("<!-- Map Path: "));
+}
+
+#[test]
+fn tree_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_tree");
+    let engine_type = EngineType::VX;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let tree = MapTree::parse(data_path.join("MapInfos.rvdata"), engine_type);
+    let order: Vec<u16> = tree.traverse();
+    assert_eq!(order.len(), tree.maps.len());
+
+    // Parents precede their children
+    for (i, map_number) in order.iter().enumerate() {
+        let map = tree.get(*map_number).unwrap();
+
+        if let Some(parent_position) = order.iter().position(|x| *x == map.parent_id) {
+            assert!(parent_position < i);
+        }
+
+        assert_eq!(tree.path(*map_number).last().copied(), Some(map.name.as_str()));
+    }
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+
+        let content: String = read_to_string(&maps_txt_path).unwrap();
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut positions: Vec<usize> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(map_number) = line.strip_prefix("<!-- Map --><#>") else {
+                continue;
+            };
+            let map_number: u16 = map_number.parse().unwrap();
+            let path: Vec<&str> = tree.path(map_number);
+
+            if !path.is_empty() {
+                assert_eq!(
+                    lines[i + 1],
+                    format!("<!-- Map Path: {} --><#>", escape(&path.join("/")))
+                );
+            }
+
+            positions.push(order.iter().position(|x| *x == map_number).unwrap_or(usize::MAX));
+        }
+
+        // Maps are ordered by the tree
+        assert!(positions.is_sorted());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert!(!read_to_string(&maps_txt_path).unwrap().contains("<!-- Map Path: "));
+}
+
+#[test]
+fn tree_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("Data");
+
+    let translation_path = game_path.join("translation_tree");
+    let engine_type = EngineType::XP;
+    let maps_txt_path = translation_path.join("maps.txt");
+
+    create_dir_all(&translation_path).unwrap();
+
+    let tree = MapTree::parse(data_path.join("MapInfos.rxdata"), engine_type);
+    let order: Vec<u16> = tree.traverse();
+    assert_eq!(order.len(), tree.maps.len());
+
+    // Parents precede their children
+    for (i, map_number) in order.iter().enumerate() {
+        let map = tree.get(*map_number).unwrap();
+
+        if let Some(parent_position) = order.iter().position(|x| *x == map.parent_id) {
+            assert!(parent_position < i);
+        }
+
+        assert_eq!(tree.path(*map_number).last().copied(), Some(map.name.as_str()));
+    }
+
+    for maps_processing_mode in [
+        MapsProcessingMode::Default,
+        MapsProcessingMode::Separate,
+        MapsProcessingMode::Preserve,
+    ] {
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Force)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+
+        let content: String = read_to_string(&maps_txt_path).unwrap();
+        let lines: Vec<&str> = content.split('\n').collect();
+        let mut positions: Vec<usize> = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            let Some(map_number) = line.strip_prefix("<!-- Map --><#>") else {
+                continue;
+            };
+            let map_number: u16 = map_number.parse().unwrap();
+            let path: Vec<&str> = tree.path(map_number);
+
+            if !path.is_empty() {
+                assert_eq!(
+                    lines[i + 1],
+                    format!("<!-- Map Path: {} --><#>", escape(&path.join("/")))
+                );
+            }
+
+            positions.push(order.iter().position(|x| *x == map_number).unwrap_or(usize::MAX));
+        }
+
+        // Maps are ordered by the tree
+        assert!(positions.is_sorted());
+
+        MapReader::new(&data_path, &translation_path, engine_type)
+            .processing_mode(ProcessingMode::Append)
+            .maps_processing_mode(maps_processing_mode)
+            .tree_order(true)
+            .read();
+        assert_eq!(read_to_string(&maps_txt_path).unwrap(), content);
+    }
+
+    MapReader::new(&data_path, &translation_path, engine_type)
+        .processing_mode(ProcessingMode::Append)
+        .read();
+    assert!(!read_to_string(&maps_txt_path).unwrap().contains("<!-- Map Path: "));
+}
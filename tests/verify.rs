@@ -0,0 +1,245 @@
+use marshal_rs::{dump, load, StringMode};
+use rvpacker_txt_rs_lib::{
+    types::EngineType,
+    verify::{Difference, DifferenceKind, Verifier},
+};
+use sonic_rs::{from_str, to_string, Value};
+use std::{
+    env::var,
+    fs::{copy, create_dir_all, read, read_dir, read_to_string, write},
+    path::PathBuf,
+};
+
+#[test]
+fn verify_mz() {
+    let game_path = PathBuf::from(var("MZ_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let fixture_path = game_path.join("verify_data");
+    let work_path = game_path.join("verify");
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&fixture_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), fixture_path.join(entry.file_name())).unwrap();
+    }
+
+    // Identity translation of every string is written back as is
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .plugins_file_path(Some(&plugins_file_path))
+        .strings(true)
+        .verify();
+
+    assert!(report.files.contains_key("Items.json"));
+    assert!(!report.files.values().flatten().any(|x| x.kind.is_string()));
+
+    // Header of `plugins.js` is compared exactly
+    let header_differs = !read_to_string(&plugins_file_path)
+        .unwrap()
+        .starts_with("var $plugins =\n[");
+    assert_eq!(
+        report.files["plugins.js"]
+            .iter()
+            .any(|x| x.path == "header" && x.kind == DifferenceKind::Header),
+        header_differs
+    );
+
+    // Romanization replaces quotes of the item name, so the written name differs from the original
+    let items_path = fixture_path.join("Items.json");
+    let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+    items[1]["name"] = "「Quoted」".into();
+    write(&items_path, to_string(&items).unwrap()).unwrap();
+
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .romanize(true)
+        .strings(true)
+        .verify();
+
+    assert!(report.files["Items.json"].contains(&Difference {
+        path: String::from("[1].name"),
+        kind: DifferenceKind::String,
+        original: String::from("\"「Quoted」\""),
+        written: String::from("\"'Quoted'\""),
+    }));
+}
+
+#[test]
+fn verify_mv() {
+    let game_path = PathBuf::from(var("MV_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let fixture_path = game_path.join("verify_data");
+    let work_path = game_path.join("verify");
+    let plugins_file_path = game_path.join("js").join("plugins.js");
+    let engine_type = EngineType::New;
+
+    create_dir_all(&fixture_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), fixture_path.join(entry.file_name())).unwrap();
+    }
+
+    // Identity translation of every string is written back as is
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .plugins_file_path(Some(&plugins_file_path))
+        .strings(true)
+        .verify();
+
+    assert!(report.files.contains_key("Items.json"));
+    assert!(!report.files.values().flatten().any(|x| x.kind.is_string()));
+
+    // Header of `plugins.js` is compared exactly
+    let header_differs = !read_to_string(&plugins_file_path)
+        .unwrap()
+        .starts_with("var $plugins =\n[");
+    assert_eq!(
+        report.files["plugins.js"]
+            .iter()
+            .any(|x| x.path == "header" && x.kind == DifferenceKind::Header),
+        header_differs
+    );
+
+    // Romanization replaces quotes of the item name, so the written name differs from the original
+    let items_path = fixture_path.join("Items.json");
+    let mut items: Value = from_str(&read_to_string(&items_path).unwrap()).unwrap();
+    items[1]["name"] = "「Quoted」".into();
+    write(&items_path, to_string(&items).unwrap()).unwrap();
+
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .romanize(true)
+        .strings(true)
+        .verify();
+
+    assert!(report.files["Items.json"].contains(&Difference {
+        path: String::from("[1].name"),
+        kind: DifferenceKind::String,
+        original: String::from("\"「Quoted」\""),
+        written: String::from("\"'Quoted'\""),
+    }));
+}
+
+#[test]
+fn verify_vxace() {
+    let game_path = PathBuf::from(var("VXACE_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let fixture_path = game_path.join("verify_data");
+    let work_path = game_path.join("verify");
+    let engine_type = EngineType::VXAce;
+
+    create_dir_all(&fixture_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), fixture_path.join(entry.file_name())).unwrap();
+    }
+
+    // Identity translation of every string is written back as is
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .plugins_file_path(None)
+        .strings(true)
+        .verify();
+
+    assert!(report.files.contains_key("Items.rvdata2"));
+    assert!(!report.files.values().flatten().any(|x| x.kind.is_string()));
+
+    // Romanization replaces quotes of the item name, so the written name differs from the original
+    let items_path = fixture_path.join("Items.rvdata2");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[1]["__symbol__name"] = "「Quoted」".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .romanize(true)
+        .strings(true)
+        .verify();
+
+    assert!(report.files["Items.rvdata2"].contains(&Difference {
+        path: String::from("[1].__symbol__name"),
+        kind: DifferenceKind::String,
+        original: String::from("\"「Quoted」\""),
+        written: String::from("\"'Quoted'\""),
+    }));
+}
+
+#[test]
+fn verify_vx() {
+    let game_path = PathBuf::from(var("VX_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let fixture_path = game_path.join("verify_data");
+    let work_path = game_path.join("verify");
+    let engine_type = EngineType::VX;
+
+    create_dir_all(&fixture_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), fixture_path.join(entry.file_name())).unwrap();
+    }
+
+    // Identity translation of every string is written back as is
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .plugins_file_path(None)
+        .strings(true)
+        .verify();
+
+    assert!(report.files.contains_key("Items.rvdata"));
+    assert!(!report.files.values().flatten().any(|x| x.kind.is_string()));
+
+    // Romanization replaces quotes of the item name, so the written name differs from the original
+    let items_path = fixture_path.join("Items.rvdata");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[1]["__symbol__name"] = "「Quoted」".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .romanize(true)
+        .strings(true)
+        .verify();
+
+    assert!(report.files["Items.rvdata"].contains(&Difference {
+        path: String::from("[1].__symbol__name"),
+        kind: DifferenceKind::String,
+        original: String::from("\"「Quoted」\""),
+        written: String::from("\"'Quoted'\""),
+    }));
+}
+
+#[test]
+fn verify_xp() {
+    let game_path = PathBuf::from(var("XP_GAME_PATH").unwrap());
+    let data_path = game_path.join("data");
+    let fixture_path = game_path.join("verify_data");
+    let work_path = game_path.join("verify");
+    let engine_type = EngineType::XP;
+
+    create_dir_all(&fixture_path).unwrap();
+
+    for entry in read_dir(&data_path).unwrap().flatten() {
+        copy(entry.path(), fixture_path.join(entry.file_name())).unwrap();
+    }
+
+    // Identity translation of every string is written back as is
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .plugins_file_path(None)
+        .strings(true)
+        .verify();
+
+    assert!(report.files.contains_key("Items.rxdata"));
+    assert!(!report.files.values().flatten().any(|x| x.kind.is_string()));
+
+    // Romanization replaces quotes of the item name, so the written name differs from the original
+    let items_path = fixture_path.join("Items.rxdata");
+    let mut items: Value = load(&read(&items_path).unwrap(), Some(StringMode::UTF8), Some("")).unwrap();
+    items[1]["__symbol__name"] = "「Quoted」".into();
+    write(&items_path, dump(items, Some(""))).unwrap();
+
+    let report = Verifier::new(&fixture_path, &work_path, engine_type)
+        .romanize(true)
+        .strings(true)
+        .verify();
+
+    assert!(report.files["Items.rxdata"].contains(&Difference {
+        path: String::from("[1].__symbol__name"),
+        kind: DifferenceKind::String,
+        original: String::from("\"「Quoted」\""),
+        written: String::from("\"'Quoted'\""),
+    }));
+}